@@ -15,7 +15,7 @@ use http::Uri;
 
 use ibc_relayer::{
     config::{
-        filter::{ChannelFilters, FilterPattern, PacketFilter},
+        filter::{ChannelFilters, FeeFilter, FilterPattern, PacketFilter},
         gas_multiplier::GasMultiplier,
         types::{MaxMsgNum, MaxTxSize, Memo},
         {default, AddressType, ChainConfig, GasPrice},
@@ -107,9 +107,11 @@ where
         rpc_addr: rpc_data.rpc_address,
         websocket_addr: rpc_data.websocket,
         grpc_addr: grpc_address,
+        witness_rpc_addrs: Vec::new(),
         rpc_timeout: default::rpc_timeout(),
         account_prefix: chain_data.bech32_prefix,
         key_name: String::new(),
+        key_names: Vec::new(),
         key_store_type: Store::default(),
         store_prefix: "ibc".to_string(),
         default_gas: Some(100000),
@@ -122,6 +124,7 @@ where
         clock_drift: default::clock_drift(),
         max_block_time: default::max_block_time(),
         trusting_period: None,
+        light_block_store_dir: None,
         memo_prefix: Memo::default(),
         proof_specs: Default::default(),
         trust_threshold: TrustThreshold::default(),
@@ -129,7 +132,13 @@ where
             price: 0.1,
             denom: asset.base.to_owned(),
         },
+        dynamic_gas_price: Default::default(),
+        event_source: Default::default(),
         packet_filter: packet_filter.unwrap_or_default(),
+        fee_filter: FeeFilter::default(),
+        fee_budget: Default::default(),
+        remote_signer: None,
+        wasm_checksum: None,
         address_type: AddressType::default(),
         sequential_batch_tx: false,
         extension_options: Vec::new(),
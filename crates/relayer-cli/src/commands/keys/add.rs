@@ -10,7 +10,7 @@ use abscissa_core::{Command, Runnable};
 use eyre::eyre;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{HDPath, KeyEntry, KeyRing, Store},
+    keyring::{errors::ErrorDetail, HDPath, KeyEntry, KeyRing},
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tracing::warn;
@@ -190,7 +190,7 @@ pub fn add_key(
     hd_path: &HDPath,
     overwrite: bool,
 ) -> eyre::Result<KeyEntry> {
-    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+    let mut keyring = KeyRing::from_chain_config(config)?;
 
     check_key_exists(&keyring, key_name, overwrite);
 
@@ -211,7 +211,7 @@ pub fn restore_key(
     let mnemonic_content =
         fs::read_to_string(mnemonic).map_err(|_| eyre!("error reading the mnemonic file"))?;
 
-    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+    let mut keyring = KeyRing::from_chain_config(config)?;
 
     check_key_exists(&keyring, key_name, overwrite);
 
@@ -224,13 +224,21 @@ pub fn restore_key(
 /// Check if the key with the given key name already exists.
 /// If it already exists and overwrite is false, abort the command with an error.
 /// If overwrite is true, output a warning message informing the key will be overwritten.
+/// If the key cannot be read, eg. because it is encrypted with another passphrase,
+/// abort the command with an error rather than overwriting it.
 fn check_key_exists(keyring: &KeyRing, key_name: &str, overwrite: bool) {
-    if keyring.get_key(key_name).is_ok() {
-        if overwrite {
-            warn!("key {} will be overwritten", key_name);
-        } else {
-            Output::error(format!("A key with name '{}' already exists", key_name)).exit();
+    match keyring.get_key(key_name) {
+        Ok(_) => {
+            if overwrite {
+                warn!("key {} will be overwritten", key_name);
+            } else {
+                Output::error(format!("A key with name '{}' already exists", key_name)).exit();
+            }
         }
+        Err(e) => match e.detail() {
+            ErrorDetail::KeyNotFound(_) | ErrorDetail::KeyFileNotFound(_) => {}
+            _ => Output::error(format!("failed to read key '{}': {}", key_name, e)).exit(),
+        },
     }
 }
 
@@ -4,7 +4,7 @@ use abscissa_core::{Command, Runnable};
 use eyre::eyre;
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyRing, Store},
+    keyring::KeyRing,
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -112,13 +112,13 @@ impl Runnable for KeysDeleteCmd {
 }
 
 pub fn delete_key(config: &ChainConfig, key_name: &str) -> eyre::Result<()> {
-    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+    let mut keyring = KeyRing::from_chain_config(config)?;
     keyring.remove_key(key_name)?;
     Ok(())
 }
 
 pub fn delete_all_keys(config: &ChainConfig) -> eyre::Result<()> {
-    let mut keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
+    let mut keyring = KeyRing::from_chain_config(config)?;
     let keys = keyring.keys()?;
     for key in keys {
         keyring.remove_key(&key.0)?;
@@ -8,7 +8,7 @@ use crate::conclude::Output;
 use crate::{application::app_config, conclude::json};
 use ibc_relayer::{
     config::{ChainConfig, Config},
-    keyring::{KeyEntry, KeyRing, Store},
+    keyring::{KeyRing, KeySigner},
 };
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -53,7 +53,7 @@ impl Runnable for KeysListCmd {
             Ok(keys) => {
                 let mut msg = String::new();
                 for (name, key) in keys {
-                    let _ = write!(msg, "\n- {} ({})", name, key.account);
+                    let _ = write!(msg, "\n- {} ({})", name, key.account());
                 }
                 Output::success_msg(msg).exit()
             }
@@ -67,9 +67,9 @@ pub struct KeysListOptions {
     pub chain_config: ChainConfig,
 }
 
-pub fn list_keys(config: ChainConfig) -> eyre::Result<Vec<(String, KeyEntry)>> {
-    let keyring = KeyRing::new(Store::Test, &config.account_prefix, &config.id)?;
-    let keys = keyring.keys()?;
+pub fn list_keys(config: ChainConfig) -> eyre::Result<Vec<(String, KeySigner)>> {
+    let keyring = KeyRing::from_chain_config(&config)?;
+    let keys = keyring.key_signers()?;
     Ok(keys)
 }
 
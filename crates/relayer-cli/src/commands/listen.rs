@@ -16,9 +16,11 @@ use ibc_relayer_types::{core::ics24_host::identifier::ChainId, events::IbcEvent}
 
 use eyre::eyre;
 use ibc_relayer::{
+    chain::cosmos::query::results::AttributeEncoding,
     config::ChainConfig,
     event::monitor::{EventMonitor, EventReceiver},
 };
+use tendermint_rpc::HttpClient;
 
 use crate::prelude::*;
 
@@ -149,9 +151,17 @@ fn subscribe(
     chain_config: &ChainConfig,
     rt: Arc<TokioRuntime>,
 ) -> eyre::Result<(EventMonitor, EventReceiver)> {
+    let rpc_client = HttpClient::new(chain_config.rpc_addr.clone())
+        .map_err(|e| eyre!("could not create RPC client: {}", e))?;
+
+    let attribute_encoding = rt
+        .block_on(AttributeEncoding::query(&rpc_client))
+        .map_err(|e| eyre!("could not query the version of the node: {}", e))?;
+
     let (mut event_monitor, rx, _) = EventMonitor::new(
         chain_config.id.clone(),
         chain_config.websocket_addr.clone(),
+        attribute_encoding,
         rt,
     )
     .map_err(|e| eyre!("could not initialize event monitor: {}", e))?;
@@ -16,6 +16,7 @@ mod client;
 mod clients;
 mod connection;
 mod connections;
+mod ica;
 mod packet;
 mod transfer;
 mod tx;
@@ -55,6 +56,10 @@ pub enum QueryCmd {
     /// Query information about token transfers
     #[clap(subcommand)]
     Transfer(transfer::TransferCmd),
+
+    /// Query information about interchain accounts
+    #[clap(subcommand)]
+    Ica(ica::IcaCmd),
 }
 
 #[derive(Command, Debug, Parser, Runnable)]
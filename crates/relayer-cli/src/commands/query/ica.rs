@@ -0,0 +1,13 @@
+//! `query ica` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+mod address;
+
+/// `query ica` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum IcaCmd {
+    /// Query the address of an interchain account on its host chain
+    Address(address::IcaAddressCmd),
+}
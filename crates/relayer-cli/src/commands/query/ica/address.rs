@@ -0,0 +1,113 @@
+use core::str::FromStr;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::signer::Signer;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, json, Output};
+
+/// The data structure that represents the arguments when invoking the `query ica address` CLI command.
+///
+/// The command has the following format:
+///
+/// `query ica address --chain <CONTROLLER_CHAIN_ID> --connection <CONNECTION_ID> --owner <OWNER>`
+///
+/// If successful the address of the interchain account on the host chain will be displayed.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct IcaAddressCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "owner",
+        required = true,
+        value_name = "OWNER",
+        help_heading = "REQUIRED",
+        help = "Address of the owner of the interchain account on the controller chain"
+    )]
+    owner: String,
+}
+
+impl Runnable for IcaAddressCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let owner = Signer::from_str(&self.owner).unwrap_or_else(exit_with_unrecoverable_error);
+
+        let chain = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        match chain.query_interchain_account(owner, self.connection_id.clone()) {
+            Ok(address) if json() => Output::success(address).exit(),
+            Ok(address) => Output::success_msg(format!("address: {}", address)).exit(),
+            Err(e) => Output::error(format!(
+                "there was a problem querying the interchain account: {}",
+                e
+            ))
+            .exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IcaAddressCmd;
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_ica_address() {
+        assert_eq!(
+            IcaAddressCmd {
+                chain_id: ChainId::from_string("chain_id"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                owner: "cosmos1owner".to_owned()
+            },
+            IcaAddressCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_id",
+                "--connection",
+                "connection-0",
+                "--owner",
+                "cosmos1owner"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_address_no_owner() {
+        assert!(IcaAddressCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_id",
+            "--connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+}
@@ -8,12 +8,14 @@ use crossbeam_channel::Sender;
 
 use ibc_relayer::chain::handle::{CachingChainHandle, ChainHandle};
 use ibc_relayer::config::Config;
+use ibc_relayer::keyring::{self, Store};
 use ibc_relayer::registry::SharedRegistry;
 use ibc_relayer::rest;
 use ibc_relayer::supervisor::{cmd::SupervisorCmd, spawn_supervisor, SupervisorHandle};
 
 use crate::conclude::json;
 use crate::conclude::Output;
+use crate::config::{validate_config, Diagnostic};
 use crate::prelude::*;
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
@@ -29,6 +31,22 @@ impl Runnable for StartCmd {
     fn run(&self) {
         let config = (*app_config()).clone();
 
+        // Read the passphrase of the encrypted keyring upfront, as it can only
+        // be read once when it is passed through a file descriptor.
+        if config
+            .chains
+            .iter()
+            .any(|chain| chain.key_store_type == Store::Encrypted)
+        {
+            keyring::unlock().unwrap_or_else(|e| {
+                Output::error(format!(
+                    "Hermes failed to unlock the encrypted keyring: {}",
+                    e
+                ))
+                .exit()
+            });
+        }
+
         let supervisor_handle = make_supervisor::<CachingChainHandle>(config, self.full_scan)
             .unwrap_or_else(|e| {
                 Output::error(format!("Hermes failed to start, last error: {}", e)).exit()
@@ -52,13 +70,13 @@ impl Runnable for StartCmd {
 }
 
 /// Register the SIGHUP and SIGUSR1 signals, and notify the supervisor.
-/// - [DEPRECATED] SIGHUP: Trigger a reload of the configuration.
+/// - SIGHUP: Trigger a reload of the configuration.
 /// - SIGUSR1: Ask the supervisor to dump its state and print it to the console.
 fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     use signal_hook::{consts::signal::*, iterator::Signals};
 
     let sigs = vec![
-        SIGHUP,  // Reload of configuration (disabled)
+        SIGHUP,  // Reload of configuration
         SIGUSR1, // Dump state
     ];
 
@@ -67,10 +85,37 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     std::thread::spawn(move || {
         for signal in &mut signals {
             match signal {
-                SIGHUP => warn!(
-                    "configuration reloading via SIGHUP has been disabled, \
-                     the signal handler will be removed in the future"
-                ),
+                SIGHUP => {
+                    info!("reloading configuration (triggered by SIGHUP)");
+
+                    let config = match reload_config() {
+                        Ok(config) => config,
+                        Err(e) => {
+                            error!("failed to reload configuration: {}", e);
+                            continue;
+                        }
+                    };
+
+                    let (tx, rx) = crossbeam_channel::bounded(1);
+                    let cmd = SupervisorCmd::UpdateConfig(Box::new(config), tx);
+
+                    if let Err(e) = tx_cmd.try_send(cmd) {
+                        error!(
+                            "failed to send the reloaded configuration to the supervisor: {}",
+                            e
+                        );
+                        continue;
+                    }
+
+                    std::thread::spawn(move || {
+                        if let Ok(updates) = rx.recv() {
+                            for update in &updates {
+                                info!("applied configuration update: {}", update);
+                            }
+                        }
+                    });
+                }
+
                 SIGUSR1 => {
                     info!("dumping state (triggered by SIGUSR1)");
 
@@ -101,6 +146,30 @@ fn register_signals(tx_cmd: Sender<SupervisorCmd>) -> Result<(), io::Error> {
     Ok(())
 }
 
+/// Load the configuration file and validate it, before it is sent to the supervisor.
+fn reload_config() -> Result<Config, String> {
+    let path = crate::config::config_path()
+        .ok_or_else(|| "cannot figure out configuration path".to_string())?;
+
+    let config = ibc_relayer::config::load(path).map_err(|e| e.to_string())?;
+
+    check_config(&config)?;
+
+    Ok(config)
+}
+
+/// Validate the configuration, only logging the warnings.
+fn check_config(config: &Config) -> Result<(), String> {
+    match validate_config(config) {
+        Ok(()) => Ok(()),
+        Err(Diagnostic::Warning(e)) => {
+            warn!("relayer may be misconfigured: {}", e);
+            Ok(())
+        }
+        Err(Diagnostic::Error(e)) => Err(e.to_string()),
+    }
+}
+
 #[cfg(feature = "rest-server")]
 fn spawn_rest_server(config: &Config) -> Option<rest::Receiver> {
     let _span = tracing::error_span!("rest").entered();
@@ -140,6 +209,12 @@ fn spawn_telemetry_server(config: &Config) -> Result<(), Box<dyn Error + Send +
     let state = ibc_telemetry::global();
 
     let telemetry = config.telemetry.clone();
+
+    state.set_latency_buckets(ibc_telemetry::state::LatencyBuckets {
+        recv: telemetry.buckets.packet_recv_latency.clone(),
+        ack: telemetry.buckets.packet_ack_latency.clone(),
+    });
+
     if telemetry.enabled {
         match ibc_telemetry::spawn((telemetry.host, telemetry.port), state.clone()) {
             Ok((addr, _)) => {
@@ -155,6 +230,17 @@ fn spawn_telemetry_server(config: &Config) -> Result<(), Box<dyn Error + Send +
         }
     }
 
+    let traces = config.telemetry.traces.clone();
+    if traces.enabled {
+        ibc_telemetry::spawn_trace_exporter(
+            traces.endpoint.clone(),
+            traces.service_name,
+            state.clone(),
+        );
+
+        info!("exporting packet traces to {}", traces.endpoint);
+    }
+
     Ok(())
 }
 
@@ -167,6 +253,13 @@ fn spawn_telemetry_server(config: &Config) -> Result<(), Box<dyn Error + Send +
         );
     }
 
+    if config.telemetry.traces.enabled {
+        warn!(
+            "packet traces enabled in the config but Hermes was built without telemetry support, \
+             build Hermes with --features=telemetry to enable telemetry support."
+        );
+    }
+
     Ok(())
 }
 
@@ -186,6 +279,8 @@ fn make_supervisor<Chain: ChainHandle>(
         SupervisorOptions {
             health_check: true,
             force_full_scan,
+            config_path: crate::config::config_path(),
+            validate_config: check_config,
         },
     )?)
 }
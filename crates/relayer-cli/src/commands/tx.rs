@@ -6,7 +6,9 @@ use ibc_relayer::config::Config;
 mod channel;
 pub(crate) mod client;
 mod connection;
+mod ica;
 mod packet;
+mod recover_client;
 mod transfer;
 mod upgrade;
 
@@ -44,23 +46,59 @@ pub enum TxCmd {
     /// Confirm the closing of a channel (ChannelCloseConfirm)
     ChanCloseConfirm(channel::TxChanCloseConfirmCmd),
 
+    /// Initiate the upgrade of a channel (ChannelUpgradeInit)
+    ///
+    /// The chain only accepts this message from the authority configured for channel
+    /// upgrades, which defaults to the governance module. Unless the key of the destination
+    /// chain is that authority, submit the message in a governance proposal instead.
+    ChanUpgradeInit(channel::TxChanUpgradeInitCmd),
+
+    /// Relay the channel upgrade attempt (ChannelUpgradeTry)
+    ChanUpgradeTry(channel::TxChanUpgradeTryCmd),
+
+    /// Relay acknowledgment of a channel upgrade attempt (ChannelUpgradeAck)
+    ChanUpgradeAck(channel::TxChanUpgradeAckCmd),
+
+    /// Confirm the upgrade of a channel (ChannelUpgradeConfirm)
+    ChanUpgradeConfirm(channel::TxChanUpgradeConfirmCmd),
+
+    /// Open an upgraded channel once its packets in flight are flushed (ChannelUpgradeOpen)
+    ChanUpgradeOpen(channel::TxChanUpgradeOpenCmd),
+
+    /// Abort a channel upgrade which timed out on the counterparty (ChannelUpgradeTimeout)
+    ChanUpgradeTimeout(channel::TxChanUpgradeTimeoutCmd),
+
+    /// Abort a channel upgrade which failed on the counterparty (ChannelUpgradeCancel)
+    ChanUpgradeCancel(channel::TxChanUpgradeCancelCmd),
+
     /// Send a fungible token transfer test transaction (ICS20 MsgTransfer)
     FtTransfer(transfer::TxIcs20MsgTransferCmd),
 
+    /// Register and use interchain accounts (ICS27)
+    #[clap(subcommand)]
+    Ica(ica::TxIcaCmd),
+
     /// Relay receive or timeout packets
     PacketRecv(packet::TxPacketRecvCmd),
 
     /// Relay acknowledgment packets
     PacketAck(packet::TxPacketAckCmd),
 
+    /// Relay timeout packets for the pending packets which expired on the destination chain
+    PacketTimeout(packet::TxPacketTimeoutCmd),
+
     /// Send an IBC upgrade plan
     UpgradeChain(upgrade::TxIbcUpgradeChainCmd),
+
+    /// Submit a governance proposal to recover an expired or frozen client
+    ClientRecover(recover_client::TxClientRecoverCmd),
 }
 
 impl Override<Config> for TxCmd {
     fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
         match self {
             Self::FtTransfer(cmd) => cmd.override_config(config),
+            Self::Ica(cmd) => cmd.override_config(config),
             _ => Ok(config),
         }
     }
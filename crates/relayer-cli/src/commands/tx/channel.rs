@@ -2,10 +2,14 @@ use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 
 use ibc_relayer::chain::handle::ChainHandle;
-use ibc_relayer::chain::requests::{IncludeProof, QueryConnectionRequest, QueryHeight};
+use ibc_relayer::chain::requests::{
+    IncludeProof, QueryChannelRequest, QueryConnectionRequest, QueryHeight,
+};
+use ibc_relayer::channel::version::Version;
 use ibc_relayer::channel::{Channel, ChannelSide};
 use ibc_relayer_types::core::ics03_connection::connection::ConnectionEnd;
 use ibc_relayer_types::core::ics04_channel::channel::Order;
+use ibc_relayer_types::core::ics04_channel::upgrade::UpgradeFields;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
@@ -263,7 +267,762 @@ impl Runnable for TxChanOpenTryCmd {
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanOpenAckCmd {
+pub struct TxChanOpenAckCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanOpenAckCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanOpenAck",
+            build_chan_open_ack_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Order::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanOpenConfirmCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanOpenConfirmCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanOpenConfirm",
+            build_chan_open_confirm_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Order::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanCloseInitCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanCloseInitCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanCloseInit",
+            build_chan_close_init_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Order::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanCloseConfirmCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanCloseConfirmCmd {
+    fn run(&self) {
+        tx_chan_cmd!(
+            "ChanCloseConfirm",
+            build_chan_close_confirm_and_send,
+            self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                Channel {
+                    connection_delay: Default::default(),
+                    ordering: Order::default(),
+                    a_side: ChannelSide::new(
+                        chains.src,
+                        ClientId::default(),
+                        ConnectionId::default(),
+                        self.src_port_id.clone(),
+                        Some(self.src_chan_id.clone()),
+                        None,
+                    ),
+                    b_side: ChannelSide::new(
+                        chains.dst,
+                        dst_connection.client_id().clone(),
+                        self.dst_conn_id.clone(),
+                        self.dst_port_id.clone(),
+                        Some(self.dst_chan_id.clone()),
+                        None,
+                    ),
+                }
+            }
+        );
+    }
+}
+
+macro_rules! tx_chan_upgrade_cmd {
+    ($dbg_string:literal, $func:ident, $self:expr) => {
+        tx_chan_cmd!(
+            $dbg_string,
+            $func,
+            $self,
+            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
+                upgrade_channel(
+                    chains,
+                    dst_connection,
+                    &$self.dst_conn_id,
+                    &$self.dst_port_id,
+                    &$self.dst_chan_id,
+                    &$self.src_port_id,
+                    &$self.src_chan_id,
+                )
+            }
+        )
+    };
+}
+
+/// Builds the channel whose destination end is upgraded by the channel upgrade commands.
+fn upgrade_channel<Chain: ChainHandle>(
+    chains: ChainHandlePair<Chain>,
+    dst_connection: ConnectionEnd,
+    dst_conn_id: &ConnectionId,
+    dst_port_id: &PortId,
+    dst_chan_id: &ChannelId,
+    src_port_id: &PortId,
+    src_chan_id: &ChannelId,
+) -> Channel<Chain, Chain> {
+    Channel {
+        connection_delay: Default::default(),
+        ordering: Order::default(),
+        a_side: ChannelSide::new(
+            chains.src,
+            ClientId::default(),
+            ConnectionId::default(),
+            src_port_id.clone(),
+            Some(src_chan_id.clone()),
+            None,
+        ),
+        b_side: ChannelSide::new(
+            chains.dst,
+            dst_connection.client_id().clone(),
+            dst_conn_id.clone(),
+            dst_port_id.clone(),
+            Some(dst_chan_id.clone()),
+            None,
+        ),
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanUpgradeInitCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection, which the upgraded channel will use"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+
+    #[clap(
+        long = "version",
+        required = true,
+        value_name = "VERSION",
+        help_heading = "REQUIRED",
+        help = "The version of the upgraded channel"
+    )]
+    version: Version,
+
+    #[clap(
+        long = "order",
+        value_name = "ORDER",
+        help = "The ordering of the upgraded channel, valid options 'unordered' and 'ordered'. Defaults to the current ordering of the channel"
+    )]
+    order: Option<Order>,
+}
+
+impl Runnable for TxChanUpgradeInitCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        };
+
+        // Retrieve the connection
+        let dst_connection = match chains.dst.query_connection(
+            QueryConnectionRequest {
+                connection_id: self.dst_conn_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        ) {
+            Ok((connection, _)) => connection,
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        };
+
+        // Keep the current ordering of the channel unless another one is given
+        let order = match self.order {
+            Some(order) => order,
+            None => match chains.dst.query_channel(
+                QueryChannelRequest {
+                    port_id: self.dst_port_id.clone(),
+                    channel_id: self.dst_chan_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            ) {
+                Ok((channel_end, _)) => channel_end.ordering,
+                Err(e) => Output::error(format!("{}", e)).exit(),
+            },
+        };
+
+        let fields =
+            UpgradeFields::new(order, vec![self.dst_conn_id.clone()], self.version.clone());
+
+        let channel = upgrade_channel(
+            chains,
+            dst_connection,
+            &self.dst_conn_id,
+            &self.dst_port_id,
+            &self.dst_chan_id,
+            &self.src_port_id,
+            &self.src_chan_id,
+        );
+
+        info!("message ChanUpgradeInit: {}", channel);
+
+        let res: Result<IbcEvent, Error> = channel
+            .build_chan_upgrade_init_and_send(fields)
+            .map_err(Error::channel);
+
+        match res {
+            Ok(receipt) => Output::success(receipt).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanUpgradeTryCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanUpgradeTryCmd {
+    fn run(&self) {
+        tx_chan_upgrade_cmd!("ChanUpgradeTry", build_chan_upgrade_try_and_send, self);
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanUpgradeAckCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "dst-connection",
+        visible_alias = "dst-conn",
+        required = true,
+        value_name = "DST_CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination connection"
+    )]
+    dst_conn_id: ConnectionId,
+
+    #[clap(
+        long = "dst-port",
+        required = true,
+        value_name = "DST_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination port"
+    )]
+    dst_port_id: PortId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "dst-channel",
+        visible_alias = "dst-chan",
+        required = true,
+        value_name = "DST_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination channel (required)"
+    )]
+    dst_chan_id: ChannelId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel (required)"
+    )]
+    src_chan_id: ChannelId,
+}
+
+impl Runnable for TxChanUpgradeAckCmd {
+    fn run(&self) {
+        tx_chan_upgrade_cmd!("ChanUpgradeAck", build_chan_upgrade_ack_and_send, self);
+    }
+}
+
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxChanUpgradeConfirmCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -331,40 +1090,18 @@ pub struct TxChanOpenAckCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanOpenAckCmd {
+impl Runnable for TxChanUpgradeConfirmCmd {
     fn run(&self) {
-        tx_chan_cmd!(
-            "ChanOpenAck",
-            build_chan_open_ack_and_send,
-            self,
-            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
-                Channel {
-                    connection_delay: Default::default(),
-                    ordering: Order::default(),
-                    a_side: ChannelSide::new(
-                        chains.src,
-                        ClientId::default(),
-                        ConnectionId::default(),
-                        self.src_port_id.clone(),
-                        Some(self.src_chan_id.clone()),
-                        None,
-                    ),
-                    b_side: ChannelSide::new(
-                        chains.dst,
-                        dst_connection.client_id().clone(),
-                        self.dst_conn_id.clone(),
-                        self.dst_port_id.clone(),
-                        Some(self.dst_chan_id.clone()),
-                        None,
-                    ),
-                }
-            }
+        tx_chan_upgrade_cmd!(
+            "ChanUpgradeConfirm",
+            build_chan_upgrade_confirm_and_send,
+            self
         );
     }
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanOpenConfirmCmd {
+pub struct TxChanUpgradeOpenCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -432,40 +1169,14 @@ pub struct TxChanOpenConfirmCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanOpenConfirmCmd {
+impl Runnable for TxChanUpgradeOpenCmd {
     fn run(&self) {
-        tx_chan_cmd!(
-            "ChanOpenConfirm",
-            build_chan_open_confirm_and_send,
-            self,
-            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
-                Channel {
-                    connection_delay: Default::default(),
-                    ordering: Order::default(),
-                    a_side: ChannelSide::new(
-                        chains.src,
-                        ClientId::default(),
-                        ConnectionId::default(),
-                        self.src_port_id.clone(),
-                        Some(self.src_chan_id.clone()),
-                        None,
-                    ),
-                    b_side: ChannelSide::new(
-                        chains.dst,
-                        dst_connection.client_id().clone(),
-                        self.dst_conn_id.clone(),
-                        self.dst_port_id.clone(),
-                        Some(self.dst_chan_id.clone()),
-                        None,
-                    ),
-                }
-            }
-        );
+        tx_chan_upgrade_cmd!("ChanUpgradeOpen", build_chan_upgrade_open_and_send, self);
     }
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanCloseInitCmd {
+pub struct TxChanUpgradeTimeoutCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -533,40 +1244,18 @@ pub struct TxChanCloseInitCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanCloseInitCmd {
+impl Runnable for TxChanUpgradeTimeoutCmd {
     fn run(&self) {
-        tx_chan_cmd!(
-            "ChanCloseInit",
-            build_chan_close_init_and_send,
-            self,
-            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
-                Channel {
-                    connection_delay: Default::default(),
-                    ordering: Order::default(),
-                    a_side: ChannelSide::new(
-                        chains.src,
-                        ClientId::default(),
-                        ConnectionId::default(),
-                        self.src_port_id.clone(),
-                        Some(self.src_chan_id.clone()),
-                        None,
-                    ),
-                    b_side: ChannelSide::new(
-                        chains.dst,
-                        dst_connection.client_id().clone(),
-                        self.dst_conn_id.clone(),
-                        self.dst_port_id.clone(),
-                        Some(self.dst_chan_id.clone()),
-                        None,
-                    ),
-                }
-            }
+        tx_chan_upgrade_cmd!(
+            "ChanUpgradeTimeout",
+            build_chan_upgrade_timeout_and_send,
+            self
         );
     }
 }
 
 #[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
-pub struct TxChanCloseConfirmCmd {
+pub struct TxChanUpgradeCancelCmd {
     #[clap(
         long = "dst-chain",
         required = true,
@@ -634,34 +1323,12 @@ pub struct TxChanCloseConfirmCmd {
     src_chan_id: ChannelId,
 }
 
-impl Runnable for TxChanCloseConfirmCmd {
+impl Runnable for TxChanUpgradeCancelCmd {
     fn run(&self) {
-        tx_chan_cmd!(
-            "ChanCloseConfirm",
-            build_chan_close_confirm_and_send,
-            self,
-            |chains: ChainHandlePair, dst_connection: ConnectionEnd| {
-                Channel {
-                    connection_delay: Default::default(),
-                    ordering: Order::default(),
-                    a_side: ChannelSide::new(
-                        chains.src,
-                        ClientId::default(),
-                        ConnectionId::default(),
-                        self.src_port_id.clone(),
-                        Some(self.src_chan_id.clone()),
-                        None,
-                    ),
-                    b_side: ChannelSide::new(
-                        chains.dst,
-                        dst_connection.client_id().clone(),
-                        self.dst_conn_id.clone(),
-                        self.dst_port_id.clone(),
-                        Some(self.dst_chan_id.clone()),
-                        None,
-                    ),
-                }
-            }
+        tx_chan_upgrade_cmd!(
+            "ChanUpgradeCancel",
+            build_chan_upgrade_cancel_and_send,
+            self
         );
     }
 }
@@ -670,12 +1337,13 @@ impl Runnable for TxChanCloseConfirmCmd {
 mod tests {
     use super::{
         TxChanCloseConfirmCmd, TxChanCloseInitCmd, TxChanOpenAckCmd, TxChanOpenConfirmCmd,
-        TxChanOpenInitCmd, TxChanOpenTryCmd,
+        TxChanOpenInitCmd, TxChanOpenTryCmd, TxChanUpgradeInitCmd, TxChanUpgradeTryCmd,
     };
 
     use std::str::FromStr;
 
     use abscissa_core::clap::Parser;
+    use ibc_relayer::channel::version::Version;
     use ibc_relayer_types::core::{
         ics04_channel::channel::Order,
         ics24_host::identifier::{ChainId, ChannelId, ConnectionId, PortId},
@@ -1861,4 +2529,152 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_chan_upgrade_init_required_only() {
+        assert_eq!(
+            TxChanUpgradeInitCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                version: Version::from_str("ics20-2").unwrap(),
+                order: None
+            },
+            TxChanUpgradeInitCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-connection",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-channel",
+                "channel_b",
+                "--src-channel",
+                "channel_a",
+                "--version",
+                "ics20-2"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_chan_upgrade_init_order() {
+        assert_eq!(
+            TxChanUpgradeInitCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap(),
+                version: Version::from_str("ics20-2").unwrap(),
+                order: Some(Order::Ordered)
+            },
+            TxChanUpgradeInitCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-conn",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-chan",
+                "channel_b",
+                "--src-chan",
+                "channel_a",
+                "--version",
+                "ics20-2",
+                "--order",
+                "ordered"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_chan_upgrade_init_no_version() {
+        assert!(TxChanUpgradeInitCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_b",
+            "--src-chain",
+            "chain_a",
+            "--dst-connection",
+            "connection_b",
+            "--dst-port",
+            "port_b",
+            "--src-port",
+            "port_a",
+            "--dst-channel",
+            "channel_b",
+            "--src-channel",
+            "channel_a"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_chan_upgrade_try_required_only() {
+        assert_eq!(
+            TxChanUpgradeTryCmd {
+                dst_chain_id: ChainId::from_string("chain_b"),
+                src_chain_id: ChainId::from_string("chain_a"),
+                dst_conn_id: ConnectionId::from_str("connection_b").unwrap(),
+                dst_port_id: PortId::from_str("port_b").unwrap(),
+                src_port_id: PortId::from_str("port_a").unwrap(),
+                dst_chan_id: ChannelId::from_str("channel_b").unwrap(),
+                src_chan_id: ChannelId::from_str("channel_a").unwrap()
+            },
+            TxChanUpgradeTryCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_b",
+                "--src-chain",
+                "chain_a",
+                "--dst-connection",
+                "connection_b",
+                "--dst-port",
+                "port_b",
+                "--src-port",
+                "port_a",
+                "--dst-channel",
+                "channel_b",
+                "--src-channel",
+                "channel_a"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_chan_upgrade_try_no_b_channel() {
+        assert!(TxChanUpgradeTryCmd::try_parse_from([
+            "test",
+            "--dst-chain",
+            "chain_b",
+            "--src-chain",
+            "chain_a",
+            "--dst-connection",
+            "connection_b",
+            "--dst-port",
+            "port_b",
+            "--src-port",
+            "port_a",
+            "--src-channel",
+            "channel_a"
+        ])
+        .is_err())
+    }
 }
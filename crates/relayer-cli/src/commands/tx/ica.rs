@@ -0,0 +1,27 @@
+//! `tx ica` subcommand
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{config::Override, Command, Runnable};
+use ibc_relayer::config::Config;
+
+mod register;
+mod send;
+
+/// `tx ica` subcommand
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum TxIcaCmd {
+    /// Register an interchain account on a host chain (MsgRegisterInterchainAccount)
+    Register(register::TxIcaRegisterCmd),
+
+    /// Execute messages on a host chain with an interchain account (MsgSendTx)
+    Send(send::TxIcaSendCmd),
+}
+
+impl Override<Config> for TxIcaCmd {
+    fn override_config(&self, config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        match self {
+            Self::Register(cmd) => cmd.override_config(config),
+            Self::Send(cmd) => cmd.override_config(config),
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{config::Override, Command, FrameworkErrorKind, Runnable};
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::tracking::TrackedMsgs;
+use ibc_relayer::channel::{Channel, ChannelError};
+use ibc_relayer::config::Config;
+use ibc_relayer_types::applications::ics27_ica::msgs::register_account::build_register_interchain_account_message;
+use ibc_relayer_types::core::ics04_channel::version::Version;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::events::IbcEvent;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `tx ica register` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx ica register --chain <CONTROLLER_CHAIN_ID> --connection <CONNECTION_ID> [--host-chain <HOST_CHAIN_ID>]`
+///
+/// The owner of the interchain account is the signing key of the controller chain.
+/// If the host chain is given, the channel handshake opened by the registration
+/// is completed right away, otherwise it is left to a running relayer.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaRegisterCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "version",
+        value_name = "VERSION",
+        help = "Version of the interchain accounts channel. If omitted, the controller chain picks the default metadata"
+    )]
+    version: Option<Version>,
+
+    #[clap(
+        long = "host-chain",
+        value_name = "HOST_CHAIN_ID",
+        help = "Identifier of the host chain, to complete the channel handshake with"
+    )]
+    host_chain_id: Option<ChainId>,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl Override<Config> for TxIcaRegisterCmd {
+    fn override_config(&self, mut config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        let chain_config = config.find_chain_mut(&self.chain_id).ok_or_else(|| {
+            FrameworkErrorKind::ComponentError.context(format!(
+                "missing configuration for controller chain '{}'",
+                self.chain_id
+            ))
+        })?;
+
+        if let Some(ref key_name) = self.key_name {
+            chain_config.key_name = key_name.to_string();
+        }
+
+        Ok(config)
+    }
+}
+
+impl Runnable for TxIcaRegisterCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let controller = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let event = register_interchain_account(
+            &controller,
+            &self.connection_id,
+            self.version.clone().unwrap_or_else(Version::empty),
+        )
+        .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let host_chain_id = match &self.host_chain_id {
+            Some(host_chain_id) => host_chain_id,
+            None => Output::success(event).exit(),
+        };
+
+        let host = spawn_chain_runtime(&config, host_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let res = Channel::restore_from_event(controller, host, event)
+            .and_then(|mut channel| channel.handshake().map(|_| channel));
+
+        match res {
+            Ok(channel) => Output::success(channel).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+/// Registers an interchain account for the signer of the controller chain,
+/// and returns the event of the channel opened for it on the controller chain.
+fn register_interchain_account(
+    controller: &impl ChainHandle,
+    connection_id: &ConnectionId,
+    version: Version,
+) -> Result<IbcEvent, Error> {
+    let owner = controller.get_signer().map_err(Error::relayer)?;
+
+    let message = build_register_interchain_account_message(&owner, connection_id, &version)
+        .map_err(Error::ica)?;
+
+    let messages = TrackedMsgs::new_static(vec![message], "cli");
+
+    let events = controller
+        .send_messages_and_wait_commit(messages)
+        .map_err(Error::relayer)?;
+
+    let result = events
+        .into_iter()
+        .find(|event_with_height| {
+            matches!(
+                event_with_height.event,
+                IbcEvent::OpenInitChannel(_) | IbcEvent::ChainError(_)
+            )
+        })
+        .ok_or_else(|| {
+            Error::channel(ChannelError::missing_event(
+                "no chan init event was in the response".to_string(),
+            ))
+        })?;
+
+    match result.event {
+        IbcEvent::ChainError(e) => Err(Error::channel(ChannelError::tx_response(e))),
+        event => Ok(event),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TxIcaRegisterCmd;
+
+    use abscissa_core::clap::Parser;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics04_channel::version::Version;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_ica_register_required_only() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                version: None,
+                host_chain_id: None,
+                key_name: None,
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--connection",
+                "connection-0"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_all_options() {
+        assert_eq!(
+            TxIcaRegisterCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                version: Some(Version::new("ics27-1".to_owned())),
+                host_chain_id: Some(ChainId::from_string("chain_b")),
+                key_name: Some("key_name".to_owned()),
+            },
+            TxIcaRegisterCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--conn",
+                "connection-0",
+                "--version",
+                "ics27-1",
+                "--host-chain",
+                "chain_b",
+                "--key-name",
+                "key_name"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_register_no_connection() {
+        assert!(TxIcaRegisterCmd::try_parse_from(["test", "--chain", "chain_a"]).is_err())
+    }
+}
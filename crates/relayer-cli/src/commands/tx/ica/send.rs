@@ -0,0 +1,418 @@
+use core::time::Duration;
+use std::path::PathBuf;
+
+use abscissa_core::clap::Parser;
+use abscissa_core::{config::Override, Command, FrameworkErrorKind, Runnable};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use subtle_encoding::base64;
+
+use ibc_proto::cosmos::bank::v1beta1::{MsgMultiSend, MsgSend};
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::transfer::v1::MsgTransfer;
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::tracking::TrackedMsgs;
+use ibc_relayer::config::Config;
+use ibc_relayer::event::IbcEventWithHeight;
+use ibc_relayer_types::applications::ics27_ica::msgs::send_tx::build_send_tx_message;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+use ibc_relayer_types::tx_msg::encode_message;
+
+use crate::application::app_config;
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+
+/// The data structure that represents the arguments when invoking the `tx ica send` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx ica send --chain <CONTROLLER_CHAIN_ID> --connection <CONNECTION_ID> --msg-file <PATH>`
+///
+/// The messages file holds a JSON array of the messages to execute on the host chain,
+/// each either in the proto-JSON encoding output by the Cosmos SDK, e.g.
+///
+/// `[{ "@type": "/cosmos.bank.v1beta1.MsgSend", "from_address": "cosmos1...", ... }]`
+///
+/// or with its `type_url` and its base64-encoded protobuf `value`, e.g.
+///
+/// `[{ "type_url": "/cosmos.bank.v1beta1.MsgSend", "value": "CgVjb3Ntb3M..." }]`
+///
+/// The proto-JSON encoding is supported for the bank `MsgSend` and `MsgMultiSend`
+/// messages and for the ICS-20 `MsgTransfer` message, while any other message
+/// must be given already encoded.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxIcaSendCmd {
+    #[clap(
+        long = "chain",
+        required = true,
+        value_name = "CONTROLLER_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the controller chain"
+    )]
+    chain_id: ChainId,
+
+    #[clap(
+        long = "connection",
+        visible_alias = "conn",
+        required = true,
+        value_name = "CONNECTION_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the connection to the host chain"
+    )]
+    connection_id: ConnectionId,
+
+    #[clap(
+        long = "msg-file",
+        required = true,
+        value_name = "PATH",
+        help_heading = "REQUIRED",
+        help = "Path to a JSON file with the messages to execute on the host chain. \
+                Only `MsgSend`, `MsgMultiSend` and `MsgTransfer` can be given in proto-JSON, \
+                any other message must be given with its `type_url` and its base64-encoded \
+                protobuf `value`"
+    )]
+    msg_file: PathBuf,
+
+    #[clap(
+        long = "memo",
+        value_name = "MEMO",
+        default_value = "",
+        help = "Memo of the interchain accounts packet"
+    )]
+    memo: String,
+
+    #[clap(
+        long = "timeout-seconds",
+        default_value = "600",
+        value_name = "TIMEOUT_SECONDS",
+        help = "Timeout of the interchain accounts packet, in seconds since current"
+    )]
+    timeout_seconds: u64,
+
+    #[clap(
+        long = "key-name",
+        value_name = "KEY_NAME",
+        help = "Use the given signing key name (default: `key_name` config)"
+    )]
+    key_name: Option<String>,
+}
+
+impl Override<Config> for TxIcaSendCmd {
+    fn override_config(&self, mut config: Config) -> Result<Config, abscissa_core::FrameworkError> {
+        let chain_config = config.find_chain_mut(&self.chain_id).ok_or_else(|| {
+            FrameworkErrorKind::ComponentError.context(format!(
+                "missing configuration for controller chain '{}'",
+                self.chain_id
+            ))
+        })?;
+
+        if let Some(ref key_name) = self.key_name {
+            chain_config.key_name = key_name.to_string();
+        }
+
+        Ok(config)
+    }
+}
+
+impl Runnable for TxIcaSendCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let messages = std::fs::read_to_string(&self.msg_file)
+            .map_err(Error::io)
+            .and_then(|json| parse_messages(&json))
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let controller = spawn_chain_runtime(&config, &self.chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let res = send_tx(
+            &controller,
+            &self.connection_id,
+            messages,
+            self.memo.clone(),
+            Duration::from_secs(self.timeout_seconds),
+        );
+
+        match res {
+            Ok(events) => Output::success(events).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+/// A message to execute on the host chain, as given in the messages file.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawMessage {
+    Json {
+        #[serde(rename = "@type")]
+        type_url: String,
+        #[serde(flatten)]
+        fields: Map<String, Value>,
+    },
+    Encoded {
+        type_url: String,
+        value: String,
+    },
+}
+
+fn parse_messages(json: &str) -> Result<Vec<Any>, Error> {
+    let raw_messages: Vec<RawMessage> = serde_json::from_str(json)
+        .map_err(|e| Error::cli_arg(format!("invalid messages file: {}", e)))?;
+
+    raw_messages
+        .into_iter()
+        .map(|raw| match raw {
+            RawMessage::Json { type_url, fields } => {
+                let value = encode_json_message(&type_url, fields)?;
+                Ok(Any { type_url, value })
+            }
+            RawMessage::Encoded { type_url, value } => {
+                let value = base64::decode(&value).map_err(|e| {
+                    Error::cli_arg(format!(
+                        "invalid base64 value of message '{}': {}",
+                        type_url, e
+                    ))
+                })?;
+
+                Ok(Any { type_url, value })
+            }
+        })
+        .collect()
+}
+
+/// Encodes in Protobuf a message given in proto-JSON, for the supported message types.
+fn encode_json_message(type_url: &str, fields: Map<String, Value>) -> Result<Vec<u8>, Error> {
+    let encoded = match type_url {
+        "/cosmos.bank.v1beta1.MsgSend" => encode_json::<MsgSend>(fields, &[]),
+        "/cosmos.bank.v1beta1.MsgMultiSend" => encode_json::<MsgMultiSend>(fields, &[]),
+        "/ibc.applications.transfer.v1.MsgTransfer" => encode_json::<MsgTransfer>(
+            fields,
+            &[
+                "/timeout_height/revision_number",
+                "/timeout_height/revision_height",
+                "/timeout_timestamp",
+            ],
+        ),
+        _ => {
+            return Err(Error::cli_arg(format!(
+                "message '{}' cannot be given in JSON, \
+                give its `type_url` and its base64-encoded protobuf `value` instead",
+                type_url
+            )))
+        }
+    };
+
+    encoded.map_err(|e| Error::cli_arg(format!("invalid message '{}': {}", type_url, e)))
+}
+
+/// Decodes a message from its proto-JSON fields, given the paths of its
+/// 64-bit integer fields, and encodes it in Protobuf.
+fn encode_json<M>(fields: Map<String, Value>, integer_fields: &[&str]) -> Result<Vec<u8>, String>
+where
+    M: prost::Message + Default + Serialize + DeserializeOwned,
+{
+    // Proto-JSON omits the fields which are set to their default value
+    let mut message = serde_json::to_value(M::default()).map_err(|e| e.to_string())?;
+
+    if let Value::Object(defaults) = &mut message {
+        for (name, value) in fields {
+            defaults.insert(to_snake_case(&name), snake_case_keys(value));
+        }
+    }
+
+    // Proto-JSON encodes the 64-bit integers as strings
+    for pointer in integer_fields {
+        if let Some(value) = message.pointer_mut(pointer) {
+            if let Some(n) = value.as_str().and_then(|s| s.parse::<u64>().ok()) {
+                *value = Value::from(n);
+            }
+        }
+    }
+
+    let message: M = serde_json::from_value(message).map_err(|e| e.to_string())?;
+    encode_message(&message).map_err(|e| e.to_string())
+}
+
+/// Proto-JSON uses the `lowerCamelCase` names of the fields, while the Cosmos SDK
+/// outputs their original `snake_case` names, which are used by the Rust types.
+fn snake_case_keys(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (to_snake_case(&name), snake_case_keys(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(snake_case_keys).collect()),
+        value => value,
+    }
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::with_capacity(name.len());
+
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            snake_case.push('_');
+            snake_case.push(c.to_ascii_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+
+    snake_case
+}
+
+fn send_tx(
+    controller: &impl ChainHandle,
+    connection_id: &ConnectionId,
+    messages: Vec<Any>,
+    memo: String,
+    relative_timeout: Duration,
+) -> Result<Vec<IbcEventWithHeight>, Error> {
+    let owner = controller.get_signer().map_err(Error::relayer)?;
+
+    let message = build_send_tx_message(&owner, connection_id, messages, memo, relative_timeout)
+        .map_err(Error::ica)?;
+
+    let messages = TrackedMsgs::new_static(vec![message], "cli");
+
+    controller
+        .send_messages_and_wait_commit(messages)
+        .map_err(Error::relayer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_messages, TxIcaSendCmd};
+
+    use abscissa_core::clap::Parser;
+    use ibc_proto::cosmos::bank::v1beta1::MsgSend;
+    use ibc_proto::ibc::applications::transfer::v1::MsgTransfer;
+    use prost::Message;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ConnectionId};
+
+    #[test]
+    fn test_ica_send_required_only() {
+        assert_eq!(
+            TxIcaSendCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                msg_file: PathBuf::from("msgs.json"),
+                memo: "".to_owned(),
+                timeout_seconds: 600,
+                key_name: None,
+            },
+            TxIcaSendCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--connection",
+                "connection-0",
+                "--msg-file",
+                "msgs.json"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_send_memo_timeout() {
+        assert_eq!(
+            TxIcaSendCmd {
+                chain_id: ChainId::from_string("chain_a"),
+                connection_id: ConnectionId::from_str("connection-0").unwrap(),
+                msg_file: PathBuf::from("msgs.json"),
+                memo: "memo".to_owned(),
+                timeout_seconds: 60,
+                key_name: None,
+            },
+            TxIcaSendCmd::parse_from([
+                "test",
+                "--chain",
+                "chain_a",
+                "--conn",
+                "connection-0",
+                "--msg-file",
+                "msgs.json",
+                "--memo",
+                "memo",
+                "--timeout-seconds",
+                "60"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_ica_send_no_msg_file() {
+        assert!(TxIcaSendCmd::try_parse_from([
+            "test",
+            "--chain",
+            "chain_a",
+            "--connection",
+            "connection-0"
+        ])
+        .is_err())
+    }
+
+    #[test]
+    fn test_parse_messages() {
+        let messages =
+            parse_messages(r#"[{ "type_url": "/cosmos.bank.v1beta1.MsgSend", "value": "AQID" }]"#)
+                .unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(messages[0].value, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_json_messages() {
+        let messages = parse_messages(
+            r#"[
+                {
+                    "@type": "/cosmos.bank.v1beta1.MsgSend",
+                    "from_address": "cosmos1from",
+                    "to_address": "cosmos1to",
+                    "amount": [{ "denom": "stake", "amount": "100" }]
+                },
+                {
+                    "@type": "/ibc.applications.transfer.v1.MsgTransfer",
+                    "sourcePort": "transfer",
+                    "sourceChannel": "channel-0",
+                    "token": { "denom": "stake", "amount": "5" },
+                    "sender": "cosmos1from",
+                    "receiver": "cosmos1to",
+                    "timeoutHeight": { "revisionNumber": "1", "revisionHeight": "1000" }
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        let send = MsgSend::decode(messages[0].value.as_slice()).unwrap();
+        assert_eq!(messages[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(send.from_address, "cosmos1from");
+        assert_eq!(send.amount[0].amount, "100");
+
+        let transfer = MsgTransfer::decode(messages[1].value.as_slice()).unwrap();
+        assert_eq!(transfer.source_channel, "channel-0");
+        assert_eq!(transfer.timeout_height.unwrap().revision_height, 1000);
+        assert_eq!(transfer.timeout_timestamp, 0);
+    }
+
+    #[test]
+    fn test_parse_json_messages_unsupported_type() {
+        assert!(parse_messages(r#"[{ "@type": "/cosmos.staking.v1beta1.MsgDelegate" }]"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_messages_invalid_value() {
+        assert!(parse_messages(r#"[{ "type_url": "/foo", "value": "not base64!" }]"#).is_err());
+    }
+}
@@ -1,6 +1,10 @@
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
 use abscissa_core::clap::Parser;
 use abscissa_core::{Command, Runnable};
 use ibc_relayer_types::core::ics02_client::height::Height;
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
 
 use ibc_relayer::chain::handle::ChainHandle;
 use ibc_relayer::link::{Link, LinkParameters};
@@ -172,13 +176,123 @@ impl Runnable for TxPacketAckCmd {
     }
 }
 
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxPacketTimeoutCmd {
+    #[clap(
+        long = "dst-chain",
+        required = true,
+        value_name = "DST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the destination chain"
+    )]
+    dst_chain_id: ChainId,
+
+    #[clap(
+        long = "src-chain",
+        required = true,
+        value_name = "SRC_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source chain"
+    )]
+    src_chain_id: ChainId,
+
+    #[clap(
+        long = "src-port",
+        required = true,
+        value_name = "SRC_PORT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source port"
+    )]
+    src_port_id: PortId,
+
+    #[clap(
+        long = "src-channel",
+        visible_alias = "src-chan",
+        required = true,
+        value_name = "SRC_CHANNEL_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the source channel"
+    )]
+    src_channel_id: ChannelId,
+
+    #[clap(
+        long = "sequence",
+        visible_alias = "seq",
+        value_name = "SEQUENCES",
+        value_delimiter = ',',
+        value_parser = parse_sequence_range,
+        help = "Sequences of the packets to time out, either single sequences or inclusive ranges \
+                (e.g. `1,5..8,10..`). If omitted, all expired packets are timed out"
+    )]
+    sequences: Vec<RangeInclusive<Sequence>>,
+}
+
+impl Runnable for TxPacketTimeoutCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let chains = match ChainHandlePair::spawn(&config, &self.src_chain_id, &self.dst_chain_id) {
+            Ok(chains) => chains,
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        };
+
+        let opts = LinkParameters {
+            src_port_id: self.src_port_id.clone(),
+            src_channel_id: self.src_channel_id.clone(),
+        };
+        let link = match Link::new_from_opts(chains.src, chains.dst, opts, false, false) {
+            Ok(link) => link,
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        };
+
+        let res: Result<Vec<IbcEvent>, Error> = link
+            .relay_timeout_packet_messages(&self.sequences)
+            .map_err(Error::link);
+
+        match res {
+            Ok(ev) => Output::success(ev).exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+/// Parses either a single sequence, e.g. `5`, or an inclusive range of sequences,
+/// e.g. `5..8`, where either bound may be omitted, e.g. `5..` or `..8`.
+fn parse_sequence_range(s: &str) -> Result<RangeInclusive<Sequence>, String> {
+    let parse = |s: &str, default: u64| -> Result<Sequence, String> {
+        if s.is_empty() {
+            Ok(Sequence::from(default))
+        } else {
+            Sequence::from_str(s).map_err(|e| e.to_string())
+        }
+    };
+
+    match s.trim().split_once("..") {
+        Some((start, end)) => {
+            let start = parse(start, 1)?;
+            let end = parse(end, u64::MAX)?;
+
+            if start > end {
+                return Err(format!("invalid sequence range '{}'", s));
+            }
+
+            Ok(start..=end)
+        }
+        None => {
+            let seq = Sequence::from_str(s.trim()).map_err(|e| e.to_string())?;
+            Ok(seq..=seq)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{TxPacketAckCmd, TxPacketRecvCmd};
+    use super::{parse_sequence_range, TxPacketAckCmd, TxPacketRecvCmd, TxPacketTimeoutCmd};
 
     use std::str::FromStr;
 
     use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics04_channel::packet::Sequence;
     use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
     #[test]
@@ -413,4 +527,75 @@ mod tests {
         ])
         .is_err())
     }
+
+    #[test]
+    fn test_packet_timeout_required_only() {
+        assert_eq!(
+            TxPacketTimeoutCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                sequences: vec![]
+            },
+            TxPacketTimeoutCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-channel",
+                "channel_sender"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_packet_timeout_sequences() {
+        assert_eq!(
+            TxPacketTimeoutCmd {
+                dst_chain_id: ChainId::from_string("chain_receiver"),
+                src_chain_id: ChainId::from_string("chain_sender"),
+                src_port_id: PortId::from_str("port_sender").unwrap(),
+                src_channel_id: ChannelId::from_str("channel_sender").unwrap(),
+                sequences: vec![
+                    Sequence::from(1)..=Sequence::from(1),
+                    Sequence::from(5)..=Sequence::from(8),
+                ]
+            },
+            TxPacketTimeoutCmd::parse_from([
+                "test",
+                "--dst-chain",
+                "chain_receiver",
+                "--src-chain",
+                "chain_sender",
+                "--src-port",
+                "port_sender",
+                "--src-chan",
+                "channel_sender",
+                "--sequence",
+                "1,5..8"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_parse_sequence_range() {
+        assert_eq!(
+            parse_sequence_range("3"),
+            Ok(Sequence::from(3)..=Sequence::from(3))
+        );
+        assert_eq!(
+            parse_sequence_range("..8"),
+            Ok(Sequence::from(1)..=Sequence::from(8))
+        );
+        assert_eq!(
+            parse_sequence_range("10.."),
+            Ok(Sequence::from(10)..=Sequence::from(u64::MAX))
+        );
+        assert!(parse_sequence_range("8..5").is_err());
+        assert!(parse_sequence_range("a..5").is_err());
+    }
 }
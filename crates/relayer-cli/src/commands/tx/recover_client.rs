@@ -0,0 +1,272 @@
+use abscissa_core::clap::Parser;
+use abscissa_core::{Command, Runnable};
+use serde::Serialize;
+use tendermint_rpc::abci::transaction::Hash as TxHash;
+use tracing::info;
+
+use ibc_relayer::chain::handle::ChainHandle;
+use ibc_relayer::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use ibc_relayer::foreign_client::{extract_client_id, CreateOptions, ForeignClient};
+use ibc_relayer::recover_client::{build_and_send_recover_client_proposal, RecoverClientOptions};
+use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::cli_utils::spawn_chain_runtime;
+use crate::conclude::{exit_with_unrecoverable_error, Output};
+use crate::error::Error;
+use crate::prelude::*;
+
+/// The data structure that represents the arguments when invoking the `tx client-recover` CLI command.
+///
+/// The command has the following format:
+///
+/// `tx client-recover --host-chain <HOST_CHAIN_ID> --subject-client <SUBJECT_CLIENT_ID> --amount <AMOUNT>`
+///
+/// Unless a substitute client is given, a fresh client of the chain tracked by the
+/// subject client is created with the same parameters, and a governance proposal
+/// for substituting its state for the state of the subject client is submitted.
+#[derive(Clone, Command, Debug, Parser, PartialEq, Eq)]
+pub struct TxClientRecoverCmd {
+    #[clap(
+        long = "host-chain",
+        required = true,
+        value_name = "HOST_CHAIN_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the chain which hosts the client to recover"
+    )]
+    host_chain_id: ChainId,
+
+    #[clap(
+        long = "subject-client",
+        required = true,
+        value_name = "SUBJECT_CLIENT_ID",
+        help_heading = "REQUIRED",
+        help = "Identifier of the expired or frozen client to recover"
+    )]
+    subject_client_id: ClientId,
+
+    #[clap(
+        long = "amount",
+        required = true,
+        value_name = "AMOUNT",
+        help_heading = "REQUIRED",
+        help = "Amount of the deposit of the proposal"
+    )]
+    amount: u64,
+
+    #[clap(
+        long = "substitute-client",
+        value_name = "SUBSTITUTE_CLIENT_ID",
+        help = "Identifier of an existing client to substitute for the subject client. If omitted, a new client is created"
+    )]
+    substitute_client_id: Option<ClientId>,
+
+    #[clap(
+        long = "denom",
+        value_name = "DENOM",
+        help = "Denomination for the deposit (default: 'stake')"
+    )]
+    denom: Option<String>,
+
+    #[clap(
+        long = "title",
+        value_name = "TITLE",
+        help = "Title of the proposal (default: 'recover client <SUBJECT_CLIENT_ID>')"
+    )]
+    title: Option<String>,
+
+    #[clap(
+        long = "summary",
+        value_name = "SUMMARY",
+        help = "Summary of the proposal"
+    )]
+    summary: Option<String>,
+
+    #[clap(
+        long = "legacy",
+        help = "Submit a legacy ClientUpdateProposal, for chains which do not support MsgRecoverClient"
+    )]
+    legacy: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RecoverClientResult {
+    substitute_client_id: ClientId,
+    proposal_tx_hash: TxHash,
+}
+
+impl Runnable for TxClientRecoverCmd {
+    fn run(&self) {
+        let config = app_config();
+
+        let host_chain = spawn_chain_runtime(&config, &self.host_chain_id)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let (subject_client_state, _) = host_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.subject_client_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(Error::relayer)
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let reference_chain = spawn_chain_runtime(&config, &subject_client_state.chain_id())
+            .unwrap_or_else(exit_with_unrecoverable_error);
+
+        let subject_client = ForeignClient::restore(
+            self.subject_client_id.clone(),
+            host_chain.clone(),
+            reference_chain.clone(),
+        );
+
+        if !subject_client.is_expired_or_frozen() {
+            Output::error(format!(
+                "client {} on chain {} is neither expired nor frozen, and cannot be recovered",
+                self.subject_client_id, self.host_chain_id
+            ))
+            .exit()
+        }
+
+        let substitute_client_id = match &self.substitute_client_id {
+            Some(substitute_client_id) => substitute_client_id.clone(),
+            None => {
+                // The substitute client must have the same parameters as the subject client
+                let options = CreateOptions {
+                    max_clock_drift: Some(subject_client_state.max_clock_drift()),
+                    trusting_period: None,
+                    trust_threshold: subject_client_state.trust_threshold(),
+                };
+
+                let substitute_client = ForeignClient::restore(
+                    ClientId::default(),
+                    host_chain.clone(),
+                    reference_chain,
+                );
+
+                let client_id = substitute_client
+                    .build_create_client_and_send(options)
+                    .and_then(|event_with_height| {
+                        extract_client_id(&event_with_height.event).cloned()
+                    })
+                    .map_err(Error::foreign_client)
+                    .unwrap_or_else(exit_with_unrecoverable_error);
+
+                info!(
+                    "created substitute client {} on chain {}",
+                    client_id, self.host_chain_id
+                );
+
+                client_id
+            }
+        };
+
+        let opts = RecoverClientOptions {
+            subject_client_id: self.subject_client_id.clone(),
+            substitute_client_id: substitute_client_id.clone(),
+            amount: self.amount,
+            denom: self.denom.as_deref().unwrap_or("stake").into(),
+            title: self
+                .title
+                .clone()
+                .unwrap_or_else(|| format!("recover client {}", self.subject_client_id)),
+            summary: self.summary.clone().unwrap_or_else(|| {
+                format!(
+                    "substitute client {} for the expired or frozen client {}",
+                    substitute_client_id, self.subject_client_id
+                )
+            }),
+            legacy: self.legacy,
+        };
+
+        let res = build_and_send_recover_client_proposal(host_chain, &opts)
+            .map_err(Error::recover_client);
+
+        match res {
+            Ok(proposal_tx_hash) => Output::success(RecoverClientResult {
+                substitute_client_id,
+                proposal_tx_hash,
+            })
+            .exit(),
+            Err(e) => Output::error(format!("{}", e)).exit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TxClientRecoverCmd;
+
+    use abscissa_core::clap::Parser;
+    use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_client_recover_required_only() {
+        assert_eq!(
+            TxClientRecoverCmd {
+                host_chain_id: ChainId::from_string("chain_host"),
+                subject_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                amount: 42,
+                substitute_client_id: None,
+                denom: None,
+                title: None,
+                summary: None,
+                legacy: false
+            },
+            TxClientRecoverCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_host",
+                "--subject-client",
+                "07-tendermint-0",
+                "--amount",
+                "42"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_client_recover_substitute_legacy() {
+        assert_eq!(
+            TxClientRecoverCmd {
+                host_chain_id: ChainId::from_string("chain_host"),
+                subject_client_id: ClientId::from_str("07-tendermint-0").unwrap(),
+                amount: 42,
+                substitute_client_id: Some(ClientId::from_str("07-tendermint-1").unwrap()),
+                denom: Some("my_denom".to_owned()),
+                title: None,
+                summary: None,
+                legacy: true
+            },
+            TxClientRecoverCmd::parse_from([
+                "test",
+                "--host-chain",
+                "chain_host",
+                "--subject-client",
+                "07-tendermint-0",
+                "--amount",
+                "42",
+                "--substitute-client",
+                "07-tendermint-1",
+                "--denom",
+                "my_denom",
+                "--legacy"
+            ])
+        )
+    }
+
+    #[test]
+    fn test_client_recover_no_subject() {
+        assert!(TxClientRecoverCmd::try_parse_from([
+            "test",
+            "--host-chain",
+            "chain_host",
+            "--amount",
+            "42"
+        ])
+        .is_err())
+    }
+}
@@ -8,7 +8,7 @@ use alloc::collections::BTreeSet;
 use std::path::PathBuf;
 
 use flex_error::{define_error, TraceError};
-use ibc_relayer::config::{ChainConfig, Config, ModeConfig};
+use ibc_relayer::config::{ChainConfig, Config, DynamicGasPrice, ModeConfig};
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_light_client_verifier::types::TrustThreshold;
 use tracing_subscriber::filter::ParseError;
@@ -73,6 +73,16 @@ define_error! {
                     e.chain_id, e.gas_adjustment, e.gas_multiplier
                 )
             },
+
+        InvalidDynamicGasPrice
+            {
+                chain_id: ChainId,
+                reason: String,
+            }
+            |e| {
+                format!("config file specifies an invalid `dynamic_gas_price` for the chain '{0}', caused by: {1}",
+                    e.chain_id, e.reason)
+            },
     }
 }
 
@@ -168,5 +178,25 @@ fn validate_gas_settings(id: &ChainId, config: &ChainConfig) -> Result<(), Diagn
         )));
     }
 
+    let dynamic_gas_price = &config.dynamic_gas_price;
+
+    if dynamic_gas_price.multiplier < DynamicGasPrice::MIN_MULTIPLIER {
+        return Err(Diagnostic::Error(Error::invalid_dynamic_gas_price(
+            id.clone(),
+            format!(
+                "`multiplier` must be greater than or equal to {}, found {}",
+                DynamicGasPrice::MIN_MULTIPLIER,
+                dynamic_gas_price.multiplier
+            ),
+        )));
+    }
+
+    if dynamic_gas_price.max <= 0.0 {
+        return Err(Diagnostic::Error(Error::invalid_dynamic_gas_price(
+            id.clone(),
+            format!("`max` must be positive, found {}", dynamic_gas_price.max),
+        )));
+    }
+
     Ok(())
 }
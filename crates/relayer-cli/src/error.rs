@@ -5,6 +5,7 @@ use std::io::Error as IoError;
 
 use tendermint::Error as TendermintError;
 
+use ibc_relayer_types::applications::ics27_ica::error::Error as IcaError;
 use ibc_relayer_types::applications::ics29_fee::error::Error as FeeError;
 use ibc_relayer_types::core::ics04_channel::channel::IdentifiedChannelEnd;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
@@ -16,6 +17,7 @@ use ibc_relayer::error::Error as RelayerError;
 use ibc_relayer::foreign_client::ForeignClientError;
 use ibc_relayer::keyring::errors::Error as KeyRingError;
 use ibc_relayer::link::error::LinkError;
+use ibc_relayer::recover_client::RecoverClientError;
 use ibc_relayer::spawn::SpawnError;
 use ibc_relayer::supervisor::Error as SupervisorError;
 use ibc_relayer::transfer::TransferError;
@@ -88,6 +90,10 @@ define_error! {
             [ FeeError ]
             |_| { "fee error" },
 
+        Ica
+            [ IcaError ]
+            |_| { "interchain accounts error" },
+
         Transfer
             [ TransferError ]
             |_| { "transfer error" },
@@ -112,6 +118,10 @@ define_error! {
             [ UpgradeChainError ]
             |_| { "upgrade chain error" },
 
+        RecoverClient
+            [ RecoverClientError ]
+            |_| { "recover client error" },
+
         Signer
             [ SignerError ]
             |_| { "signer error" },
@@ -1,18 +1,22 @@
 use core::fmt::Debug;
+use core::str::FromStr;
+use std::sync::Arc;
 
 use tracing::error;
 
 use crossbeam_channel as channel;
 
-use ibc_relayer::supervisor::dump_state::SupervisorState;
+use ibc_relayer::supervisor::{cmd::ConfigUpdate, dump_state::SupervisorState};
 use ibc_relayer::{
     config::ChainConfig,
+    link::activity::RelayActivity,
+    object::Object,
     rest::{
-        request::{reply_channel, ReplySender, Request, VersionInfo},
+        request::{reply_channel, PendingPacketsSummary, ReplySender, Request, VersionInfo},
         RestApiError,
     },
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
 pub const NAME: &str = env!(
     "CARGO_PKG_NAME",
@@ -64,6 +68,58 @@ pub fn supervisor_state(
     submit_request(sender, |reply_to| Request::State { reply_to })
 }
 
+pub fn reload_config(sender: &channel::Sender<Request>) -> Result<Vec<ConfigUpdate>, RestApiError> {
+    submit_request(sender, |reply_to| Request::ReloadConfig { reply_to })
+}
+
+pub fn pending_packets(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    port_id: &str,
+    channel_id: &str,
+) -> Result<PendingPacketsSummary, RestApiError> {
+    let (port_id, channel_id) = parse_channel_end(port_id, channel_id)?;
+
+    submit_request(sender, |reply_to| Request::PendingPackets {
+        chain_id: ChainId::from_string(chain_id),
+        port_id,
+        channel_id,
+        reply_to,
+    })
+}
+
+pub fn clear_packets(
+    sender: &channel::Sender<Request>,
+    chain_id: &str,
+    port_id: &str,
+    channel_id: &str,
+) -> Result<Object, RestApiError> {
+    let (port_id, channel_id) = parse_channel_end(port_id, channel_id)?;
+
+    submit_request(sender, |reply_to| Request::ClearPackets {
+        chain_id: ChainId::from_string(chain_id),
+        port_id,
+        channel_id,
+        reply_to,
+    })
+}
+
+pub fn subscribe_activity(
+    sender: &channel::Sender<Request>,
+) -> Result<channel::Receiver<Arc<RelayActivity>>, RestApiError> {
+    submit_request(sender, |reply_to| Request::SubscribeActivity { reply_to })
+}
+
+fn parse_channel_end(port_id: &str, channel_id: &str) -> Result<(PortId, ChannelId), RestApiError> {
+    let port = PortId::from_str(port_id)
+        .map_err(|e| RestApiError::InvalidPortId(port_id.to_string(), e.0))?;
+
+    let channel = ChannelId::from_str(channel_id)
+        .map_err(|e| RestApiError::InvalidChannelId(channel_id.to_string(), e.0))?;
+
+    Ok((port, channel))
+}
+
 pub fn assemble_version_info(sender: &channel::Sender<Request>) -> Vec<VersionInfo> {
     // Fetch the relayer library version
     let lib_version = submit_request(sender, |reply_to| Request::Version { reply_to })
@@ -1,13 +1,19 @@
+use core::str::FromStr;
 use std::thread;
 
 use crossbeam_channel as channel;
+use rouille::websocket;
 use serde::{Deserialize, Serialize};
-use tracing::{info, trace};
+use tracing::{error, info, trace};
 
-use ibc_relayer::rest::request::Request;
+use ibc_relayer::rest::{request::Request, RestApiError};
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId};
 
 use crate::{
-    handle::{all_chain_ids, assemble_version_info, chain_config, supervisor_state},
+    handle::{
+        all_chain_ids, assemble_version_info, chain_config, clear_packets, pending_packets,
+        reload_config, subscribe_activity, supervisor_state,
+    },
     Config,
 };
 
@@ -82,6 +88,33 @@ fn run(config: Config, sender: channel::Sender<Request>) -> ServerHandle {
                 rouille::Response::json(&JsonResult::from(result))
             },
 
+            (POST) (/config/reload) => {
+                trace!("[rest] POST /config/reload");
+                let result = reload_config(&sender);
+                rouille::Response::json(&JsonResult::from(result))
+            },
+
+            (GET) (/packets/pending/{chain: String}/{port: String}/{channel: String}) => {
+                trace!("[rest] GET /packets/pending/{}/{}/{}", chain, port, channel);
+                let result = pending_packets(&sender, &chain, &port, &channel);
+                packets_response(result)
+            },
+
+            (POST) (/packets/clear/{chain: String}/{port: String}/{channel: String}) => {
+                trace!("[rest] POST /packets/clear/{}/{}/{}", chain, port, channel);
+                let result = clear_packets(&sender, &chain, &port, &channel);
+                packets_response(result)
+            },
+
+            (GET) (/activity) => {
+                trace!("[rest] GET /activity");
+                match activity_stream(request, &sender) {
+                    Ok(response) => response,
+                    Err(e) => rouille::Response::json(&JsonResult::<(), _>::Error(e))
+                        .with_status_code(400),
+                }
+            },
+
             _ => rouille::Response::empty_404(),
         )
     })
@@ -94,3 +127,73 @@ fn run(config: Config, sender: channel::Sender<Request>) -> ServerHandle {
         tx_stop,
     }
 }
+
+/// Respond to a request about the packets of a channel, with
+/// a 404 status if the chain or the channel is not relayed.
+fn packets_response<R: Serialize>(result: Result<R, RestApiError>) -> rouille::Response {
+    let not_found = matches!(
+        result,
+        Err(RestApiError::ChainNotFound(_)) | Err(RestApiError::PacketWorkerNotFound(..))
+    );
+
+    let response = rouille::Response::json(&JsonResult::from(result));
+
+    if not_found {
+        response.with_status_code(404)
+    } else {
+        response
+    }
+}
+
+/// Upgrade the request to a WebSocket connection, over which every transaction
+/// relayed from now on is sent as a JSON message.
+///
+/// The `chain` and `channel` query parameters optionally restrict the stream to the
+/// transactions relayed to or from that chain, and over that channel.
+fn activity_stream(
+    request: &rouille::Request,
+    sender: &channel::Sender<Request>,
+) -> Result<rouille::Response, RestApiError> {
+    let chain_id = request
+        .get_param("chain")
+        .map(|id| ChainId::from_string(&id));
+
+    let channel_id = request
+        .get_param("channel")
+        .map(|id| ChannelId::from_str(&id).map_err(|e| RestApiError::InvalidChannelId(id, e.0)))
+        .transpose()?;
+
+    let (response, websocket) = websocket::start(request, None::<&str>)
+        .map_err(|e| RestApiError::WebSocket(e.to_string()))?;
+
+    let activity = subscribe_activity(sender)?;
+
+    thread::spawn(move || {
+        // The connection is upgraded once the response has been sent
+        let mut websocket = match websocket.recv() {
+            Ok(websocket) => websocket,
+            Err(_) => return,
+        };
+
+        for activity in activity.iter() {
+            if !activity.matches(chain_id.as_ref(), channel_id.as_ref()) {
+                continue;
+            }
+
+            let message = match serde_json::to_string(activity.as_ref()) {
+                Ok(message) => message,
+                Err(e) => {
+                    error!("[rest] failed to serialize relay activity: {}", e);
+                    continue;
+                }
+            };
+
+            // Stop streaming once the client has gone away
+            if websocket.send_text(&message).is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(response)
+}
@@ -3,11 +3,19 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 use ibc_relayer::{
+    chain::counterparty::PendingPackets,
     config::ChainConfig,
-    rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    object::{self, Object},
+    rest::{
+        request::{PendingPacketsSummary, Request, VersionInfo},
+        RestApiError,
+    },
+    supervisor::{cmd::ConfigUpdate, dump_state::SupervisorState},
+};
+use ibc_relayer_types::core::{
+    ics04_channel::packet::Sequence,
+    ics24_host::identifier::{ChainId, ChannelId, PortId},
 };
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
 use ibc_relayer_rest::{server::spawn, Config};
 
@@ -25,6 +33,14 @@ enum JsonResult<R, E> {
 }
 
 fn run_test<R, F>(port: u16, path: &str, expected: R, handler: F)
+where
+    R: Serialize,
+    F: FnOnce(Request) -> TestResult + Send + 'static,
+{
+    run_test_with_method("GET", port, path, expected, handler)
+}
+
+fn run_test_with_method<R, F>(method: &str, port: u16, path: &str, expected: R, handler: F)
 where
     R: Serialize,
     F: FnOnce(Request) -> TestResult + Send + 'static,
@@ -41,7 +57,7 @@ where
         Err(e) => panic!("got an error: {}", e),
     });
 
-    let response = ureq::get(&format!("http://127.0.0.1:{}{}", port, path))
+    let response = ureq::request(method, &format!("http://127.0.0.1:{}{}", port, path))
         .call()
         .unwrap()
         .into_string()
@@ -137,3 +153,115 @@ fn state() {
         req => TestResult::WrongRequest(req),
     });
 }
+
+#[test]
+fn reload_config() {
+    let config: ChainConfig = toml::de::from_str(MOCK_CHAIN_CONFIG).unwrap();
+    let updates = vec![
+        ConfigUpdate::Add(config),
+        ConfigUpdate::Remove(ChainId::from_str("mock-1").unwrap()),
+    ];
+    let result: JsonResult<_, ()> = JsonResult::Success(updates.clone());
+
+    run_test_with_method("POST", 19105, "/config/reload", result, |req| match req {
+        Request::ReloadConfig { reply_to } => {
+            reply_to.send(Ok(updates)).unwrap();
+            TestResult::Success
+        }
+        req => TestResult::WrongRequest(req),
+    });
+}
+
+#[test]
+fn pending_packets() {
+    let summary = PendingPacketsSummary {
+        src: PendingPackets {
+            unreceived_packets: vec![Sequence::from(1), Sequence::from(2)],
+            unreceived_acks: vec![],
+        },
+        dst: PendingPackets {
+            unreceived_packets: vec![],
+            unreceived_acks: vec![Sequence::from(7)],
+        },
+    };
+    let result: JsonResult<_, ()> = JsonResult::Success(summary.clone());
+
+    run_test(
+        19106,
+        "/packets/pending/mock-0/transfer/channel-0",
+        result,
+        |req| match req {
+            Request::PendingPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } if chain_id.as_str() == "mock-0"
+                && port_id.as_str() == "transfer"
+                && channel_id.to_string() == "channel-0" =>
+            {
+                reply_to.send(Ok(summary)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    );
+}
+
+#[test]
+fn pending_packets_unknown_chain() {
+    let config = Config::new("127.0.0.1".to_string(), 19110);
+
+    let (handle, rx) = spawn(config);
+
+    std::thread::spawn(move || match rx.recv() {
+        Ok(Request::PendingPackets {
+            chain_id, reply_to, ..
+        }) => reply_to
+            .send(Err(RestApiError::ChainNotFound(chain_id)))
+            .unwrap(),
+        Ok(req) => panic!("got the wrong request: {:?}", req),
+        Err(e) => panic!("got an error: {}", e),
+    });
+
+    let response =
+        ureq::get("http://127.0.0.1:19110/packets/pending/unknown-0/transfer/channel-0").call();
+
+    match response {
+        Err(ureq::Error::Status(404, response)) => {
+            let body: serde_json::Value =
+                serde_json::from_str(&response.into_string().unwrap()).unwrap();
+            assert_eq!(body["status"], "error");
+            assert_eq!(body["result"]["name"], "ChainNotFound");
+        }
+        other => panic!("expected a 404 response, got: {:?}", other),
+    }
+
+    handle.stop();
+    handle.join().unwrap();
+}
+
+#[test]
+fn clear_packets() {
+    let object = Object::Packet(object::Packet {
+        dst_chain_id: ChainId::from_str("mock-1").unwrap(),
+        src_chain_id: ChainId::from_str("mock-0").unwrap(),
+        src_channel_id: ChannelId::from_str("channel-0").unwrap(),
+        src_port_id: PortId::from_str("transfer").unwrap(),
+    });
+    let result: JsonResult<_, ()> = JsonResult::Success(object.clone());
+
+    run_test_with_method(
+        "POST",
+        19107,
+        "/packets/clear/mock-0/transfer/channel-0",
+        result,
+        |req| match req {
+            Request::ClearPackets { reply_to, .. } => {
+                reply_to.send(Ok(object)).unwrap();
+                TestResult::Success
+            }
+            req => TestResult::WrongRequest(req),
+        },
+    );
+}
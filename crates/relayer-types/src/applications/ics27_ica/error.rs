@@ -0,0 +1,16 @@
+use flex_error::{define_error, TraceError};
+use prost::EncodeError;
+
+use crate::prelude::*;
+
+define_error! {
+    #[derive(Debug, PartialEq, Eq)]
+    Error {
+        Encode
+            [ TraceError<EncodeError> ]
+            | _ | { "protobuf encode error" },
+
+        EmptyMessages
+            | _ | { "expected at least one message to execute on the host chain" },
+    }
+}
@@ -0,0 +1,12 @@
+//! The controller side of ICS 27 [interchain accounts](https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md).
+//! An owner account on a controller chain registers an interchain account on a host chain,
+//! and then executes transactions on the host chain through this account by sending packets
+//! over the channel opened for the registration.
+
+pub mod error;
+pub mod msgs;
+
+mod raw;
+
+/// The version negotiated on the channels of interchain accounts.
+pub const VERSION: &str = "ics27-1";
@@ -0,0 +1,2 @@
+pub mod register_account;
+pub mod send_tx;
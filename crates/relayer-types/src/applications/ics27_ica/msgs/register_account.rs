@@ -0,0 +1,34 @@
+use ibc_proto::google::protobuf::Any;
+
+use crate::applications::ics27_ica::error::Error;
+use crate::applications::ics27_ica::raw::MsgRegisterInterchainAccount;
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::prelude::*;
+use crate::signer::Signer;
+use crate::tx_msg::encode_message;
+
+const TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+
+/// Builds the message which registers an interchain account for the given owner
+/// over the given connection. When the version is empty, the controller chain
+/// picks the default metadata of the channel.
+pub fn build_register_interchain_account_message(
+    owner: &Signer,
+    connection_id: &ConnectionId,
+    version: &Version,
+) -> Result<Any, Error> {
+    let message = MsgRegisterInterchainAccount {
+        owner: owner.to_string(),
+        connection_id: connection_id.to_string(),
+        version: version.to_string(),
+    };
+
+    let encoded = encode_message(&message).map_err(Error::encode)?;
+
+    Ok(Any {
+        type_url: TYPE_URL.to_string(),
+        value: encoded,
+    })
+}
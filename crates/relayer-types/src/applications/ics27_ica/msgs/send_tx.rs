@@ -0,0 +1,103 @@
+use core::time::Duration;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::applications::interchain_accounts::v1::{
+    CosmosTx, InterchainAccountPacketData, Type,
+};
+
+use crate::applications::ics27_ica::error::Error;
+use crate::applications::ics27_ica::raw::MsgSendTx;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::prelude::*;
+use crate::signer::Signer;
+use crate::tx_msg::encode_message;
+
+const TYPE_URL: &str = "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+
+/// Builds the message which executes the given messages on the host chain,
+/// with the interchain account of the given owner over the given connection.
+///
+/// The packet carrying the messages times out after `relative_timeout`
+/// has elapsed on the controller chain.
+pub fn build_send_tx_message(
+    owner: &Signer,
+    connection_id: &ConnectionId,
+    messages: Vec<Any>,
+    memo: String,
+    relative_timeout: Duration,
+) -> Result<Any, Error> {
+    if messages.is_empty() {
+        return Err(Error::empty_messages());
+    }
+
+    let data = encode_message(&CosmosTx { messages }).map_err(Error::encode)?;
+
+    let message = MsgSendTx {
+        owner: owner.to_string(),
+        connection_id: connection_id.to_string(),
+        packet_data: Some(InterchainAccountPacketData {
+            r#type: Type::ExecuteTx as i32,
+            data,
+            memo,
+        }),
+        relative_timeout: relative_timeout.as_nanos() as u64,
+    };
+
+    let encoded = encode_message(&message).map_err(Error::encode)?;
+
+    Ok(Any {
+        type_url: TYPE_URL.to_string(),
+        value: encoded,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use prost::Message;
+
+    use crate::test_utils::get_dummy_account_id;
+
+    #[test]
+    fn send_tx_wraps_messages_in_packet_data() {
+        let inner = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![1, 2, 3],
+        };
+
+        let any = build_send_tx_message(
+            &get_dummy_account_id(),
+            &ConnectionId::default(),
+            vec![inner.clone()],
+            "memo".to_string(),
+            Duration::from_secs(60),
+        )
+        .unwrap();
+
+        assert_eq!(any.type_url, TYPE_URL);
+
+        let msg = MsgSendTx::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.relative_timeout, 60_000_000_000);
+
+        let packet_data = msg.packet_data.unwrap();
+        assert_eq!(packet_data.r#type, Type::ExecuteTx as i32);
+        assert_eq!(packet_data.memo, "memo");
+
+        let tx = CosmosTx::decode(packet_data.data.as_slice()).unwrap();
+        assert_eq!(tx.messages, vec![inner]);
+    }
+
+    #[test]
+    fn send_tx_without_messages_fails() {
+        let res = build_send_tx_message(
+            &get_dummy_account_id(),
+            &ConnectionId::default(),
+            vec![],
+            String::new(),
+            Duration::from_secs(60),
+        );
+
+        assert!(res.is_err());
+    }
+}
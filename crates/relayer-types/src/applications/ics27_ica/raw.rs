@@ -0,0 +1,29 @@
+//! Protobuf definitions of the messages of the interchain accounts controller
+//! which are not yet part of `ibc-proto`, as defined by ibc-go v6.
+
+use ibc_proto::ibc::applications::interchain_accounts::v1::InterchainAccountPacketData;
+
+use crate::prelude::*;
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message)]
+pub struct MsgRegisterInterchainAccount {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(string, tag = "3")]
+    pub version: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MsgSendTx {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub packet_data: Option<InterchainAccountPacketData>,
+    /// Relative timeout of the packet, in nanoseconds
+    #[prost(uint64, tag = "4")]
+    pub relative_timeout: u64,
+}
@@ -1,5 +1,6 @@
 //! Various packet encoding semantics which underpin the various types of transactions.
 
+pub mod ics27_ica;
 pub mod ics29_fee;
 pub mod ics31_icq;
 pub mod transfer;
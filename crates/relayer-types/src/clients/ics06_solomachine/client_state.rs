@@ -0,0 +1,273 @@
+use core::convert::{TryFrom, TryInto};
+use core::time::Duration;
+
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics06_solomachine::consensus_state::ConsensusState;
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::{
+    v2::ClientState as RawSmClientState, v3::ClientState as RawSmV3ClientState,
+};
+use crate::clients::ics06_solomachine::Version;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::prelude::*;
+use crate::Height;
+
+pub const SOLOMACHINE_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.solomachine.v2.ClientState";
+
+pub const SOLOMACHINE_V3_CLIENT_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ClientState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// The sequence of the next header, which is also the latest height of the client
+    pub sequence: u64,
+    pub is_frozen: bool,
+    pub consensus_state: ConsensusState,
+    /// Whether governance may update the client. Only `v2` client states
+    /// record it, so it is always `false` for `v3` client states.
+    pub allow_update_after_proposal: bool,
+}
+
+impl ClientState {
+    /// A client state in the same version as the given consensus state.
+    pub fn new(sequence: u64, consensus_state: ConsensusState) -> Result<Self, Error> {
+        if sequence == 0 {
+            return Err(Error::invalid_raw_client_state(
+                "sequence must be greater than zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            sequence,
+            is_frozen: false,
+            consensus_state,
+            allow_update_after_proposal: false,
+        })
+    }
+
+    pub fn version(&self) -> Version {
+        self.consensus_state.version
+    }
+
+    pub fn latest_height(&self) -> Height {
+        Height::new(0, self.sequence)
+            .expect("malformed solo machine client state has an illegal sequence of 0")
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    /// Solo machines do not have a chain identifier, and the client state does not
+    /// record the one under which the solo machine is configured, so the diversifier
+    /// stands in for it. Checking that a solo machine client tracks a given chain thus
+    /// compares the identifier of that chain with the diversifier.
+    fn chain_id(&self) -> ChainId {
+        ChainId::from_string(&self.consensus_state.diversifier)
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.is_frozen.then(|| self.latest_height())
+    }
+
+    fn upgrade(
+        &mut self,
+        _upgrade_height: Height,
+        _upgrade_options: &dyn CoreUpgradeOptions,
+        _chain_id: ChainId,
+    ) {
+        // Solo machine clients cannot be upgraded, their key is rotated with a header instead
+    }
+
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl Protobuf<RawSmClientState> for ClientState {}
+
+impl TryFrom<RawSmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSmClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Error::invalid_raw_client_state("missing consensus state".into()))?;
+        let consensus_state = ConsensusState::from_raw(Version::V2, consensus_state)?;
+
+        Ok(Self {
+            is_frozen: raw.is_frozen,
+            allow_update_after_proposal: raw.allow_update_after_proposal,
+            ..Self::new(raw.sequence, consensus_state)?
+        })
+    }
+}
+
+impl From<ClientState> for RawSmClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+            allow_update_after_proposal: value.allow_update_after_proposal,
+        }
+    }
+}
+
+impl TryFrom<RawSmV3ClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawSmV3ClientState) -> Result<Self, Self::Error> {
+        let consensus_state = raw
+            .consensus_state
+            .ok_or_else(|| Error::invalid_raw_client_state("missing consensus state".into()))?;
+        let consensus_state = ConsensusState::from_raw(Version::V3, consensus_state)?;
+
+        Ok(Self {
+            is_frozen: raw.is_frozen,
+            ..Self::new(raw.sequence, consensus_state)?
+        })
+    }
+}
+
+impl From<ClientState> for RawSmV3ClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            sequence: value.sequence,
+            is_frozen: value.is_frozen,
+            consensus_state: Some(value.consensus_state.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawSmClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        fn decode_v3_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawSmV3ClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            SOLOMACHINE_V3_CLIENT_STATE_TYPE_URL => {
+                decode_v3_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        match client_state.version() {
+            Version::V2 => Any {
+                type_url: SOLOMACHINE_CLIENT_STATE_TYPE_URL.to_string(),
+                value: Protobuf::<RawSmClientState>::encode_vec(&client_state)
+                    .expect("encoding to `Any` from `SmClientState`"),
+            },
+            Version::V3 => Any {
+                type_url: SOLOMACHINE_V3_CLIENT_STATE_TYPE_URL.to_string(),
+                value: RawSmV3ClientState::from(client_state).encode_to_vec(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_state_roundtrip() {
+        let public_key = Any {
+            type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            value: vec![10, 33, 2],
+        };
+        let consensus_state = ConsensusState::new(
+            Version::V2,
+            public_key,
+            "solo-0".to_string(),
+            1_665_000_000_000_000_000,
+        );
+        let client_state = ClientState::new(1, consensus_state).unwrap();
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, SOLOMACHINE_CLIENT_STATE_TYPE_URL);
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+
+        assert_eq!(client_state.latest_height(), Height::new(0, 1).unwrap());
+        assert_eq!(client_state.chain_id(), ChainId::from_string("solo-0"));
+        assert_eq!(client_state.frozen_height(), None);
+
+        let zero_sequence = RawSmClientState {
+            sequence: 0,
+            ..client_state.into()
+        };
+        assert!(ClientState::try_from(zero_sequence).is_err());
+    }
+
+    #[test]
+    fn v3_client_state_roundtrip() {
+        let public_key = Any {
+            type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+            value: vec![10, 33, 2],
+        };
+        let consensus_state = ConsensusState::new(
+            Version::V3,
+            public_key,
+            "solo-0".to_string(),
+            1_665_000_000_000_000_000,
+        );
+        let client_state = ClientState::new(3, consensus_state).unwrap();
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, SOLOMACHINE_V3_CLIENT_STATE_TYPE_URL);
+
+        let raw = RawSmV3ClientState::decode(any.value.as_slice()).unwrap();
+        assert_eq!(raw.sequence, 3);
+        assert_eq!(
+            raw.consensus_state.unwrap().diversifier,
+            client_state.consensus_state.diversifier
+        );
+
+        let decoded = ClientState::try_from(any).unwrap();
+        assert_eq!(decoded, client_state);
+        assert_eq!(decoded.version(), Version::V3);
+        assert_eq!(
+            Any::from(decoded.consensus_state).type_url,
+            "/ibc.lightclients.solomachine.v3.ConsensusState"
+        );
+    }
+}
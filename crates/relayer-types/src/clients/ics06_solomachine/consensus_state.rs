@@ -0,0 +1,137 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::v2::ConsensusState as RawConsensusState;
+use crate::clients::ics06_solomachine::Version;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const SOLOMACHINE_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v2.ConsensusState";
+
+pub const SOLOMACHINE_V3_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.solomachine.v3.ConsensusState";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    /// The version of the Protobuf package in which the consensus state is encoded
+    pub version: Version,
+    /// The public key of the solo machine, e.g. a `/cosmos.crypto.secp256k1.PubKey`
+    pub public_key: Any,
+    /// Allows the same public key to be used by several solo machine clients
+    /// without being considered as misbehaviour
+    pub diversifier: String,
+    /// Timestamp of the consensus state, in nanoseconds
+    pub timestamp: u64,
+    /// Solo machines do not commit to a root, which is always empty
+    #[serde(skip, default = "empty_root")]
+    root: CommitmentRoot,
+}
+
+fn empty_root() -> CommitmentRoot {
+    CommitmentRoot::from_bytes(&[])
+}
+
+impl ConsensusState {
+    pub fn new(version: Version, public_key: Any, diversifier: String, timestamp: u64) -> Self {
+        Self {
+            version,
+            public_key,
+            diversifier,
+            timestamp,
+            root: empty_root(),
+        }
+    }
+
+    /// Decode a consensus state, whose encoding is the same in both versions.
+    pub(crate) fn from_raw(version: Version, raw: RawConsensusState) -> Result<Self, Error> {
+        let public_key = raw
+            .public_key
+            .ok_or_else(|| Error::invalid_raw_consensus_state("missing public key".into()))?;
+
+        Ok(Self::new(
+            version,
+            public_key,
+            raw.diversifier,
+            raw.timestamp,
+        ))
+    }
+}
+
+impl crate::core::ics02_client::consensus_state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &self.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.timestamp).unwrap_or_else(|_| Timestamp::none())
+    }
+}
+
+impl Protobuf<RawConsensusState> for ConsensusState {}
+
+/// Decodes a `v2` consensus state, as the encoding does not tell the version apart.
+impl TryFrom<RawConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawConsensusState) -> Result<Self, Self::Error> {
+        Self::from_raw(Version::V2, raw)
+    }
+}
+
+impl From<ConsensusState> for RawConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawConsensusState {
+            public_key: Some(value.public_key),
+            diversifier: value.diversifier,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use core::ops::Deref;
+
+        let version = match raw.type_url.as_str() {
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL => Version::V2,
+            SOLOMACHINE_V3_CONSENSUS_STATE_TYPE_URL => Version::V3,
+            _ => return Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        };
+
+        RawConsensusState::decode(raw.value.deref())
+            .map_err(Error::decode)
+            .and_then(|raw| Self::from_raw(version, raw))
+            .map_err(Into::into)
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        let type_url = match consensus_state.version {
+            Version::V2 => SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+            Version::V3 => SOLOMACHINE_V3_CONSENSUS_STATE_TYPE_URL,
+        };
+
+        Any {
+            type_url: type_url.to_string(),
+            value: Protobuf::<RawConsensusState>::encode_vec(&consensus_state)
+                .expect("encoding to `Any` from `SmConsensusState`"),
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::prelude::*;
+
+use bytes::Buf;
+use ibc_proto::cosmos::tx::signing::v1beta1::signature_descriptor::{data, Data};
+use ibc_proto::cosmos::tx::signing::v1beta1::SignMode;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::v2::{
+    DataType, Header as RawHeader, HeaderData, SignBytes,
+};
+use crate::clients::ics06_solomachine::raw::v3::{Header as RawV3Header, SignBytes as V3SignBytes};
+use crate::clients::ics06_solomachine::Version;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+pub const SOLOMACHINE_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v2.Header";
+
+pub const SOLOMACHINE_V3_HEADER_TYPE_URL: &str = "/ibc.lightclients.solomachine.v3.Header";
+
+/// The path within the `v3` sign bytes of a header.
+const SENTINEL_HEADER_PATH: &str = "solomachine:header";
+
+/// Solo machine header, which rotates the public key and the diversifier of the client.
+///
+/// The header is signed by the current key of the solo machine, over its [`sign_bytes`].
+///
+/// [`sign_bytes`]: Header::sign_bytes
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Header {
+    /// The version of the Protobuf package in which the header is encoded
+    pub version: Version,
+    /// The current sequence of the client, which `v3` headers do not encode
+    pub sequence: u64,
+    /// Timestamp of the header, in nanoseconds
+    pub timestamp: u64,
+    /// Encoded `SignatureDescriptor.Data` of the signature over the sign bytes
+    pub signature: Vec<u8>,
+    pub new_public_key: Any,
+    pub new_diversifier: String,
+}
+
+impl Header {
+    /// The bytes to sign with the current key of the solo machine,
+    /// whose current diversifier is given.
+    ///
+    /// As in ibc-go, the `v2` sign bytes hold the new diversifier and the type of
+    /// the signed data, whereas the `v3` sign bytes hold the current diversifier
+    /// and the path of the signed data.
+    pub fn sign_bytes(&self, current_diversifier: &str) -> Vec<u8> {
+        let header_data = HeaderData {
+            new_pub_key: Some(self.new_public_key.clone()),
+            new_diversifier: self.new_diversifier.clone(),
+        };
+
+        match self.version {
+            Version::V2 => SignBytes {
+                sequence: self.sequence,
+                timestamp: self.timestamp,
+                diversifier: self.new_diversifier.clone(),
+                data_type: DataType::Header as i32,
+                data: header_data.encode_to_vec(),
+            }
+            .encode_to_vec(),
+            Version::V3 => V3SignBytes {
+                sequence: self.sequence,
+                timestamp: self.timestamp,
+                diversifier: current_diversifier.to_string(),
+                path: SENTINEL_HEADER_PATH.as_bytes().to_vec(),
+                data: header_data.encode_to_vec(),
+            }
+            .encode_to_vec(),
+        }
+    }
+
+    /// Attach the signature over the sign bytes, as produced by a single key.
+    pub fn with_signature(self, signature: Vec<u8>) -> Self {
+        let data = Data {
+            sum: Some(data::Sum::Single(data::Single {
+                mode: SignMode::Direct as i32,
+                signature,
+            })),
+        };
+
+        Self {
+            signature: data.encode_to_vec(),
+            ..self
+        }
+    }
+
+    pub fn height(&self) -> Height {
+        Height::new(0, self.sequence)
+            .expect("malformed solo machine header has an illegal sequence of 0")
+    }
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Solomachine
+    }
+
+    fn height(&self) -> Height {
+        self.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        Timestamp::from_nanoseconds(self.timestamp).unwrap_or_else(|_| Timestamp::none())
+    }
+}
+
+impl Protobuf<RawHeader> for Header {}
+
+impl TryFrom<RawHeader> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawHeader) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(Error::invalid_raw_header(
+                "sequence must be greater than zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            version: Version::V2,
+            sequence: raw.sequence,
+            timestamp: raw.timestamp,
+            signature: raw.signature,
+            new_public_key: raw
+                .new_public_key
+                .ok_or_else(|| Error::invalid_raw_header("missing new public key".into()))?,
+            new_diversifier: raw.new_diversifier,
+        })
+    }
+}
+
+impl From<Header> for RawHeader {
+    fn from(value: Header) -> Self {
+        RawHeader {
+            sequence: value.sequence,
+            timestamp: value.timestamp,
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl From<Header> for RawV3Header {
+    fn from(value: Header) -> Self {
+        RawV3Header {
+            timestamp: value.timestamp,
+            signature: value.signature,
+            new_public_key: Some(value.new_public_key),
+            new_diversifier: value.new_diversifier,
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        fn decode_header<B: Buf>(buf: B) -> Result<Header, Error> {
+            RawHeader::decode(buf).map_err(Error::decode)?.try_into()
+        }
+
+        match raw.type_url.as_str() {
+            SOLOMACHINE_HEADER_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            // The height of a header is the sequence of the client it updates
+            SOLOMACHINE_V3_HEADER_TYPE_URL => Err(Error::invalid_raw_header(
+                "v3 headers do not encode the sequence of the client they update".into(),
+            )
+            .into()),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        match header.version {
+            Version::V2 => Any {
+                type_url: SOLOMACHINE_HEADER_TYPE_URL.to_string(),
+                value: Protobuf::<RawHeader>::encode_vec(&header)
+                    .expect("encoding to `Any` from `SmHeader`"),
+            },
+            Version::V3 => Any {
+                type_url: SOLOMACHINE_V3_HEADER_TYPE_URL.to_string(),
+                value: RawV3Header::from(header).encode_to_vec(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(version: Version) -> Header {
+        Header {
+            version,
+            sequence: 7,
+            timestamp: 1_665_000_000_000_000_000,
+            signature: vec![],
+            new_public_key: Any {
+                type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
+                value: vec![10, 33, 2],
+            },
+            new_diversifier: "new".to_string(),
+        }
+    }
+
+    #[test]
+    fn v2_sign_bytes() {
+        let sign_bytes =
+            SignBytes::decode(header(Version::V2).sign_bytes("old").as_slice()).unwrap();
+
+        assert_eq!(sign_bytes.sequence, 7);
+        assert_eq!(sign_bytes.diversifier, "new");
+        assert_eq!(sign_bytes.data_type, DataType::Header as i32);
+    }
+
+    #[test]
+    fn v3_sign_bytes() {
+        let header = header(Version::V3);
+        let sign_bytes = V3SignBytes::decode(header.sign_bytes("old").as_slice()).unwrap();
+
+        assert_eq!(sign_bytes.sequence, 7);
+        assert_eq!(sign_bytes.timestamp, header.timestamp);
+        assert_eq!(sign_bytes.diversifier, "old");
+        assert_eq!(sign_bytes.path, b"solomachine:header");
+
+        let header_data = HeaderData::decode(sign_bytes.data.as_slice()).unwrap();
+        assert_eq!(header_data.new_pub_key, Some(header.new_public_key.clone()));
+        assert_eq!(header_data.new_diversifier, "new");
+    }
+
+    #[test]
+    fn v3_header_encoding() {
+        let header = header(Version::V3).with_signature(vec![1, 2, 3]);
+        let any = Any::from(header.clone());
+        assert_eq!(any.type_url, SOLOMACHINE_V3_HEADER_TYPE_URL);
+
+        let raw = RawV3Header::decode(any.value.as_slice()).unwrap();
+        assert_eq!(raw.timestamp, header.timestamp);
+        assert_eq!(raw.signature, header.signature);
+        assert_eq!(raw.new_diversifier, "new");
+
+        assert!(Header::try_from(any).is_err());
+    }
+}
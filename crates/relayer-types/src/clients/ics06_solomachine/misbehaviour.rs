@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics06_solomachine::error::Error;
+use crate::clients::ics06_solomachine::raw::v2::{
+    Misbehaviour as RawMisbehaviour, SignatureAndData as RawSignatureAndData,
+};
+use crate::core::ics24_host::identifier::ClientId;
+use crate::Height;
+
+pub const SOLOMACHINE_MISBEHAVIOUR_TYPE_URL: &str = "/ibc.lightclients.solomachine.v2.Misbehaviour";
+
+/// Two signatures of the solo machine over different data at the same sequence.
+///
+/// Only `v2` misbehaviours are supported, as `v3` misbehaviours do not
+/// encode the identifier of the client they are submitted to.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Misbehaviour {
+    pub client_id: ClientId,
+    pub sequence: u64,
+    pub signature_one: SignatureAndData,
+    pub signature_two: SignatureAndData,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureAndData {
+    pub signature: Vec<u8>,
+    /// The `DataType` of the signed data
+    pub data_type: i32,
+    pub data: Vec<u8>,
+    pub timestamp: u64,
+}
+
+impl crate::core::ics02_client::misbehaviour::Misbehaviour for Misbehaviour {
+    fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    fn height(&self) -> Height {
+        Height::new(0, self.sequence)
+            .expect("malformed solo machine misbehaviour has an illegal sequence of 0")
+    }
+}
+
+impl Protobuf<RawMisbehaviour> for Misbehaviour {}
+
+impl TryFrom<RawMisbehaviour> for Misbehaviour {
+    type Error = Error;
+
+    fn try_from(raw: RawMisbehaviour) -> Result<Self, Self::Error> {
+        if raw.sequence == 0 {
+            return Err(Error::invalid_raw_misbehaviour(
+                "sequence must be greater than zero".into(),
+            ));
+        }
+
+        Ok(Self {
+            client_id: raw
+                .client_id
+                .parse()
+                .map_err(|_| Error::invalid_raw_misbehaviour("invalid client id".into()))?,
+            sequence: raw.sequence,
+            signature_one: raw
+                .signature_one
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature one".into()))?
+                .into(),
+            signature_two: raw
+                .signature_two
+                .ok_or_else(|| Error::invalid_raw_misbehaviour("missing signature two".into()))?
+                .into(),
+        })
+    }
+}
+
+impl From<Misbehaviour> for RawMisbehaviour {
+    fn from(value: Misbehaviour) -> Self {
+        RawMisbehaviour {
+            client_id: value.client_id.to_string(),
+            sequence: value.sequence,
+            signature_one: Some(value.signature_one.into()),
+            signature_two: Some(value.signature_two.into()),
+        }
+    }
+}
+
+impl From<RawSignatureAndData> for SignatureAndData {
+    fn from(raw: RawSignatureAndData) -> Self {
+        Self {
+            signature: raw.signature,
+            data_type: raw.data_type,
+            data: raw.data,
+            timestamp: raw.timestamp,
+        }
+    }
+}
+
+impl From<SignatureAndData> for RawSignatureAndData {
+    fn from(value: SignatureAndData) -> Self {
+        RawSignatureAndData {
+            signature: value.signature,
+            data_type: value.data_type,
+            data: value.data,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+impl core::fmt::Display for Misbehaviour {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{} sequence: {}", self.client_id, self.sequence)
+    }
+}
@@ -0,0 +1,21 @@
+//! ICS 06: Solo Machine Client implements a client verification algorithm for standalone
+//! machines, like off-chain services, which sign their state with a single key.
+
+use serde::{Deserialize, Serialize};
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+pub mod misbehaviour;
+mod raw;
+
+/// The version of the `ibc.lightclients.solomachine` Protobuf package in which
+/// a solo machine client is encoded, which depends on the version of ibc-go.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Version {
+    /// Served by ibc-go v3 to v6
+    V2,
+    /// Served by ibc-go v7 and later
+    V3,
+}
@@ -0,0 +1,86 @@
+//! The Protobuf messages of the `ibc.lightclients.solomachine.v2` and
+//! `ibc.lightclients.solomachine.v3` packages, which are not part of `ibc-proto` yet.
+
+/// The messages of the `v2` package, served by ibc-go v3 to v6.
+///
+/// Except for `ClientState`, whose `frozen_sequence` became `is_frozen`, the messages of
+/// the `v2` package are encoded the same way as those of the `v1` package.
+pub mod v2 {
+    pub use ibc_proto::ibc::lightclients::solomachine::v1::{
+        ConsensusState, DataType, Header, HeaderData, Misbehaviour, SignBytes, SignatureAndData,
+    };
+
+    /// ClientState defines a solo machine client that tracks the current consensus
+    /// state and if the client is frozen.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ClientState {
+        /// latest sequence of the client state
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        /// frozen sequence of the solo machine
+        #[prost(bool, tag = "2")]
+        pub is_frozen: bool,
+        #[prost(message, optional, tag = "3")]
+        pub consensus_state: ::core::option::Option<ConsensusState>,
+        /// when set to true, will allow governance to update a solo machine client.
+        /// The client will be unfrozen if it is frozen.
+        #[prost(bool, tag = "4")]
+        pub allow_update_after_proposal: bool,
+    }
+}
+
+/// The messages of the `v3` package, served by ibc-go v7 and later.
+///
+/// The consensus state and the header data are encoded the same way as in the `v2` package.
+pub mod v3 {
+    use ibc_proto::google::protobuf::Any;
+
+    pub use super::v2::ConsensusState;
+
+    /// ClientState defines a solo machine client that tracks the current consensus
+    /// state and if the client is frozen.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ClientState {
+        /// latest sequence of the client state
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        /// frozen sequence of the solo machine
+        #[prost(bool, tag = "2")]
+        pub is_frozen: bool,
+        #[prost(message, optional, tag = "3")]
+        pub consensus_state: ::core::option::Option<ConsensusState>,
+    }
+
+    /// Header defines a solo machine consensus header
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Header {
+        #[prost(uint64, tag = "1")]
+        pub timestamp: u64,
+        #[prost(bytes = "vec", tag = "2")]
+        pub signature: ::prost::alloc::vec::Vec<u8>,
+        #[prost(message, optional, tag = "3")]
+        pub new_public_key: ::core::option::Option<Any>,
+        #[prost(string, tag = "4")]
+        pub new_diversifier: ::prost::alloc::string::String,
+    }
+
+    /// SignBytes defines the signed bytes used for signature verification.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SignBytes {
+        /// the sequence number
+        #[prost(uint64, tag = "1")]
+        pub sequence: u64,
+        /// the proof timestamp
+        #[prost(uint64, tag = "2")]
+        pub timestamp: u64,
+        /// the public key diversifier
+        #[prost(string, tag = "3")]
+        pub diversifier: ::prost::alloc::string::String,
+        /// the standardised path bytes
+        #[prost(bytes = "vec", tag = "4")]
+        pub path: ::prost::alloc::vec::Vec<u8>,
+        /// the marshaled data bytes
+        #[prost(bytes = "vec", tag = "5")]
+        pub data: ::prost::alloc::vec::Vec<u8>,
+    }
+}
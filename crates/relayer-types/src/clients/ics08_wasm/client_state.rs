@@ -0,0 +1,169 @@
+use core::convert::{TryFrom, TryInto};
+use core::time::Duration;
+
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics07_tendermint::client_state::ClientState as TmClientState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientState as RawWasmClientState;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::prelude::*;
+use crate::Height;
+
+pub const WASM_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientState";
+
+/// Client state of a Tendermint light client hosted by a Wasm contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    /// Checksum of the code of the contract
+    pub checksum: Vec<u8>,
+    /// Client state of the Tendermint light client, which the
+    /// latest height of the Wasm client state always mirrors
+    pub inner: TmClientState,
+}
+
+impl ClientState {
+    pub fn new(checksum: Vec<u8>, inner: TmClientState) -> Self {
+        Self { checksum, inner }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.inner.latest_height
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.inner.chain_id()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        self.inner.frozen_height()
+    }
+
+    fn upgrade(
+        &mut self,
+        upgrade_height: Height,
+        upgrade_options: &dyn CoreUpgradeOptions,
+        chain_id: ChainId,
+    ) {
+        self.inner
+            .upgrade(upgrade_height, upgrade_options, chain_id)
+    }
+
+    fn expired(&self, elapsed: Duration) -> bool {
+        self.inner.expired(elapsed)
+    }
+}
+
+impl Protobuf<RawWasmClientState> for ClientState {}
+
+impl TryFrom<RawWasmClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmClientState) -> Result<Self, Self::Error> {
+        if raw.checksum.is_empty() {
+            return Err(Error::invalid_raw_client_state(
+                "checksum cannot be empty".into(),
+            ));
+        }
+
+        let inner = Any::decode(raw.data.as_slice())
+            .map_err(Error::decode)?
+            .try_into()
+            .map_err(Error::invalid_wrapped_state)?;
+
+        Ok(Self::new(raw.checksum, inner))
+    }
+}
+
+impl From<ClientState> for RawWasmClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            latest_height: Some(value.inner.latest_height.into()),
+            data: Any::from(value.inner).encode_to_vec(),
+            checksum: value.checksum,
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawWasmClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: WASM_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawWasmClientState>::encode_vec(&client_state)
+                .expect("encoding to `Any` from `WasmClientState`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clients::ics07_tendermint::client_state::test_util::get_dummy_tendermint_client_state;
+    use crate::clients::ics07_tendermint::header::test_util::get_dummy_tendermint_header;
+
+    #[test]
+    fn client_state_roundtrip() {
+        let inner = get_dummy_tendermint_client_state(get_dummy_tendermint_header());
+        let client_state = ClientState::new(vec![0xab; 32], inner.clone());
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, WASM_CLIENT_STATE_TYPE_URL);
+        assert_eq!(ClientState::try_from(any).unwrap(), client_state);
+
+        let raw = RawWasmClientState::from(client_state.clone());
+        assert_eq!(raw.latest_height, Some(inner.latest_height.into()));
+        assert_eq!(Any::decode(raw.data.as_slice()).unwrap(), Any::from(inner));
+
+        assert_eq!(client_state.client_type(), ClientType::Wasm);
+
+        let no_checksum = RawWasmClientState {
+            checksum: vec![],
+            ..client_state.into()
+        };
+        assert!(ClientState::try_from(no_checksum).is_err());
+    }
+}
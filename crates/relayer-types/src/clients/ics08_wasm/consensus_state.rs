@@ -0,0 +1,105 @@
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::consensus_state::ConsensusState as TmConsensusState;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ConsensusState as RawWasmConsensusState;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics23_commitment::commitment::CommitmentRoot;
+use crate::timestamp::Timestamp;
+
+pub const WASM_CONSENSUS_STATE_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ConsensusState";
+
+/// Consensus state of a Tendermint light client hosted by a Wasm contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsensusState {
+    pub inner: TmConsensusState,
+}
+
+impl ConsensusState {
+    pub fn new(inner: TmConsensusState) -> Self {
+        Self { inner }
+    }
+}
+
+impl crate::core::ics02_client::consensus_state::ConsensusState for ConsensusState {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn root(&self) -> &CommitmentRoot {
+        &self.inner.root
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.inner.timestamp.into()
+    }
+}
+
+impl Protobuf<RawWasmConsensusState> for ConsensusState {}
+
+impl TryFrom<RawWasmConsensusState> for ConsensusState {
+    type Error = Error;
+
+    fn try_from(raw: RawWasmConsensusState) -> Result<Self, Self::Error> {
+        let inner = Any::decode(raw.data.as_slice())
+            .map_err(Error::decode)?
+            .try_into()
+            .map_err(Error::invalid_wrapped_state)?;
+
+        Ok(Self::new(inner))
+    }
+}
+
+impl From<ConsensusState> for RawWasmConsensusState {
+    fn from(value: ConsensusState) -> Self {
+        RawWasmConsensusState {
+            data: Any::from(value.inner).encode_to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for ConsensusState {}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_consensus_state<B: Buf>(buf: B) -> Result<ConsensusState, Error> {
+            RawWasmConsensusState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_CONSENSUS_STATE_TYPE_URL => {
+                decode_consensus_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_consensus_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ConsensusState> for Any {
+    fn from(consensus_state: ConsensusState) -> Self {
+        Any {
+            type_url: WASM_CONSENSUS_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawWasmConsensusState>::encode_vec(&consensus_state)
+                .expect("encoding to `Any` from `WasmConsensusState`"),
+        }
+    }
+}
+
+impl From<TmConsensusState> for ConsensusState {
+    fn from(inner: TmConsensusState) -> Self {
+        Self::new(inner)
+    }
+}
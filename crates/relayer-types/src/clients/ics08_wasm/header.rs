@@ -0,0 +1,108 @@
+use crate::prelude::*;
+
+use bytes::Buf;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+use prost::Message;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::clients::ics07_tendermint::header::Header as TmHeader;
+use crate::clients::ics08_wasm::error::Error;
+use crate::clients::ics08_wasm::raw::ClientMessage as RawClientMessage;
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// The `08-wasm` module wraps headers as well as misbehaviours in a `ClientMessage`.
+pub const WASM_HEADER_TYPE_URL: &str = "/ibc.lightclients.wasm.v1.ClientMessage";
+
+/// Header for a Tendermint light client hosted by a Wasm contract.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Header {
+    pub inner: TmHeader,
+}
+
+impl Header {
+    pub fn new(inner: TmHeader) -> Self {
+        Self { inner }
+    }
+
+    pub fn height(&self) -> Height {
+        self.inner.height()
+    }
+}
+
+impl crate::core::ics02_client::header::Header for Header {
+    fn client_type(&self) -> ClientType {
+        ClientType::Wasm
+    }
+
+    fn height(&self) -> Height {
+        self.height()
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        crate::core::ics02_client::header::Header::timestamp(&self.inner)
+    }
+}
+
+impl Protobuf<RawClientMessage> for Header {}
+
+impl TryFrom<RawClientMessage> for Header {
+    type Error = Error;
+
+    fn try_from(raw: RawClientMessage) -> Result<Self, Self::Error> {
+        let inner = Any::decode(raw.data.as_slice())
+            .map_err(Error::decode)?
+            .try_into()
+            .map_err(Error::invalid_wrapped_state)?;
+
+        Ok(Self::new(inner))
+    }
+}
+
+impl From<Header> for RawClientMessage {
+    fn from(value: Header) -> Self {
+        RawClientMessage {
+            data: Any::from(value.inner).encode_to_vec(),
+        }
+    }
+}
+
+impl Protobuf<Any> for Header {}
+
+impl TryFrom<Any> for Header {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Ics02Error> {
+        use core::ops::Deref;
+
+        fn decode_header<B: Buf>(buf: B) -> Result<Header, Error> {
+            RawClientMessage::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            WASM_HEADER_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            _ => Err(Ics02Error::unknown_header_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<Header> for Any {
+    fn from(header: Header) -> Self {
+        Any {
+            type_url: WASM_HEADER_TYPE_URL.to_string(),
+            value: Protobuf::<RawClientMessage>::encode_vec(&header)
+                .expect("encoding to `Any` from `WasmHeader`"),
+        }
+    }
+}
+
+impl From<TmHeader> for Header {
+    fn from(inner: TmHeader) -> Self {
+        Self::new(inner)
+    }
+}
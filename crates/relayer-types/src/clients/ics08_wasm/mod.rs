@@ -0,0 +1,9 @@
+//! ICS 08: Wasm Client wraps the states and messages of a light client which is
+//! implemented as a Wasm contract, here a Tendermint light client, along with
+//! the checksum of the code of that contract.
+
+pub mod client_state;
+pub mod consensus_state;
+pub mod error;
+pub mod header;
+mod raw;
@@ -0,0 +1,33 @@
+//! The Protobuf messages of the `ibc.lightclients.wasm.v1` package, served by
+//! the `08-wasm` light client module of ibc-go, which are not part of `ibc-proto` yet.
+
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// Wasm light client's ClientState
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    /// bytes encoding the client state of the underlying light client
+    /// implemented as a Wasm contract.
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub checksum: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub latest_height: ::core::option::Option<Height>,
+}
+
+/// Wasm light client's ConsensusState
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConsensusState {
+    /// bytes encoding the consensus state of the underlying light client
+    /// implemented as a Wasm contract.
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
+
+/// Wasm light client message (either header(s) or misbehaviour)
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientMessage {
+    #[prost(bytes = "vec", tag = "1")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+}
@@ -0,0 +1,163 @@
+use core::convert::{TryFrom, TryInto};
+use core::time::Duration;
+
+use prost::Message;
+use serde::{Deserialize, Serialize};
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::protobuf::Protobuf;
+
+use crate::clients::ics09_localhost::error::Error;
+use crate::clients::ics09_localhost::raw::ClientState as RawLocalhostClientState;
+use crate::core::ics02_client::client_state::{
+    ClientState as Ics2ClientState, UpgradeOptions as CoreUpgradeOptions,
+};
+use crate::core::ics02_client::client_type::ClientType;
+use crate::core::ics02_client::error::Error as Ics02Error;
+use crate::core::ics24_host::identifier::ChainId;
+use crate::prelude::*;
+use crate::Height;
+
+pub const LOCALHOST_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.localhost.v2.ClientState";
+
+/// Client state of the localhost client, which tracks the chain hosting it.
+///
+/// The identifier of that chain is not part of the encoded client state,
+/// so it must be set with [`ClientState::with_chain_id`] after decoding.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientState {
+    pub chain_id: ChainId,
+    pub latest_height: Height,
+}
+
+impl ClientState {
+    pub fn new(chain_id: ChainId, latest_height: Height) -> Self {
+        Self {
+            chain_id,
+            latest_height,
+        }
+    }
+
+    pub fn with_chain_id(self, chain_id: ChainId) -> Self {
+        Self { chain_id, ..self }
+    }
+
+    pub fn latest_height(&self) -> Height {
+        self.latest_height
+    }
+}
+
+impl Ics2ClientState for ClientState {
+    fn chain_id(&self) -> ChainId {
+        self.chain_id.clone()
+    }
+
+    fn client_type(&self) -> ClientType {
+        ClientType::Localhost
+    }
+
+    fn latest_height(&self) -> Height {
+        self.latest_height()
+    }
+
+    fn frozen_height(&self) -> Option<Height> {
+        None
+    }
+
+    fn upgrade(
+        &mut self,
+        _upgrade_height: Height,
+        _upgrade_options: &dyn CoreUpgradeOptions,
+        _chain_id: ChainId,
+    ) {
+        // The localhost client follows the chain hosting it, and is never upgraded
+    }
+
+    fn expired(&self, _elapsed: Duration) -> bool {
+        false
+    }
+}
+
+impl Protobuf<RawLocalhostClientState> for ClientState {}
+
+impl TryFrom<RawLocalhostClientState> for ClientState {
+    type Error = Error;
+
+    fn try_from(raw: RawLocalhostClientState) -> Result<Self, Self::Error> {
+        let latest_height = raw
+            .latest_height
+            .ok_or_else(|| Error::invalid_raw_client_state("missing latest height".into()))?
+            .try_into()
+            .map_err(|_| Error::invalid_raw_client_state("invalid latest height".into()))?;
+
+        Ok(Self::new(ChainId::default(), latest_height))
+    }
+}
+
+impl From<ClientState> for RawLocalhostClientState {
+    fn from(value: ClientState) -> Self {
+        Self {
+            latest_height: Some(value.latest_height.into()),
+        }
+    }
+}
+
+impl Protobuf<Any> for ClientState {}
+
+impl TryFrom<Any> for ClientState {
+    type Error = Ics02Error;
+
+    fn try_from(raw: Any) -> Result<Self, Self::Error> {
+        use bytes::Buf;
+        use core::ops::Deref;
+
+        fn decode_client_state<B: Buf>(buf: B) -> Result<ClientState, Error> {
+            RawLocalhostClientState::decode(buf)
+                .map_err(Error::decode)?
+                .try_into()
+        }
+
+        match raw.type_url.as_str() {
+            LOCALHOST_CLIENT_STATE_TYPE_URL => {
+                decode_client_state(raw.value.deref()).map_err(Into::into)
+            }
+            _ => Err(Ics02Error::unknown_client_state_type(raw.type_url)),
+        }
+    }
+}
+
+impl From<ClientState> for Any {
+    fn from(client_state: ClientState) -> Self {
+        Any {
+            type_url: LOCALHOST_CLIENT_STATE_TYPE_URL.to_string(),
+            value: Protobuf::<RawLocalhostClientState>::encode_vec(&client_state)
+                .expect("encoding to `Any` from `LocalhostClientState`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_state_roundtrip() {
+        let chain_id = ChainId::new("ibc".to_string(), 1);
+        let client_state = ClientState::new(chain_id.clone(), Height::new(1, 42).unwrap());
+
+        let any = Any::from(client_state.clone());
+        assert_eq!(any.type_url, LOCALHOST_CLIENT_STATE_TYPE_URL);
+
+        let decoded = ClientState::try_from(any).unwrap();
+        assert_eq!(decoded.latest_height(), Height::new(1, 42).unwrap());
+        assert_eq!(decoded.with_chain_id(chain_id), client_state);
+
+        assert_eq!(client_state.client_type(), ClientType::Localhost);
+        assert_eq!(client_state.frozen_height(), None);
+
+        let no_height = RawLocalhostClientState {
+            latest_height: None,
+        };
+        assert!(ClientState::try_from(no_height).is_err());
+    }
+}
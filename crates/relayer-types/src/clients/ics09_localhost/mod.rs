@@ -0,0 +1,35 @@
+//! ICS 09: Localhost Client lets a chain verify its own state, so that two modules
+//! of the same chain can communicate over IBC through the `connection-localhost`
+//! connection, which every chain running ibc-go v7.1 or later provides.
+//!
+//! The localhost client reads the state of its own chain directly instead of
+//! verifying Merkle proofs, so messages only carry a [`SENTINEL_PROOF`] in their place.
+
+use crate::prelude::*;
+
+use crate::core::ics23_commitment::commitment::CommitmentProofBytes;
+use crate::proofs::Proofs;
+use crate::Height;
+
+pub mod client_state;
+pub mod error;
+mod raw;
+
+/// The identifier of the localhost client, of which there is a single instance per chain.
+pub const LOCALHOST_CLIENT_ID: &str = "09-localhost";
+
+/// The identifier of the connection on top of the localhost client.
+pub const LOCALHOST_CONNECTION_ID: &str = "connection-localhost";
+
+/// The proof that messages verified by the localhost client must carry.
+pub const SENTINEL_PROOF: &[u8] = &[0x01];
+
+/// Builds the proofs of a message verified by the localhost client at the given height,
+/// where each of the proofs, e.g. the `proof_close` of `MsgTimeoutOnClose`, is the sentinel proof.
+pub fn sentinel_proofs(height: Height) -> Proofs {
+    let sentinel = CommitmentProofBytes::try_from(SENTINEL_PROOF.to_vec())
+        .expect("the sentinel proof is not empty");
+
+    Proofs::new(sentinel.clone(), None, None, Some(sentinel), height)
+        .expect("proofs without a consensus proof are always valid")
+}
@@ -0,0 +1,12 @@
+//! The Protobuf messages of the `ibc.lightclients.localhost.v2` package, served by
+//! the `09-localhost` light client module of ibc-go, which are not part of `ibc-proto` yet.
+
+use ibc_proto::ibc::core::client::v1::Height;
+
+/// ClientState defines the 09-localhost client state
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ClientState {
+    /// the latest block height
+    #[prost(message, optional, tag = "1")]
+    pub latest_height: ::core::option::Option<Height>,
+}
@@ -12,6 +12,8 @@ use ibc_proto::ibc::core::channel::v1::{
     IdentifiedChannel as RawIdentifiedChannel,
 };
 
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::raw::Channel as RawUpgradableChannel;
 use crate::core::ics04_channel::{error::Error, version::Version};
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 
@@ -80,14 +82,22 @@ pub struct ChannelEnd {
     pub remote: Counterparty,
     pub connection_hops: Vec<ConnectionId>,
     pub version: Version,
+    /// The sequence of the latest upgrade attempted on this channel end, or `0`
+    /// if it has never been upgraded
+    pub upgrade_sequence: Sequence,
 }
 
 impl Display for ChannelEnd {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(
             f,
-            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {} }}",
-            self.state, self.ordering, self.remote, PrettySlice(&self.connection_hops), self.version
+            "ChannelEnd {{ state: {}, ordering: {}, remote: {}, connection_hops: {}, version: {}, upgrade_sequence: {} }}",
+            self.state,
+            self.ordering,
+            self.remote,
+            PrettySlice(&self.connection_hops),
+            self.version,
+            self.upgrade_sequence
         )
     }
 }
@@ -100,11 +110,48 @@ impl Default for ChannelEnd {
             remote: Counterparty::default(),
             connection_hops: Vec::new(),
             version: Version::default(),
+            upgrade_sequence: Sequence::default(),
         }
     }
 }
 
-impl Protobuf<RawChannel> for ChannelEnd {}
+/// Channel ends are stored with the sequence of their latest upgrade,
+/// which is not part of the `Channel` message of `ibc-proto`.
+impl Protobuf<RawUpgradableChannel> for ChannelEnd {}
+
+impl TryFrom<RawUpgradableChannel> for ChannelEnd {
+    type Error = Error;
+
+    fn try_from(value: RawUpgradableChannel) -> Result<Self, Self::Error> {
+        let upgrade_sequence = value.upgrade_sequence.into();
+
+        let channel_end = ChannelEnd::try_from(RawChannel {
+            state: value.state,
+            ordering: value.ordering,
+            counterparty: value.counterparty,
+            connection_hops: value.connection_hops,
+            version: value.version,
+        })?;
+
+        Ok(channel_end.with_upgrade_sequence(upgrade_sequence))
+    }
+}
+
+impl From<ChannelEnd> for RawUpgradableChannel {
+    fn from(value: ChannelEnd) -> Self {
+        let upgrade_sequence = value.upgrade_sequence.into();
+        let raw = RawChannel::from(value);
+
+        RawUpgradableChannel {
+            state: raw.state,
+            ordering: raw.ordering,
+            counterparty: raw.counterparty,
+            connection_hops: raw.connection_hops,
+            version: raw.version,
+            upgrade_sequence,
+        }
+    }
+}
 
 impl TryFrom<RawChannel> for ChannelEnd {
     type Error = Error;
@@ -175,6 +222,14 @@ impl ChannelEnd {
             remote,
             connection_hops,
             version,
+            upgrade_sequence: Sequence::default(),
+        }
+    }
+
+    pub fn with_upgrade_sequence(self, upgrade_sequence: Sequence) -> Self {
+        Self {
+            upgrade_sequence,
+            ..self
         }
     }
 
@@ -216,6 +271,10 @@ impl ChannelEnd {
         &self.version
     }
 
+    pub fn upgrade_sequence(&self) -> &Sequence {
+        &self.upgrade_sequence
+    }
+
     pub fn validate_basic(&self) -> Result<(), Error> {
         if self.connection_hops.len() != 1 {
             return Err(Error::invalid_connection_hops_length(
@@ -383,6 +442,10 @@ pub enum State {
     TryOpen = 2,
     Open = 3,
     Closed = 4,
+    /// An upgrade is in progress and the in-flight packets sent before it are being flushed
+    Flushing = 5,
+    /// All in-flight packets were flushed, and the upgrade awaits the counterparty
+    Flushcomplete = 6,
 }
 
 impl State {
@@ -394,6 +457,8 @@ impl State {
             Self::TryOpen => "TRYOPEN",
             Self::Open => "OPEN",
             Self::Closed => "CLOSED",
+            Self::Flushing => "FLUSHING",
+            Self::Flushcomplete => "FLUSHCOMPLETE",
         }
     }
 
@@ -405,6 +470,8 @@ impl State {
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
             4 => Ok(Self::Closed),
+            5 => Ok(Self::Flushing),
+            6 => Ok(Self::Flushcomplete),
             _ => Err(Error::unknown_state(s)),
         }
     }
@@ -414,17 +481,40 @@ impl State {
         self == State::Open
     }
 
+    /// Returns whether or not the channel with this state is being upgraded.
+    pub fn is_upgrading(self) -> bool {
+        matches!(self, State::Flushing | State::Flushcomplete)
+    }
+
     /// Returns whether or not the channel with this state
     /// has progressed less or the same than the argument.
     ///
+    /// The upgrade states come after `Open`, from which an upgrade starts,
+    /// and `Closed` comes last, even though its discriminant is lower.
+    ///
     /// # Example
     /// ```rust,ignore
     /// assert!(State::Init.less_or_equal_progress(State::Open));
     /// assert!(State::TryOpen.less_or_equal_progress(State::TryOpen));
     /// assert!(!State::Closed.less_or_equal_progress(State::Open));
+    /// assert!(State::Open.less_or_equal_progress(State::Flushing));
+    /// assert!(!State::Flushcomplete.less_or_equal_progress(State::Flushing));
     /// ```
     pub fn less_or_equal_progress(self, other: Self) -> bool {
-        self as u32 <= other as u32
+        self.progress() <= other.progress()
+    }
+
+    /// The rank of this state in the order in which a channel progresses.
+    fn progress(self) -> u32 {
+        match self {
+            Self::Uninitialized => 0,
+            Self::Init => 1,
+            Self::TryOpen => 2,
+            Self::Open => 3,
+            Self::Flushing => 4,
+            Self::Flushcomplete => 5,
+            Self::Closed => 6,
+        }
     }
 }
 
@@ -608,4 +698,29 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn channel_state_progress() {
+        use super::State;
+
+        let states = [
+            State::Uninitialized,
+            State::Init,
+            State::TryOpen,
+            State::Open,
+            State::Flushing,
+            State::Flushcomplete,
+            State::Closed,
+        ];
+
+        for (i, state) in states.iter().enumerate() {
+            for (j, other) in states.iter().enumerate() {
+                assert_eq!(
+                    state.less_or_equal_progress(*other),
+                    i <= j,
+                    "{state} <= {other}"
+                );
+            }
+        }
+    }
 }
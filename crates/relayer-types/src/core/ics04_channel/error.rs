@@ -343,7 +343,26 @@ define_error! {
 
         AbciConversionFailed
             { abci_event: String }
-            | e | { format_args!("Failed to convert abci event to IbcEvent: {}", e.abci_event)}
+            | e | { format_args!("Failed to convert abci event to IbcEvent: {}", e.abci_event)},
+
+        MissingUpgrade
+            | _ | { "missing channel upgrade" },
+
+        MissingUpgradeFields
+            | _ | { "missing channel upgrade fields" },
+
+        MissingUpgradeTimeout
+            | _ | { "missing channel upgrade timeout" },
+
+        InvalidUpgradeTimeout
+            | _ | { "invalid channel upgrade timeout: a timeout height or a timeout timestamp must be set" },
+
+        InvalidUpgradeTimeoutTimestamp
+            [ crate::timestamp::ParseTimestampError ]
+            | _ | { "invalid channel upgrade timeout timestamp" },
+
+        MissingErrorReceipt
+            | _ | { "missing channel upgrade error receipt" },
     }
 }
 
@@ -6,7 +6,7 @@ use tendermint_rpc::abci::tag::Tag;
 use tendermint_rpc::abci::Event as AbciEvent;
 
 use crate::core::ics04_channel::error::Error;
-use crate::core::ics04_channel::packet::Packet;
+use crate::core::ics04_channel::packet::{Packet, Sequence};
 use crate::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
 use crate::events::{Error as EventError, IbcEvent, IbcEventType};
 use crate::prelude::*;
@@ -19,6 +19,9 @@ pub const PORT_ID_ATTRIBUTE_KEY: &str = "port_id";
 pub const COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY: &str = "counterparty_channel_id";
 pub const COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY: &str = "counterparty_port_id";
 
+/// Channel upgrade event attribute keys
+pub const UPGRADE_SEQUENCE_ATTRIBUTE_KEY: &str = "upgrade_sequence";
+
 /// Packet event attribute keys
 pub const PKT_SEQ_ATTRIBUTE_KEY: &str = "packet_sequence";
 pub const PKT_DATA_ATTRIBUTE_KEY: &str = "packet_data";
@@ -561,6 +564,261 @@ impl_from_ibc_to_abci_event!(
     CloseConfirm
 );
 
+/// The attributes of the events emitted by the steps of the channel upgrade handshake.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct UpgradeAttributes {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+impl UpgradeAttributes {
+    pub fn port_id(&self) -> &PortId {
+        &self.port_id
+    }
+
+    pub fn channel_id(&self) -> &ChannelId {
+        &self.channel_id
+    }
+}
+
+impl Display for UpgradeAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match &self.counterparty_channel_id {
+            Some(counterparty_channel_id) => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: {}, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, counterparty_channel_id, self.upgrade_sequence),
+            None => write!(f, "UpgradeAttributes {{ port_id: {}, channel_id: {}, counterparty_port_id: {}, counterparty_channel_id: None, upgrade_sequence: {} }}", self.port_id, self.channel_id, self.counterparty_port_id, self.upgrade_sequence),
+        }
+    }
+}
+
+/// Convert attributes to Tendermint ABCI tags
+///
+/// # Note
+/// The parsing of `Key`s and `Value`s never fails, because the
+/// `FromStr` instance of `tendermint::abci::tag::{Key, Value}`
+/// is infallible, even if it is not represented in the error type.
+/// Once tendermint-rs improves the API of the `Key` and `Value` types,
+/// we will be able to remove the `.parse().unwrap()` calls.
+impl From<UpgradeAttributes> for Vec<Tag> {
+    fn from(a: UpgradeAttributes) -> Self {
+        let mut attributes = vec![];
+        let port_id = Tag {
+            key: PORT_ID_ATTRIBUTE_KEY.parse().unwrap(),
+            value: a.port_id.to_string().parse().unwrap(),
+        };
+        attributes.push(port_id);
+        let channel_id = Tag {
+            key: CHANNEL_ID_ATTRIBUTE_KEY.parse().unwrap(),
+            value: a.channel_id.to_string().parse().unwrap(),
+        };
+        attributes.push(channel_id);
+        let counterparty_port_id = Tag {
+            key: COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY.parse().unwrap(),
+            value: a.counterparty_port_id.to_string().parse().unwrap(),
+        };
+        attributes.push(counterparty_port_id);
+        if let Some(channel_id) = a.counterparty_channel_id {
+            let channel_id = Tag {
+                key: COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY.parse().unwrap(),
+                value: channel_id.to_string().parse().unwrap(),
+            };
+            attributes.push(channel_id);
+        }
+        let upgrade_sequence = Tag {
+            key: UPGRADE_SEQUENCE_ATTRIBUTE_KEY.parse().unwrap(),
+            value: a.upgrade_sequence.to_string().parse().unwrap(),
+        };
+        attributes.push(upgrade_sequence);
+        attributes
+    }
+}
+
+/// Emitted when a channel end proposes an upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeInit {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when a channel end accepts the upgrade proposed by its counterparty.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeTry {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when the channel end which proposed an upgrade acknowledges
+/// that its counterparty accepted it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeAck {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when a channel end confirms that its counterparty acknowledged the upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeConfirm {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when a channel end is open again with the upgraded fields.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeOpen {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when a channel end aborts an upgrade whose timeout has elapsed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeTimeout {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when a channel end aborts an upgrade which failed on its counterparty.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeCancel {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when the upgrade of a channel end failed, along with an error receipt
+/// which is then proven to the counterparty to cancel its upgrade as well.
+///
+/// The message of the error receipt is not kept, since it is queried from the chain anyway.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct UpgradeError {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+/// Emitted when the last packet in flight of an upgrading channel end was acknowledged
+/// or timed out, which then awaits its counterparty to be flushed as well.
+///
+/// This event carries no upgrade sequence, which is then left to its default value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FlushComplete {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: Option<ChannelId>,
+    pub upgrade_sequence: Sequence,
+}
+
+macro_rules! impl_upgrade_event {
+    ($($event:ident => $variant:ident),+) => {
+        $(impl $event {
+            pub fn port_id(&self) -> &PortId {
+                &self.port_id
+            }
+
+            pub fn channel_id(&self) -> &ChannelId {
+                &self.channel_id
+            }
+
+            pub fn counterparty_port_id(&self) -> &PortId {
+                &self.counterparty_port_id
+            }
+
+            pub fn counterparty_channel_id(&self) -> Option<&ChannelId> {
+                self.counterparty_channel_id.as_ref()
+            }
+        }
+
+        impl Display for $event {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                write!(f, "{}({})", stringify!($event), UpgradeAttributes::from(self.clone()))
+            }
+        }
+
+        impl From<$event> for UpgradeAttributes {
+            fn from(ev: $event) -> Self {
+                Self {
+                    port_id: ev.port_id,
+                    channel_id: ev.channel_id,
+                    counterparty_port_id: ev.counterparty_port_id,
+                    counterparty_channel_id: ev.counterparty_channel_id,
+                    upgrade_sequence: ev.upgrade_sequence,
+                }
+            }
+        }
+
+        impl From<UpgradeAttributes> for $event {
+            fn from(attrs: UpgradeAttributes) -> Self {
+                Self {
+                    port_id: attrs.port_id,
+                    channel_id: attrs.channel_id,
+                    counterparty_port_id: attrs.counterparty_port_id,
+                    counterparty_channel_id: attrs.counterparty_channel_id,
+                    upgrade_sequence: attrs.upgrade_sequence,
+                }
+            }
+        }
+
+        impl From<$event> for IbcEvent {
+            fn from(v: $event) -> Self {
+                IbcEvent::$variant(v)
+            }
+        }
+
+        impl EventType for $event {
+            fn event_type() -> IbcEventType {
+                IbcEventType::$variant
+            }
+        }
+
+        impl From<$event> for AbciEvent {
+            fn from(v: $event) -> Self {
+                let attributes = Vec::<Tag>::from(UpgradeAttributes::from(v));
+                let type_str = <$event>::event_type().as_str().to_string();
+                AbciEvent {
+                    type_str,
+                    attributes,
+                }
+            }
+        })+
+    };
+}
+
+impl_upgrade_event!(
+    UpgradeInit => UpgradeInitChannel,
+    UpgradeTry => UpgradeTryChannel,
+    UpgradeAck => UpgradeAckChannel,
+    UpgradeConfirm => UpgradeConfirmChannel,
+    UpgradeOpen => UpgradeOpenChannel,
+    UpgradeTimeout => UpgradeTimeoutChannel,
+    UpgradeCancel => UpgradeCancelChannel,
+    UpgradeError => UpgradeErrorChannel,
+    FlushComplete => FlushCompleteChannel
+);
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SendPacket {
     pub packet: Packet,
@@ -0,0 +1,288 @@
+//! Types for the upgrade of a channel, which changes the fields of both of its ends,
+//! e.g. its version, in a handshake driven by the relayer.
+
+use crate::prelude::*;
+
+use core::str::FromStr;
+
+use ibc_proto::protobuf::Protobuf;
+use serde::{Deserialize, Serialize};
+
+use crate::core::ics04_channel::channel::Order;
+use crate::core::ics04_channel::error::Error;
+use crate::core::ics04_channel::packet::Sequence;
+use crate::core::ics04_channel::raw::{
+    ErrorReceipt as RawErrorReceipt, Timeout as RawTimeout, Upgrade as RawUpgrade,
+    UpgradeFields as RawUpgradeFields,
+};
+use crate::core::ics04_channel::timeout::TimeoutHeight;
+use crate::core::ics04_channel::version::Version;
+use crate::core::ics24_host::identifier::ConnectionId;
+use crate::timestamp::Timestamp;
+use crate::Height;
+
+/// The fields of a channel end which are changed by an upgrade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeFields {
+    pub ordering: Order,
+    pub connection_hops: Vec<ConnectionId>,
+    pub version: Version,
+}
+
+impl UpgradeFields {
+    pub fn new(ordering: Order, connection_hops: Vec<ConnectionId>, version: Version) -> Self {
+        Self {
+            ordering,
+            connection_hops,
+            version,
+        }
+    }
+}
+
+impl Protobuf<RawUpgradeFields> for UpgradeFields {}
+
+impl TryFrom<RawUpgradeFields> for UpgradeFields {
+    type Error = Error;
+
+    fn try_from(value: RawUpgradeFields) -> Result<Self, Self::Error> {
+        let ordering = Order::from_i32(value.ordering)?;
+
+        let connection_hops = value
+            .connection_hops
+            .iter()
+            .map(|conn_id| ConnectionId::from_str(conn_id))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Error::identifier)?;
+
+        Ok(Self::new(ordering, connection_hops, value.version.into()))
+    }
+}
+
+impl From<UpgradeFields> for RawUpgradeFields {
+    fn from(value: UpgradeFields) -> Self {
+        Self {
+            ordering: value.ordering as i32,
+            connection_hops: value
+                .connection_hops
+                .iter()
+                .map(|conn_id| conn_id.to_string())
+                .collect(),
+            version: value.version.to_string(),
+        }
+    }
+}
+
+/// The deadline for the counterparty to complete an upgrade,
+/// after which the upgrade can be cancelled with `MsgChannelUpgradeTimeout`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeTimeout {
+    pub height: TimeoutHeight,
+    pub timestamp: Timestamp,
+}
+
+impl UpgradeTimeout {
+    pub fn new(height: TimeoutHeight, timestamp: Timestamp) -> Result<Self, Error> {
+        if height == TimeoutHeight::Never && timestamp == Timestamp::none() {
+            return Err(Error::invalid_upgrade_timeout());
+        }
+
+        Ok(Self { height, timestamp })
+    }
+
+    /// Whether the counterparty reached this deadline at the given height and timestamp,
+    /// i.e. whether either of them is at or past the deadline, as checked by the chain
+    /// when handling `MsgChannelUpgradeTimeout`.
+    pub fn has_elapsed(&self, height: Height, timestamp: Timestamp) -> bool {
+        let height_elapsed = match self.height {
+            TimeoutHeight::At(timeout_height) => height >= timeout_height,
+            TimeoutHeight::Never => false,
+        };
+
+        let timestamp_elapsed = self.timestamp != Timestamp::none()
+            && timestamp.nanoseconds() >= self.timestamp.nanoseconds();
+
+        height_elapsed || timestamp_elapsed
+    }
+}
+
+impl Protobuf<RawTimeout> for UpgradeTimeout {}
+
+impl TryFrom<RawTimeout> for UpgradeTimeout {
+    type Error = Error;
+
+    fn try_from(value: RawTimeout) -> Result<Self, Self::Error> {
+        let height =
+            TimeoutHeight::try_from(value.height).map_err(|_| Error::invalid_timeout_height())?;
+
+        let timestamp = Timestamp::from_nanoseconds(value.timestamp)
+            .map_err(Error::invalid_upgrade_timeout_timestamp)?;
+
+        Self::new(height, timestamp)
+    }
+}
+
+impl From<UpgradeTimeout> for RawTimeout {
+    fn from(value: UpgradeTimeout) -> Self {
+        Self {
+            height: value.height.into(),
+            timestamp: value.timestamp.nanoseconds(),
+        }
+    }
+}
+
+/// An upgrade proposed by one end of a channel, as stored by the chain hosting that end.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Upgrade {
+    pub fields: UpgradeFields,
+    pub timeout: UpgradeTimeout,
+    /// The next sequence of the packets sent over the channel when the upgrade was
+    /// proposed, which tells the counterparty which packets remain to be flushed
+    pub next_sequence_send: Sequence,
+}
+
+impl Protobuf<RawUpgrade> for Upgrade {}
+
+impl TryFrom<RawUpgrade> for Upgrade {
+    type Error = Error;
+
+    fn try_from(value: RawUpgrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            fields: value
+                .fields
+                .ok_or_else(Error::missing_upgrade_fields)?
+                .try_into()?,
+            timeout: value
+                .timeout
+                .ok_or_else(Error::missing_upgrade_timeout)?
+                .try_into()?,
+            next_sequence_send: value.next_sequence_send.into(),
+        })
+    }
+}
+
+impl From<Upgrade> for RawUpgrade {
+    fn from(value: Upgrade) -> Self {
+        Self {
+            fields: Some(value.fields.into()),
+            timeout: Some(value.timeout.into()),
+            next_sequence_send: value.next_sequence_send.into(),
+        }
+    }
+}
+
+/// The receipt of the last upgrade of a channel end which failed, with the
+/// sequence of that upgrade, proving to the counterparty that it can be cancelled.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReceipt {
+    pub sequence: Sequence,
+    pub message: String,
+}
+
+impl Protobuf<RawErrorReceipt> for ErrorReceipt {}
+
+impl TryFrom<RawErrorReceipt> for ErrorReceipt {
+    type Error = Error;
+
+    fn try_from(value: RawErrorReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sequence: value.sequence.into(),
+            message: value.message,
+        })
+    }
+}
+
+impl From<ErrorReceipt> for RawErrorReceipt {
+    fn from(value: ErrorReceipt) -> Self {
+        Self {
+            sequence: value.sequence.into(),
+            message: value.message,
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_util {
+    use crate::prelude::*;
+
+    use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+
+    use crate::core::ics04_channel::raw::{
+        Timeout as RawTimeout, Upgrade as RawUpgrade, UpgradeFields as RawUpgradeFields,
+    };
+    use crate::core::ics24_host::identifier::ConnectionId;
+
+    /// Returns a dummy `RawUpgradeFields`, for testing only!
+    pub fn get_dummy_raw_upgrade_fields() -> RawUpgradeFields {
+        RawUpgradeFields {
+            ordering: 1,
+            connection_hops: vec![ConnectionId::default().to_string()],
+            version: "ics20-1".to_string(),
+        }
+    }
+
+    /// Returns a dummy `RawUpgrade`, for testing only!
+    pub fn get_dummy_raw_upgrade() -> RawUpgrade {
+        RawUpgrade {
+            fields: Some(get_dummy_raw_upgrade_fields()),
+            timeout: Some(RawTimeout {
+                height: Some(RawHeight {
+                    revision_number: 0,
+                    revision_height: 100,
+                }),
+                timestamp: 0,
+            }),
+            next_sequence_send: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    use test_log::test;
+
+    use crate::core::ics04_channel::raw::{Timeout as RawTimeout, Upgrade as RawUpgrade};
+    use crate::core::ics04_channel::timeout::TimeoutHeight;
+    use crate::core::ics04_channel::upgrade::test_util::get_dummy_raw_upgrade;
+    use crate::core::ics04_channel::upgrade::{Upgrade, UpgradeTimeout};
+    use crate::timestamp::Timestamp;
+    use crate::Height;
+
+    #[test]
+    fn upgrade_try_from_raw() {
+        let raw = get_dummy_raw_upgrade();
+        let upgrade = Upgrade::try_from(raw.clone()).unwrap();
+        assert_eq!(RawUpgrade::from(upgrade), raw);
+
+        let no_timeout = RawUpgrade {
+            timeout: Some(RawTimeout {
+                height: None,
+                timestamp: 0,
+            }),
+            ..raw.clone()
+        };
+        assert!(Upgrade::try_from(no_timeout).is_err());
+
+        let no_fields = RawUpgrade {
+            fields: None,
+            ..raw
+        };
+        assert!(Upgrade::try_from(no_fields).is_err());
+    }
+
+    #[test]
+    fn upgrade_timeout_elapsed() {
+        let height = |h| Height::new(0, h).unwrap();
+        let timestamp = |t| Timestamp::from_nanoseconds(t).unwrap();
+
+        let at_height =
+            UpgradeTimeout::new(TimeoutHeight::At(height(10)), Timestamp::none()).unwrap();
+        assert!(!at_height.has_elapsed(height(9), timestamp(1_000)));
+        assert!(at_height.has_elapsed(height(10), timestamp(0)));
+
+        let at_timestamp = UpgradeTimeout::new(TimeoutHeight::Never, timestamp(100)).unwrap();
+        assert!(!at_timestamp.has_elapsed(height(u64::MAX), timestamp(99)));
+        assert!(at_timestamp.has_elapsed(height(1), timestamp(100)));
+    }
+}
@@ -171,6 +171,9 @@ impl ClientId {
     pub fn prefix(client_type: ClientType) -> &'static str {
         match client_type {
             ClientType::Tendermint => ClientType::Tendermint.as_str(),
+            ClientType::Solomachine => ClientType::Solomachine.as_str(),
+            ClientType::Wasm => ClientType::Wasm.as_str(),
+            ClientType::Localhost => ClientType::Localhost.as_str(),
 
             #[cfg(any(test, feature = "mocks"))]
             ClientType::Mock => ClientType::Mock.as_str(),
@@ -27,6 +27,14 @@ const UPGRADED_CLIENT_STATE: &str = "upgradedClient";
 /// - The key identifying the upgraded consensus state
 const UPGRADED_CLIENT_CONSENSUS_STATE: &str = "upgradedConsState";
 
+/// ABCI channel upgrade keys
+/// - The key identifying the channel upgrades within the IBC sub-store
+const CHANNEL_UPGRADES: &str = "channelUpgrades";
+/// - The key identifying the upgrade of a channel
+const CHANNEL_UPGRADE: &str = "upgrades";
+/// - The key identifying the error receipt of the last failed upgrade of a channel
+const CHANNEL_UPGRADE_ERROR: &str = "upgradeError";
+
 /// The Path enum abstracts out the different sub-paths.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, From, Display)]
 pub enum Path {
@@ -44,6 +52,7 @@ pub enum Path {
     Acks(AcksPath),
     Receipts(ReceiptsPath),
     Upgrade(ClientUpgradePath),
+    ChannelUpgrade(ChannelUpgradePath),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
@@ -148,6 +157,27 @@ pub enum ClientUpgradePath {
     UpgradedClientConsensusState(u64),
 }
 
+/// Paths that are specific for channel upgrades.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
+pub enum ChannelUpgradePath {
+    #[display(
+        fmt = "{}/{}/ports/{}/channels/{}",
+        CHANNEL_UPGRADES,
+        CHANNEL_UPGRADE,
+        _0,
+        _1
+    )]
+    Upgrade(PortId, ChannelId),
+    #[display(
+        fmt = "{}/{}/ports/{}/channels/{}",
+        CHANNEL_UPGRADES,
+        CHANNEL_UPGRADE_ERROR,
+        _0,
+        _1
+    )]
+    UpgradeError(PortId, ChannelId),
+}
+
 /// Sub-paths which are not part of the specification, but are still
 /// useful to represent for parsing purposes.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -193,6 +223,7 @@ impl FromStr for Path {
             .or_else(|| parse_acks(&components))
             .or_else(|| parse_receipts(&components))
             .or_else(|| parse_upgrades(&components))
+            .or_else(|| parse_channel_upgrades(&components))
             .ok_or_else(|| PathError::parse_failure(s.to_string()))
     }
 }
@@ -603,6 +634,37 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
     }
 }
 
+fn parse_channel_upgrades(components: &[&str]) -> Option<Path> {
+    if components.len() != 6 {
+        return None;
+    }
+
+    if components[0] != CHANNEL_UPGRADES {
+        return None;
+    }
+
+    let port = parse_ports(&components[2..=3]);
+    let channel = parse_channels(&components[4..=5]);
+
+    let port_id = if let Some(Path::Ports(PortsPath(port_id))) = port {
+        port_id
+    } else {
+        return None;
+    };
+
+    let channel_id = if let Some(SubPath::Channels(channel_id)) = channel {
+        channel_id
+    } else {
+        return None;
+    };
+
+    match components[1] {
+        CHANNEL_UPGRADE => Some(ChannelUpgradePath::Upgrade(port_id, channel_id).into()),
+        CHANNEL_UPGRADE_ERROR => Some(ChannelUpgradePath::UpgradeError(port_id, channel_id).into()),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1014,4 +1076,29 @@ mod tests {
             Path::Upgrade(ClientUpgradePath::UpgradedClientConsensusState(0)),
         );
     }
+
+    #[test]
+    fn channel_upgrade_paths_parse() {
+        let path = "channelUpgrades/upgrades/ports/defaultPort/channels/channel-0";
+
+        assert_eq!(
+            Path::from_str(path).unwrap(),
+            Path::ChannelUpgrade(ChannelUpgradePath::Upgrade(
+                PortId::default(),
+                ChannelId::default()
+            )),
+        );
+        assert_eq!(Path::from_str(path).unwrap().to_string(), path);
+
+        let path = "channelUpgrades/upgradeError/ports/defaultPort/channels/channel-0";
+
+        assert_eq!(
+            Path::from_str(path).unwrap(),
+            Path::ChannelUpgrade(ChannelUpgradePath::UpgradeError(
+                PortId::default(),
+                ChannelId::default()
+            )),
+        );
+        assert_eq!(Path::from_str(path).unwrap().to_string(), path);
+    }
 }
@@ -19,10 +19,13 @@ use tendermint_rpc::{
     Client, HttpClient, Order,
 };
 use tokio::runtime::Runtime as TokioRuntime;
+use tonic::transport::Channel;
 use tonic::{codegen::http::Uri, metadata::AsciiMetadataValue};
 use tracing::{error, instrument, trace, warn};
 
 use ibc_proto::cosmos::staking::v1beta1::Params as StakingParams;
+use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     AllowUpdate, ClientState as TmClientState,
@@ -37,14 +40,15 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 };
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentPrefix;
 use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
 use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::core::ics24_host::path::{
-    AcksPath, ChannelEndsPath, ClientConsensusStatePath, ClientStatePath, CommitmentsPath,
-    ConnectionsPath, ReceiptsPath, SeqRecvsPath,
+    AcksPath, ChannelEndsPath, ChannelUpgradePath, ClientConsensusStatePath, ClientStatePath,
+    CommitmentsPath, ConnectionsPath, ReceiptsPath, SeqRecvsPath,
 };
 use ibc_relayer_types::core::ics24_host::{
     ClientUpgradePath, Path, IBC_QUERY_PATH, SDK_UPGRADE_QUERY_PATH,
@@ -57,18 +61,23 @@ use crate::chain::cosmos::batch::sequential_send_batched_messages_and_wait_commi
 use crate::chain::cosmos::batch::{
     send_batched_messages_and_wait_check_tx, send_batched_messages_and_wait_commit,
 };
-use crate::chain::cosmos::encode::key_entry_to_signer;
+use crate::chain::cosmos::encode::key_to_signer;
 use crate::chain::cosmos::fee::maybe_register_counterparty_payee;
 use crate::chain::cosmos::gas::{calculate_fee, mul_ceil};
+use crate::chain::cosmos::key_pool::{set_signer, KeyPool, PRIMARY_KEY};
 use crate::chain::cosmos::query::account::get_or_fetch_account;
 use crate::chain::cosmos::query::balance::{query_all_balances, query_balance};
 use crate::chain::cosmos::query::denom_trace::query_denom_trace;
+use crate::chain::cosmos::query::interchain_account::query_interchain_account;
+use crate::chain::cosmos::query::fee::query_incentivized_packets;
+use crate::chain::cosmos::query::fee_market::query_base_fee;
+use crate::chain::cosmos::query::results::{block_results, AttributeEncoding};
 use crate::chain::cosmos::query::status::query_status;
 use crate::chain::cosmos::query::tx::{
-    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_txs,
+    filter_matching_event, query_packets_from_block, query_packets_from_txs, query_tx_response,
+    query_txs,
 };
 use crate::chain::cosmos::query::{abci_query, fetch_version_specs, packet_query, QueryResponse, custom::cross_chain_query_via_rpc};
-use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::{
     default_gas_from_config, gas_multiplier_from_config, max_gas_from_config,
@@ -77,13 +86,14 @@ use crate::chain::endpoint::{ChainEndpoint, ChainStatus, HealthCheck};
 use crate::chain::requests::{CrossChainQueryRequest, Qualified, QueryPacketEventDataRequest};
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::{AnyClientState, IdentifiedAnyClientState};
-use crate::config::ChainConfig;
+use crate::config::{ChainConfig, EventSourceMode, GasPrice};
 use crate::consensus_state::{AnyConsensusState, AnyConsensusStateWithHeight};
 use crate::denom::DenomTrace;
 use crate::error::Error;
 use crate::event::monitor::{EventReceiver, TxMonitorCmd};
+use crate::event::pull::PullEventMonitor;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{KeyEntry, KeyRing};
+use crate::keyring::{KeyRing, KeySigner};
 use crate::light_client::tendermint::LightClient as TmLightClient;
 use crate::light_client::{LightClient, Verified};
 use crate::misbehaviour::MisbehaviourEvidence;
@@ -96,20 +106,23 @@ use super::requests::{
     QueryClientConnectionsRequest, QueryClientStateRequest, QueryClientStatesRequest,
     QueryConnectionChannelsRequest, QueryConnectionRequest, QueryConnectionsRequest,
     QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight,
-    QueryHostConsensusStateRequest, QueryNextSequenceReceiveRequest,
-    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementsRequest,
-    QueryPacketCommitmentRequest, QueryPacketCommitmentsRequest, QueryPacketReceiptRequest,
-    QueryTxRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
-    QueryUpgradedClientStateRequest, QueryUpgradedConsensusStateRequest,
+    QueryHostConsensusStateRequest, QueryIncentivizedPacketsRequest,
+    QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementRequest,
+    QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest,
+    QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryTxRequest,
+    QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest, QueryUpgradeErrorRequest,
+    QueryUpgradeRequest, QueryUpgradedClientStateRequest, QueryUpgradedConsensusStateRequest,
 };
 
 pub mod batch;
+pub mod budget;
 pub mod client;
 pub mod compatibility;
 pub mod encode;
 pub mod estimate;
 pub mod fee;
 pub mod gas;
+pub mod key_pool;
 pub mod query;
 pub mod retry;
 pub mod simulate;
@@ -141,8 +154,11 @@ pub struct CosmosSdkChain {
     light_client: TmLightClient,
     rt: Arc<TokioRuntime>,
     keybase: KeyRing,
-    /// A cached copy of the account information
-    account: Option<Account>,
+    /// The keys used to submit transactions, with their account information
+    key_pool: KeyPool,
+    /// The gRPC channel over which the base fee is queried, if the dynamic gas
+    /// price is enabled. It is connected on first use, and reconnects by itself.
+    fee_market_channel: Option<Channel>,
 }
 
 impl CosmosSdkChain {
@@ -156,12 +172,115 @@ impl CosmosSdkChain {
         self.config.max_tx_size.into()
     }
 
-    fn key(&self) -> Result<KeyEntry, Error> {
+    fn key(&self) -> Result<KeySigner, Error> {
         self.keybase()
-            .get_key(&self.config.key_name)
+            .get_key_signer(&self.config.key_name)
             .map_err(Error::key_base)
     }
 
+    /// Select the key with which to submit the given messages,
+    /// and make the account of that key the signer of the messages.
+    async fn assign_key(&mut self, msgs: Vec<Any>) -> Result<(usize, KeySigner, Vec<Any>), Error> {
+        if self.key_pool.needs_refresh(&msgs, self.config.rpc_timeout) {
+            self.refresh_in_flight_txs().await;
+        }
+
+        let index = self.key_pool.select(&msgs);
+
+        let key_entry = self
+            .keybase()
+            .get_key_signer(self.key_pool.key_name(index))
+            .map_err(Error::key_base)?;
+
+        if index == PRIMARY_KEY {
+            return Ok((index, key_entry, msgs));
+        }
+
+        trace!(
+            key = self.key_pool.key_name(index),
+            "submitting messages with additional key"
+        );
+
+        let signer = key_to_signer(&key_entry, &self.config.account_prefix)?;
+        let msgs = set_signer(msgs, signer.as_ref())?;
+
+        Ok((index, key_entry, msgs))
+    }
+
+    /// Check which of the transactions in flight in the key pool have been committed,
+    /// querying all of them concurrently.
+    async fn refresh_in_flight_txs(&mut self) {
+        let in_flight = self.key_pool.in_flight();
+
+        let responses = join_all(in_flight.iter().map(|(_, tx_hash)| {
+            query_tx_response(
+                &self.rpc_client,
+                &self.config.rpc_addr,
+                self.tx_config.attribute_encoding,
+                tx_hash,
+            )
+        }))
+        .await;
+
+        for ((index, tx_hash), response) in in_flight.into_iter().zip(responses) {
+            match response {
+                Ok(Some(_)) => self.key_pool.committed(index, &tx_hash),
+                Ok(None) => {}
+                Err(e) => {
+                    trace!(
+                        "failed to check whether transaction {} was committed: {}",
+                        tx_hash,
+                        e
+                    )
+                }
+            }
+        }
+
+        self.key_pool.expire(self.config.rpc_timeout);
+    }
+
+    /// Set the gas price of the next transactions to the gas price discovered
+    /// from the fee market of the chain, if the dynamic gas price is enabled.
+    ///
+    /// Falls back to the static gas price if the base fee cannot be queried.
+    async fn update_dynamic_gas_price(&mut self) {
+        let dynamic_gas_price = &self.config.dynamic_gas_price;
+
+        if !dynamic_gas_price.enabled {
+            return;
+        }
+
+        let static_gas_price = &self.config.gas_price;
+
+        let grpc_addr = &self.grpc_addr;
+        let channel = self
+            .fee_market_channel
+            .get_or_insert_with(|| Channel::builder(grpc_addr.clone()).connect_lazy())
+            .clone();
+
+        let price = match query_base_fee(channel, &static_gas_price.denom).await {
+            Ok(base_fee) => dynamic_gas_price.price_from_base_fee(base_fee),
+            Err(e) => {
+                warn!(
+                    "failed to query the base fee, falling back to the static gas price {}: {}",
+                    static_gas_price, e
+                );
+
+                static_gas_price.price
+            }
+        };
+
+        crate::telemetry!(
+            dynamic_gas_price,
+            &self.config.id,
+            price,
+            &static_gas_price.denom
+        );
+
+        let gas_price = GasPrice::new(price, static_gas_price.denom.clone());
+        self.tx_config.gas_config.set_gas_price(gas_price);
+    }
+
     /// Fetches the trusting period as a `Duration` from the chain config.
     /// If no trusting period exists in the config, the trusting period is calculated
     /// as two-thirds of the `unbonding_period`.
@@ -459,12 +578,16 @@ impl CosmosSdkChain {
     ) -> Result<Vec<IbcEventWithHeight>, Error> {
         crate::time!("send_messages_and_wait_commit");
 
-        let proto_msgs = tracked_msgs.msgs;
+        let (key_index, key_entry, proto_msgs) = self.assign_key(tracked_msgs.msgs).await?;
 
-        let key_entry = self.key()?;
+        self.update_dynamic_gas_price().await;
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_entry.account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            key_entry.account(),
+            self.key_pool.account(key_index),
+        )
+        .await?;
 
         if self.config.sequential_batch_tx {
             sequential_send_batched_messages_and_wait_commit(
@@ -506,14 +629,18 @@ impl CosmosSdkChain {
     ) -> Result<Vec<Response>, Error> {
         crate::time!("send_messages_and_wait_check_tx");
 
-        let proto_msgs = tracked_msgs.msgs;
+        let (key_index, key_entry, proto_msgs) = self.assign_key(tracked_msgs.msgs).await?;
 
-        let key_entry = self.key()?;
+        self.update_dynamic_gas_price().await;
 
-        let account =
-            get_or_fetch_account(&self.grpc_addr, &key_entry.account, &mut self.account).await?;
+        let account = get_or_fetch_account(
+            &self.grpc_addr,
+            key_entry.account(),
+            self.key_pool.account(key_index),
+        )
+        .await?;
 
-        send_batched_messages_and_wait_check_tx(
+        let responses = send_batched_messages_and_wait_check_tx(
             &self.tx_config,
             self.config.max_msg_num,
             self.config.max_tx_size,
@@ -522,7 +649,17 @@ impl CosmosSdkChain {
             &self.config.memo_prefix,
             proto_msgs,
         )
-        .await
+        .await?;
+
+        self.key_pool.submitted(
+            key_index,
+            responses
+                .iter()
+                .filter(|response| response.code.is_ok())
+                .map(|response| response.hash),
+        );
+
+        Ok(responses)
     }
 
     fn query_packet_from_block(
@@ -541,7 +678,11 @@ impl CosmosSdkChain {
             tendermint::block::Height::try_from(block_height.revision_height()).unwrap();
 
         let response = self
-            .block_on(self.rpc_client.block_results(tm_height))
+            .block_on(block_results(
+                &self.rpc_client,
+                self.tx_config.attribute_encoding,
+                tm_height,
+            ))
             .map_err(|e| Error::rpc(self.config.rpc_addr.clone(), e))?;
 
         let response_height = ICSHeight::new(self.id().version(), u64::from(response.height))
@@ -549,8 +690,7 @@ impl CosmosSdkChain {
 
         begin_block_events.append(
             &mut response
-                .begin_block_events
-                .unwrap_or_default()
+                .begin_block_events()
                 .into_iter()
                 .filter_map(|ev| filter_matching_event(ev, request, seqs))
                 .map(|ev| IbcEventWithHeight::new(ev, response_height))
@@ -559,8 +699,7 @@ impl CosmosSdkChain {
 
         end_block_events.append(
             &mut response
-                .end_block_events
-                .unwrap_or_default()
+                .end_block_events()
                 .into_iter()
                 .filter_map(|ev| filter_matching_event(ev, request, seqs))
                 .map(|ev| IbcEventWithHeight::new(ev, response_height))
@@ -626,16 +765,29 @@ impl ChainEndpoint for CosmosSdkChain {
         let rpc_client = HttpClient::new(config.rpc_addr.clone())
             .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
 
-        let light_client = rt.block_on(init_light_client(&rpc_client, &config))?;
+        let node_info = rt
+            .block_on(rpc_client.status())
+            .map(|status| status.node_info)
+            .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
+
+        let light_client = TmLightClient::from_config(&config, node_info.id, rt.clone())?;
 
         // Initialize key store and load key
-        let keybase = KeyRing::new(config.key_store_type, &config.account_prefix, &config.id)
-            .map_err(Error::key_base)?;
+        let keybase = KeyRing::from_chain_config(&config).map_err(Error::key_base)?;
 
         let grpc_addr = Uri::from_str(&config.grpc_addr.to_string())
             .map_err(|e| Error::invalid_uri(config.grpc_addr.to_string(), e))?;
 
-        let tx_config = TxConfig::try_from(&config)?;
+        // The encoding of the event attributes changed with CometBFT 0.37
+        let attribute_encoding =
+            AttributeEncoding::from_node_version(&node_info.version.to_string());
+
+        let tx_config = TxConfig {
+            attribute_encoding,
+            ..TxConfig::try_from(&config)?
+        };
+
+        let key_pool = KeyPool::new(&config);
 
         // Retrieve the version specification of this chain
 
@@ -646,8 +798,9 @@ impl ChainEndpoint for CosmosSdkChain {
             light_client,
             rt,
             keybase,
-            account: None,
+            key_pool,
             tx_config,
+            fee_market_channel: None,
         };
 
         Ok(chain)
@@ -656,21 +809,42 @@ impl ChainEndpoint for CosmosSdkChain {
     fn init_event_monitor(
         &self,
         rt: Arc<TokioRuntime>,
+        state_dir: Option<&std::path::Path>,
     ) -> Result<(EventReceiver, TxMonitorCmd), Error> {
         crate::time!("init_event_monitor");
 
-        let (mut event_monitor, event_receiver, monitor_tx) = EventMonitor::new(
-            self.config.id.clone(),
-            self.config.websocket_addr.clone(),
-            rt,
-        )
-        .map_err(Error::event_monitor)?;
+        match self.config.event_source {
+            EventSourceMode::Push => {
+                let (mut event_monitor, event_receiver, monitor_tx) = EventMonitor::new(
+                    self.config.id.clone(),
+                    self.config.websocket_addr.clone(),
+                    self.tx_config.attribute_encoding,
+                    rt,
+                )
+                .map_err(Error::event_monitor)?;
 
-        event_monitor.subscribe().map_err(Error::event_monitor)?;
+                event_monitor.subscribe().map_err(Error::event_monitor)?;
 
-        thread::spawn(move || event_monitor.run());
+                thread::spawn(move || event_monitor.run());
 
-        Ok((event_receiver, monitor_tx))
+                Ok((event_receiver, monitor_tx))
+            }
+            EventSourceMode::Pull { interval } => {
+                let (event_monitor, event_receiver, monitor_tx) = PullEventMonitor::new(
+                    self.config.id.clone(),
+                    self.rpc_client.clone(),
+                    self.tx_config.attribute_encoding,
+                    interval,
+                    state_dir
+                        .map(|dir| dir.join(self.config.id.as_str()).join("pull_events.json")),
+                    rt,
+                );
+
+                thread::spawn(move || event_monitor.run());
+
+                Ok((event_receiver, monitor_tx))
+            }
+        }
     }
 
     fn shutdown(self) -> Result<(), Error> {
@@ -773,7 +947,7 @@ impl ChainEndpoint for CosmosSdkChain {
         // Get the key from key seed file
         let key_entry = self.key()?;
 
-        let signer = key_entry_to_signer(&key_entry, &self.config.account_prefix)?;
+        let signer = key_to_signer(&key_entry, &self.config.account_prefix)?;
 
         Ok(signer)
     }
@@ -793,10 +967,13 @@ impl ChainEndpoint for CosmosSdkChain {
         // Else retrieve the account from the configuration file.
         let account = match key_name {
             Some(key_name) => {
-                let key = self.keybase().get_key(key_name).map_err(Error::key_base)?;
-                key.account
+                let key = self
+                    .keybase()
+                    .get_key_signer(key_name)
+                    .map_err(Error::key_base)?;
+                key.account().to_string()
             }
-            _ => self.key()?.account,
+            _ => self.key()?.account().to_string(),
         };
 
         let denom = denom.unwrap_or(&self.config.gas_price.denom);
@@ -810,10 +987,13 @@ impl ChainEndpoint for CosmosSdkChain {
         // Else retrieve the account from the configuration file.
         let account = match key_name {
             Some(key_name) => {
-                let key = self.keybase().get_key(key_name).map_err(Error::key_base)?;
-                key.account
+                let key = self
+                    .keybase()
+                    .get_key_signer(key_name)
+                    .map_err(Error::key_base)?;
+                key.account().to_string()
             }
-            _ => self.key()?.account,
+            _ => self.key()?.account().to_string(),
         };
 
         let balance = self.block_on(query_all_balances(&self.grpc_addr, &account))?;
@@ -827,6 +1007,21 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(denom_trace)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<String, Error> {
+        crate::time!("query_interchain_account");
+        crate::telemetry!(query, self.id(), "query_interchain_account");
+
+        self.block_on(query_interchain_account(
+            &self.grpc_addr,
+            &owner,
+            &connection_id,
+        ))
+    }
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error> {
         crate::time!("query_commitment_prefix");
         crate::telemetry!(query, self.id(), "query_commitment_prefix");
@@ -915,6 +1110,13 @@ impl ChainEndpoint for CosmosSdkChain {
             })
             .collect();
 
+        // The localhost client tracks this very chain, whose identifier it does not store
+        for client in clients.iter_mut() {
+            if let AnyClientState::Localhost(lh_state) = &mut client.client_state {
+                lh_state.chain_id = self.id().clone();
+            }
+        }
+
         // Sort by client identifier counter
         clients.sort_by_cached_key(|c| client_id_suffix(&c.client_id).unwrap_or(0));
 
@@ -934,7 +1136,12 @@ impl ChainEndpoint for CosmosSdkChain {
             request.height,
             matches!(include_proof, IncludeProof::Yes),
         )?;
-        let client_state = AnyClientState::decode_vec(&res.value).map_err(Error::decode)?;
+        let mut client_state = AnyClientState::decode_vec(&res.value).map_err(Error::decode)?;
+
+        // The localhost client tracks this very chain, whose identifier it does not store
+        if let AnyClientState::Localhost(lh_state) = &mut client_state {
+            lh_state.chain_id = self.id().clone();
+        }
 
         match include_proof {
             IncludeProof::Yes => {
@@ -1055,7 +1262,10 @@ impl ChainEndpoint for CosmosSdkChain {
 
         let consensus_state = AnyConsensusState::decode_vec(&res.value).map_err(Error::decode)?;
 
-        if !matches!(consensus_state, AnyConsensusState::Tendermint(_)) {
+        if !matches!(
+            consensus_state,
+            AnyConsensusState::Tendermint(_) | AnyConsensusState::Wasm(_)
+        ) {
             return Err(Error::consensus_state_type_mismatch(
                 ClientType::Tendermint,
                 consensus_state.client_type(),
@@ -1332,6 +1542,56 @@ impl ChainEndpoint for CosmosSdkChain {
         }
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        crate::time!("query_upgrade");
+        crate::telemetry!(query, self.id(), "query_upgrade");
+
+        let res = self.query(
+            ChannelUpgradePath::Upgrade(request.port_id, request.channel_id),
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        let upgrade = Upgrade::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((upgrade, Some(proof)))
+            }
+            IncludeProof::No => Ok((upgrade, None)),
+        }
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        crate::time!("query_upgrade_error");
+        crate::telemetry!(query, self.id(), "query_upgrade_error");
+
+        let res = self.query(
+            ChannelUpgradePath::UpgradeError(request.port_id, request.channel_id),
+            request.height,
+            matches!(include_proof, IncludeProof::Yes),
+        )?;
+
+        let error_receipt = ErrorReceipt::decode_vec(&res.value).map_err(Error::decode)?;
+
+        match include_proof {
+            IncludeProof::Yes => {
+                let proof = res.proof.ok_or_else(Error::empty_response_proof)?;
+                Ok((error_receipt, Some(proof)))
+            }
+            IncludeProof::No => Ok((error_receipt, None)),
+        }
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -1632,6 +1892,7 @@ impl ChainEndpoint for CosmosSdkChain {
             self.id(),
             &self.rpc_client,
             &self.config.rpc_addr,
+            self.tx_config.attribute_encoding,
             request,
         ))
     }
@@ -1660,6 +1921,7 @@ impl ChainEndpoint for CosmosSdkChain {
                 self.id(),
                 &self.rpc_client,
                 &self.config.rpc_addr,
+                self.tx_config.attribute_encoding,
                 &request,
             )),
             Qualified::SmallerEqual(_) => {
@@ -1667,6 +1929,7 @@ impl ChainEndpoint for CosmosSdkChain {
                     self.id(),
                     &self.rpc_client,
                     &self.config.rpc_addr,
+                    self.tx_config.attribute_encoding,
                     &request,
                 ))?;
 
@@ -1734,6 +1997,18 @@ impl ChainEndpoint for CosmosSdkChain {
         Ok(response.block.header.into())
     }
 
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        crate::time!("query_incentivized_packets");
+        crate::telemetry!(query, self.id(), "query_incentivized_packets");
+
+        let packets = self.block_on(query_incentivized_packets(&self.grpc_addr, request))?;
+
+        Ok(packets)
+    }
+
     fn build_client_state(
         &self,
         height: ICSHeight,
@@ -1798,19 +2073,29 @@ impl ChainEndpoint for CosmosSdkChain {
         port_id: &PortId,
         counterparty_payee: &Signer,
     ) -> Result<(), Error> {
-        let address = self.get_signer()?;
-        let key_entry = self.key()?;
+        // Packets may be relayed with any key of the pool,
+        // so the payee is registered for the account of each of them
+        for index in 0..self.key_pool.key_count() {
+            let key_entry = self
+                .keybase()
+                .get_key_signer(self.key_pool.key_name(index))
+                .map_err(Error::key_base)?;
 
-        self.rt.block_on(maybe_register_counterparty_payee(
-            &self.tx_config,
-            &key_entry,
-            &mut self.account,
-            &self.config.memo_prefix,
-            channel_id,
-            port_id,
-            &address,
-            counterparty_payee,
-        ))
+            let address = key_to_signer(&key_entry, &self.config.account_prefix)?;
+
+            self.rt.block_on(maybe_register_counterparty_payee(
+                &self.tx_config,
+                &key_entry,
+                self.key_pool.account(index),
+                &self.config.memo_prefix,
+                channel_id,
+                port_id,
+                &address,
+                counterparty_payee,
+            ))?;
+        }
+
+        Ok(())
     }
 
     fn cross_chain_query(
@@ -1846,27 +2131,6 @@ fn sort_events_by_sequence(events: &mut [IbcEventWithHeight]) {
     });
 }
 
-/// Initialize the light client for the given chain using the given HTTP client
-/// to fetch the node identifier to be used as peer id in the light client.
-async fn init_light_client(
-    rpc_client: &HttpClient,
-    config: &ChainConfig,
-) -> Result<TmLightClient, Error> {
-    use tendermint_light_client_verifier::types::PeerId;
-
-    crate::time!("init_light_client");
-
-    let peer_id: PeerId = rpc_client
-        .status()
-        .await
-        .map(|s| s.node_info.id)
-        .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
-
-    let light_client = TmLightClient::from_config(config, peer_id)?;
-
-    Ok(light_client)
-}
-
 /// Returns the suffix counter for a CosmosSDK client id.
 /// Returns `None` if the client identifier is malformed
 /// and the suffix could not be parsed.
@@ -18,7 +18,7 @@ use crate::chain::cosmos::wait::wait_for_block_commits;
 use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::KeyEntry;
+use crate::keyring::KeySigner;
 
 /**
    Broadcast messages as multiple batched transactions to the chain all at once,
@@ -31,7 +31,7 @@ pub async fn send_batched_messages_and_wait_commit(
     config: &TxConfig,
     max_msg_num: MaxMsgNum,
     max_tx_size: MaxTxSize,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -55,6 +55,7 @@ pub async fn send_batched_messages_and_wait_commit(
         &config.chain_id,
         &config.rpc_client,
         &config.rpc_address,
+        config.attribute_encoding,
         &config.rpc_timeout,
         &mut tx_sync_results,
     )
@@ -77,7 +78,7 @@ pub async fn sequential_send_batched_messages_and_wait_commit(
     config: &TxConfig,
     max_msg_num: MaxMsgNum,
     max_tx_size: MaxTxSize,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -109,7 +110,7 @@ pub async fn send_batched_messages_and_wait_check_tx(
     config: &TxConfig,
     max_msg_num: MaxMsgNum,
     max_tx_size: MaxTxSize,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -145,7 +146,7 @@ async fn send_messages_as_batches(
     config: &TxConfig,
     max_msg_num: MaxMsgNum,
     max_tx_size: MaxTxSize,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -194,7 +195,7 @@ async fn sequential_send_messages_as_batches(
     config: &TxConfig,
     max_msg_num: MaxMsgNum,
     max_tx_size: MaxTxSize,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -239,6 +240,7 @@ async fn sequential_send_messages_as_batches(
             &config.chain_id,
             &config.rpc_client,
             &config.rpc_address,
+            config.attribute_encoding,
             &config.rpc_timeout,
             &mut tx_sync_results,
         )
@@ -281,7 +283,7 @@ fn batch_messages(
     config: &TxConfig,
     max_msg_num: MaxMsgNum,
     max_tx_size: MaxTxSize,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: Vec<Any>,
@@ -357,14 +359,15 @@ mod tests {
     use crate::chain::cosmos::types::config::TxConfig;
     use crate::config;
     use crate::config::types::{MaxMsgNum, MaxTxSize, Memo};
-    use crate::keyring::{self, KeyEntry, KeyRing};
+    use crate::keyring::{self, KeyRing, KeySigner};
+    use futures::executor::block_on;
     use ibc_proto::google::protobuf::Any;
     use ibc_relayer_types::core::ics24_host::identifier::ChainId;
     use std::fs;
 
     const COSMOS_HD_PATH: &str = "m/44'/118'/0'/0/0";
 
-    fn test_fixture() -> (TxConfig, KeyEntry, Account) {
+    fn test_fixture() -> (TxConfig, KeySigner, Account) {
         let path = concat!(
             env!("CARGO_MANIFEST_DIR"),
             "/tests/config/fixtures/relayer_conf_example.toml"
@@ -392,7 +395,7 @@ mod tests {
             sequence: AccountSequence::new(0),
         };
 
-        (tx_config, key_entry, account)
+        (tx_config, KeySigner::Local(key_entry), account)
     }
 
     #[test]
@@ -413,14 +416,14 @@ mod tests {
                 },
             );
             let expected_batch_len = messages.len() - 1;
-            let tx_bytes = sign_and_encode_tx(
+            let tx_bytes = block_on(sign_and_encode_tx(
                 &config,
                 &key_entry,
                 &account,
                 &memo,
                 &messages[..expected_batch_len],
                 &max_fee,
-            )
+            ))
             .unwrap();
             let max_tx_size = MaxTxSize::new(tx_bytes.len()).unwrap();
 
@@ -438,9 +441,15 @@ mod tests {
             assert_eq!(batches.len(), 2);
             assert_eq!(batches[0].len(), expected_batch_len);
 
-            let tx_bytes =
-                sign_and_encode_tx(&config, &key_entry, &account, &memo, &batches[0], &max_fee)
-                    .unwrap();
+            let tx_bytes = block_on(sign_and_encode_tx(
+                &config,
+                &key_entry,
+                &account,
+                &memo,
+                &batches[0],
+                &max_fee,
+            ))
+            .unwrap();
             assert_eq!(tx_bytes.len(), max_tx_size.to_usize());
 
             assert_eq!(batches[1].len(), 1);
@@ -475,9 +484,15 @@ mod tests {
         assert_eq!(batches[0].len(), 1);
 
         let max_fee = gas_amount_to_fee(&config.gas_config, config.gas_config.max_gas);
-        let tx_bytes =
-            sign_and_encode_tx(&config, &key_entry, &account, &memo, &batches[0], &max_fee)
-                .unwrap();
+        let tx_bytes = block_on(sign_and_encode_tx(
+            &config,
+            &key_entry,
+            &account,
+            &memo,
+            &batches[0],
+            &max_fee,
+        ))
+        .unwrap();
         assert_eq!(tx_bytes.len(), MAX_TX_SIZE);
 
         let res = batch_messages(
@@ -603,8 +618,10 @@ mod tests {
 
         for batch in batches {
             assert_eq!(batch.len(), 1);
-            let tx_bytes =
-                sign_and_encode_tx(&config, &key_entry, &account, &memo, &batch, &max_fee).unwrap();
+            let tx_bytes = block_on(sign_and_encode_tx(
+                &config, &key_entry, &account, &memo, &batch, &max_fee,
+            ))
+            .unwrap();
             assert_eq!(tx_bytes.len(), MAX_TX_SIZE);
         }
 
@@ -0,0 +1,270 @@
+//! Enforcement of the limits on the fees the relayer spends on a chain.
+//!
+//! The fees paid on a chain are only tracked in memory. They are shared by all
+//! the runtimes of the chain spawned by the process, so that respawning the
+//! runtime of a chain, eg. upon a configuration reload, does not reset its
+//! budget, but they are forgotten when the relayer restarts.
+
+use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use ibc_proto::cosmos::tx::v1beta1::Fee;
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use once_cell::sync::Lazy;
+use tracing::{error, info};
+
+use crate::config::FeeBudget;
+use crate::error::Error;
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The budget state of each chain, shared by all the trackers of that chain.
+static BUDGET_STATES: Lazy<Mutex<HashMap<ChainId, Arc<Mutex<BudgetState>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Default)]
+struct BudgetState {
+    /// The fees paid over the last day, oldest first
+    spent: VecDeque<(Instant, u64)>,
+    /// Whether the budget was exhausted the last time it was checked
+    exhausted: bool,
+}
+
+impl BudgetState {
+    fn prune(&mut self, now: Instant) {
+        while let Some((spent_at, _)) = self.spent.front() {
+            if now.duration_since(*spent_at) < DAY {
+                break;
+            }
+            self.spent.pop_front();
+        }
+    }
+
+    fn spent_since(&self, now: Instant, window: Duration) -> u64 {
+        self.spent
+            .iter()
+            .filter(|(spent_at, _)| now.duration_since(*spent_at) < window)
+            .fold(0, |total, (_, amount)| total.saturating_add(*amount))
+    }
+}
+
+/// A fee counted as spent by a [`FeeBudgetTracker`] before its transaction is submitted.
+#[derive(Debug)]
+#[must_use]
+pub struct FeeReservation {
+    spent_at: Instant,
+    amount: u64,
+}
+
+/// Tracks the fees paid by the relayer on a chain against the chain's [`FeeBudget`].
+///
+/// Only the fees paid in the denomination of the gas price count towards the budget.
+#[derive(Clone, Debug)]
+pub struct FeeBudgetTracker {
+    budget: FeeBudget,
+    denom: String,
+    state: Arc<Mutex<BudgetState>>,
+}
+
+impl FeeBudgetTracker {
+    pub fn new(budget: FeeBudget, denom: String) -> Self {
+        Self {
+            budget,
+            denom,
+            state: Arc::new(Mutex::new(BudgetState::default())),
+        }
+    }
+
+    /// A tracker sharing the fees paid on the given chain with
+    /// every other tracker of that chain created by this process.
+    pub fn for_chain(chain_id: &ChainId, budget: FeeBudget, denom: String) -> Self {
+        let state = BUDGET_STATES
+            .lock()
+            .unwrap()
+            .entry(chain_id.clone())
+            .or_default()
+            .clone();
+
+        Self {
+            budget,
+            denom,
+            state,
+        }
+    }
+
+    /// Check that a transaction with the given fee can be submitted without exceeding
+    /// the budget, and if so count the fee as spent.
+    ///
+    /// Both happen under the same lock, so that concurrent transactions cannot all
+    /// pass the check before any of their fees is recorded. The returned reservation
+    /// must be [released](Self::release) if the transaction ends up not paying the fee.
+    pub fn spend(&self, chain_id: &ChainId, fee: &Fee) -> Result<FeeReservation, Error> {
+        let now = Instant::now();
+
+        if self.budget.is_unlimited() {
+            return Ok(FeeReservation {
+                spent_at: now,
+                amount: 0,
+            });
+        }
+
+        let amount = self.fee_amount(fee);
+        let mut state = self.state.lock().unwrap();
+
+        match self.check_at(&mut state, amount, now) {
+            Ok(()) => {
+                if state.exhausted {
+                    state.exhausted = false;
+                    info!(chain = %chain_id, "fee budget is available again, resuming relaying");
+                }
+
+                state.spent.push_back((now, amount));
+
+                Ok(FeeReservation {
+                    spent_at: now,
+                    amount,
+                })
+            }
+            Err(reason) => {
+                if !state.exhausted {
+                    state.exhausted = true;
+                    error!(
+                        chain = %chain_id,
+                        %reason,
+                        "fee budget exhausted, relaying to this chain is paused until the budget frees up"
+                    );
+                }
+
+                Err(Error::fee_budget_exceeded(chain_id.clone(), reason))
+            }
+        }
+    }
+
+    /// Give back the fee counted by [`spend`](Self::spend) for a transaction
+    /// which was not accepted by the chain, and therefore paid no fee.
+    pub fn release(&self, reservation: FeeReservation) {
+        if self.budget.is_unlimited() {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let spent = (reservation.spent_at, reservation.amount);
+
+        if let Some(index) = state.spent.iter().position(|entry| *entry == spent) {
+            state.spent.remove(index);
+        }
+    }
+
+    fn check_at(&self, state: &mut BudgetState, amount: u64, now: Instant) -> Result<(), String> {
+        if let Some(max) = self.budget.max_fee_per_tx {
+            if amount > max {
+                return Err(format!(
+                    "fee of {}{} exceeds the maximum fee per transaction of {}{}",
+                    amount, self.denom, max, self.denom
+                ));
+            }
+        }
+
+        state.prune(now);
+
+        let windows = [
+            ("hour", HOUR, self.budget.max_fees_per_hour),
+            ("day", DAY, self.budget.max_fees_per_day),
+        ];
+
+        for (name, window, max) in windows {
+            if let Some(max) = max {
+                let spent = state.spent_since(now, window);
+
+                if spent.saturating_add(amount) > max {
+                    return Err(format!(
+                        "fee of {}{} on top of the {}{} paid over the last {} exceeds the maximum of {}{}",
+                        amount, self.denom, spent, self.denom, name, max, self.denom
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fee_amount(&self, fee: &Fee) -> u64 {
+        fee.amount
+            .iter()
+            .filter(|coin| coin.denom == self.denom)
+            .map(|coin| coin.amount.parse::<u64>().unwrap_or(u64::MAX))
+            .fold(0, u64::saturating_add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::cosmos::base::v1beta1::Coin;
+
+    fn fee(amount: u64) -> Fee {
+        Fee {
+            amount: vec![Coin {
+                denom: "stake".to_string(),
+                amount: amount.to_string(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn enforce_fee_budget() {
+        let chain_id = ChainId::from_string("ibc-0");
+
+        let tracker = FeeBudgetTracker::new(
+            FeeBudget {
+                max_fee_per_tx: Some(500),
+                max_fees_per_hour: Some(1000),
+                max_fees_per_day: None,
+            },
+            "stake".to_string(),
+        );
+
+        assert!(tracker.spend(&chain_id, &fee(600)).is_err());
+
+        let _ = tracker.spend(&chain_id, &fee(400)).unwrap();
+        let reservation = tracker.spend(&chain_id, &fee(400)).unwrap();
+
+        assert!(tracker.spend(&chain_id, &fee(400)).is_err());
+
+        // The fee of a transaction which was not submitted is given back
+        tracker.release(reservation);
+        let _ = tracker.spend(&chain_id, &fee(400)).unwrap();
+        let _ = tracker.spend(&chain_id, &fee(200)).unwrap();
+
+        // Fees spent more than an hour ago no longer count towards the hourly budget
+        let mut state = tracker.state.lock().unwrap();
+        let later = Instant::now() + HOUR;
+        assert!(tracker.check_at(&mut state, 500, later).is_ok());
+    }
+
+    #[test]
+    fn share_fee_budget_across_trackers() {
+        let chain_id = ChainId::from_string("ibc-budget");
+
+        let budget = FeeBudget {
+            max_fee_per_tx: None,
+            max_fees_per_hour: Some(1000),
+            max_fees_per_day: None,
+        };
+
+        let tracker = FeeBudgetTracker::for_chain(&chain_id, budget.clone(), "stake".to_string());
+        let _ = tracker.spend(&chain_id, &fee(800)).unwrap();
+
+        // A tracker created for a respawned runtime of the same chain sees the fees paid so far
+        let respawned = FeeBudgetTracker::for_chain(&chain_id, budget, "stake".to_string());
+        assert!(respawned.spend(&chain_id, &fee(400)).is_err());
+        let _ = respawned.spend(&chain_id, &fee(200)).unwrap();
+    }
+}
@@ -16,17 +16,20 @@ use crate::chain::cosmos::types::tx::SignedTx;
 use crate::config::types::Memo;
 use crate::config::AddressType;
 use crate::error::Error;
-use crate::keyring::{sign_message, KeyEntry};
+use crate::keyring::KeySigner;
 
-pub fn sign_and_encode_tx(
+/// The length of the compact ECDSA signatures of transactions, for all address types
+const SIGNATURE_LEN: usize = 64;
+
+pub async fn sign_and_encode_tx(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<Vec<u8>, Error> {
-    let signed_tx = sign_tx(config, key_entry, account, tx_memo, messages, fee)?;
+    let signed_tx = sign_tx(config, key, account, tx_memo, messages, fee).await?;
 
     let tx_raw = TxRaw {
         body_bytes: signed_tx.body_bytes,
@@ -45,15 +48,26 @@ pub struct EncodedTxMetrics {
     pub body_bytes_len: usize,
 }
 
+/// The signature does not depend on the content of the transaction, hence the
+/// metrics are computed with a placeholder signature of the same length, rather
+/// than with an actual signature which may have to be requested from a remote signer.
 pub fn encoded_tx_metrics(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<EncodedTxMetrics, Error> {
-    let signed_tx = sign_tx(config, key_entry, account, tx_memo, messages, fee)?;
+    let unsigned_tx = unsigned_tx(config, key, account, tx_memo, messages, fee)?;
+
+    let signed_tx = SignedTx {
+        body: unsigned_tx.body,
+        body_bytes: unsigned_tx.body_bytes,
+        auth_info: unsigned_tx.auth_info,
+        auth_info_bytes: unsigned_tx.auth_info_bytes,
+        signatures: vec![vec![0; SIGNATURE_LEN]],
+    };
 
     let tx_raw = TxRaw {
         body_bytes: signed_tx.body_bytes,
@@ -75,15 +89,48 @@ pub fn encoded_tx_metrics(
     })
 }
 
-pub fn sign_tx(
+pub async fn sign_tx(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<SignedTx, Error> {
-    let key_bytes = encode_key_bytes(key_entry)?;
+    let unsigned_tx = unsigned_tx(config, key, account, tx_memo, messages, fee)?;
+
+    let signed_doc = key
+        .sign(unsigned_tx.sign_doc_bytes, &config.address_type)
+        .await
+        .map_err(Error::key_base)?;
+
+    Ok(SignedTx {
+        body: unsigned_tx.body,
+        body_bytes: unsigned_tx.body_bytes,
+        auth_info: unsigned_tx.auth_info,
+        auth_info_bytes: unsigned_tx.auth_info_bytes,
+        signatures: vec![signed_doc],
+    })
+}
+
+/// A transaction along with the encoded `SignDoc` to sign.
+struct UnsignedTx {
+    body: TxBody,
+    body_bytes: Vec<u8>,
+    auth_info: AuthInfo,
+    auth_info_bytes: Vec<u8>,
+    sign_doc_bytes: Vec<u8>,
+}
+
+fn unsigned_tx(
+    config: &TxConfig,
+    key: &KeySigner,
+    account: &Account,
+    tx_memo: &Memo,
+    messages: &[Any],
+    fee: &Fee,
+) -> Result<UnsignedTx, Error> {
+    let key_bytes = encode_key_bytes(key)?;
 
     let signer = encode_signer_info(&config.address_type, account.sequence, key_bytes)?;
 
@@ -92,28 +139,26 @@ pub fn sign_tx(
 
     let (auth_info, auth_info_bytes) = auth_info_and_bytes(signer, fee.clone())?;
 
-    let signed_doc = encode_sign_doc(
+    let sign_doc_bytes = encode_sign_doc(
         &config.chain_id,
-        key_entry,
-        &config.address_type,
         account.number,
         auth_info_bytes.clone(),
         body_bytes.clone(),
-    )?;
+    );
 
-    Ok(SignedTx {
+    Ok(UnsignedTx {
         body,
         body_bytes,
         auth_info,
         auth_info_bytes,
-        signatures: vec![signed_doc],
+        sign_doc_bytes,
     })
 }
 
-fn encode_key_bytes(key: &KeyEntry) -> Result<Vec<u8>, Error> {
+fn encode_key_bytes(key: &KeySigner) -> Result<Vec<u8>, Error> {
     let mut pk_buf = Vec::new();
 
-    prost::Message::encode(&key.public_key.to_pub().to_bytes(), &mut pk_buf)
+    prost::Message::encode(&key.public_key().to_pub().to_bytes(), &mut pk_buf)
         .map_err(|e| Error::protobuf_encode("PublicKey".into(), e))?;
 
     Ok(pk_buf)
@@ -121,12 +166,10 @@ fn encode_key_bytes(key: &KeyEntry) -> Result<Vec<u8>, Error> {
 
 fn encode_sign_doc(
     chain_id: &ChainId,
-    key: &KeyEntry,
-    address_type: &AddressType,
     account_number: AccountNumber,
     auth_info_bytes: Vec<u8>,
     body_bytes: Vec<u8>,
-) -> Result<Vec<u8>, Error> {
+) -> Vec<u8> {
     let sign_doc = SignDoc {
         body_bytes,
         auth_info_bytes,
@@ -138,9 +181,7 @@ fn encode_sign_doc(
     let mut signdoc_buf = Vec::new();
     prost::Message::encode(&sign_doc, &mut signdoc_buf).unwrap();
 
-    let signed = sign_message(key, signdoc_buf, address_type).map_err(Error::key_base)?;
-
-    Ok(signed)
+    signdoc_buf
 }
 
 fn encode_signer_info(
@@ -228,8 +269,8 @@ fn tx_body_and_bytes(
     Ok((body, body_buf))
 }
 
-pub fn key_entry_to_signer(key_entry: &KeyEntry, account_prefix: &str) -> Result<Signer, Error> {
-    let bech32 = encode_to_bech32(&key_entry.address.to_hex(), account_prefix)?;
+pub fn key_to_signer(key: &KeySigner, account_prefix: &str) -> Result<Signer, Error> {
+    let bech32 = encode_to_bech32(&key.address().to_hex(), account_prefix)?;
     let signer = bech32
         .parse()
         .map_err(|e| Error::ics02(ClientError::signer(e)))?;
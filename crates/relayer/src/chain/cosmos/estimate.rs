@@ -12,12 +12,12 @@ use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::types::Memo;
 use crate::error::Error;
-use crate::keyring::KeyEntry;
+use crate::keyring::KeySigner;
 use crate::util::pretty::PrettyFee;
 
 pub async fn estimate_tx_fees(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -36,7 +36,8 @@ pub async fn estimate_tx_fees(
         tx_memo,
         messages,
         &gas_config.max_fee,
-    )?;
+    )
+    .await?;
 
     let tx = Tx {
         body: Some(signed_tx.body),
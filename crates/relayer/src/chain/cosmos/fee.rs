@@ -10,11 +10,11 @@ use crate::chain::cosmos::types::config::TxConfig;
 use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
-use crate::keyring::KeyEntry;
+use crate::keyring::KeySigner;
 
 pub async fn maybe_register_counterparty_payee(
     tx_config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     m_account: &mut Option<Account>,
     tx_memo: &Memo,
     channel_id: &ChannelId,
@@ -23,7 +23,7 @@ pub async fn maybe_register_counterparty_payee(
     counterparty_payee: &Signer,
 ) -> Result<(), Error> {
     let account =
-        get_or_fetch_account(&tx_config.grpc_address, &key_entry.account, m_account).await?;
+        get_or_fetch_account(&tx_config.grpc_address, key_entry.account(), m_account).await?;
 
     let current_counterparty_payee =
         query_counterparty_payee(&tx_config.grpc_address, channel_id, address).await?;
@@ -55,6 +55,7 @@ pub async fn maybe_register_counterparty_payee(
             wait_tx_succeed(
                 &tx_config.rpc_client,
                 &tx_config.rpc_address,
+                tx_config.attribute_encoding,
                 &tx_config.rpc_timeout,
                 &response.hash,
             )
@@ -0,0 +1,345 @@
+//! A pool of the keys used to submit transactions to a chain.
+//!
+//! Each key has its own account, and thus its own account sequence, so that
+//! batches of messages submitted with different keys neither wait on each other
+//! nor invalidate each other's sequence numbers.
+//!
+//! IBC messages embed the address of their signer, which must be the account
+//! signing the transaction. Batches are therefore only spread across the keys
+//! when all their messages are of a type whose signer can be rewritten; any
+//! other batch is submitted with the primary key, i.e. the chain's `key_name`.
+//!
+//! The pool keeps track of the transactions of each key which are in flight,
+//! i.e. which have been submitted but not committed yet, so that batches are
+//! submitted with an idle key, whose account sequence cannot be invalidated
+//! by the failure of an earlier transaction.
+
+use core::time::Duration;
+use std::time::Instant;
+
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::channel::v1::{
+    MsgAcknowledgement, MsgRecvPacket, MsgTimeout, MsgTimeoutOnClose,
+};
+use ibc_proto::ibc::core::client::v1::MsgUpdateClient;
+use prost::Message;
+use tendermint_rpc::abci::transaction::Hash as TxHash;
+use tracing::warn;
+
+use ibc_relayer_types::core::ics02_client::msgs::update_client;
+use ibc_relayer_types::core::ics04_channel::msgs::{
+    acknowledgement, recv_packet, timeout, timeout_on_close,
+};
+
+use crate::chain::cosmos::types::account::Account;
+use crate::config::ChainConfig;
+use crate::error::Error;
+
+/// The index of the primary key in the pool.
+pub const PRIMARY_KEY: usize = 0;
+
+#[derive(Debug)]
+struct PooledKey {
+    name: String,
+    /// A cached copy of the account information
+    account: Option<Account>,
+    /// The transactions submitted with this key which have not been committed yet
+    in_flight: Vec<(TxHash, Instant)>,
+}
+
+impl PooledKey {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            account: None,
+            in_flight: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct KeyPool {
+    keys: Vec<PooledKey>,
+    next: usize,
+}
+
+impl KeyPool {
+    pub fn new(config: &ChainConfig) -> Self {
+        let keys = config
+            .all_key_names()
+            .into_iter()
+            .map(PooledKey::new)
+            .collect();
+
+        Self { keys, next: 0 }
+    }
+
+    /// Select the key with which to submit the given batch of messages.
+    ///
+    /// Batches which can be signed by any key are assigned to an idle key, trying
+    /// the keys in turn from the one following the last selected key. When no key
+    /// is idle, the key with the fewest transactions in flight is selected.
+    pub fn select(&mut self, msgs: &[Any]) -> usize {
+        if self.keys.len() == 1 || !msgs.iter().all(|msg| can_set_signer(&msg.type_url)) {
+            return PRIMARY_KEY;
+        }
+
+        let count = self.keys.len();
+
+        let index = (0..count)
+            .map(|offset| (self.next + offset) % count)
+            .min_by_key(|index| self.keys[*index].in_flight.len())
+            .unwrap_or(PRIMARY_KEY);
+
+        self.next = (index + 1) % count;
+        index
+    }
+
+    /// Whether the transactions in flight must be checked before selecting a key for
+    /// the given batch of messages, i.e. when the batch can be signed by any key but
+    /// no key is idle, or when some transactions have been in flight for longer than
+    /// `timeout` and are about to be expired.
+    pub fn needs_refresh(&self, msgs: &[Any], timeout: Duration) -> bool {
+        let any_expired = self.keys.iter().any(|key| {
+            key.in_flight
+                .iter()
+                .any(|(_, submitted_at)| submitted_at.elapsed() > timeout)
+        });
+
+        let all_busy = self.keys.len() > 1
+            && msgs.iter().all(|msg| can_set_signer(&msg.type_url))
+            && self.keys.iter().all(|key| !key.in_flight.is_empty());
+
+        any_expired || all_busy
+    }
+
+    /// Record the transactions which have been submitted with the given key.
+    ///
+    /// Nothing is recorded when there is a single key, since there is no other key to pick.
+    pub fn submitted(&mut self, index: usize, tx_hashes: impl IntoIterator<Item = TxHash>) {
+        if self.keys.len() == 1 {
+            return;
+        }
+
+        let now = Instant::now();
+        let key = &mut self.keys[index];
+        key.in_flight
+            .extend(tx_hashes.into_iter().map(|hash| (hash, now)));
+    }
+
+    /// The transactions in flight, along with the index of the key they were submitted with.
+    pub fn in_flight(&self) -> Vec<(usize, TxHash)> {
+        self.keys
+            .iter()
+            .enumerate()
+            .flat_map(|(index, key)| key.in_flight.iter().map(move |(hash, _)| (index, *hash)))
+            .collect()
+    }
+
+    pub fn committed(&mut self, index: usize, tx_hash: &TxHash) {
+        self.keys[index]
+            .in_flight
+            .retain(|(hash, _)| hash != tx_hash);
+    }
+
+    /// Forget about the transactions which have been in flight for longer than `timeout`.
+    ///
+    /// Such transactions have likely been dropped from the mempool, in which case
+    /// the cached account sequence of their key is ahead of the one on chain,
+    /// hence the account is fetched again before the key is used next.
+    pub fn expire(&mut self, timeout: Duration) {
+        for key in self.keys.iter_mut() {
+            let in_flight = key.in_flight.len();
+
+            key.in_flight
+                .retain(|(_, submitted_at)| submitted_at.elapsed() <= timeout);
+
+            if key.in_flight.len() < in_flight {
+                warn!(
+                    key = %key.name,
+                    "transactions were not committed within {:?}, refreshing the account",
+                    timeout
+                );
+
+                key.account = None;
+            }
+        }
+    }
+
+    /// The number of keys in the pool, the primary key included.
+    pub fn key_count(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn key_name(&self, index: usize) -> &str {
+        &self.keys[index].name
+    }
+
+    pub fn account(&mut self, index: usize) -> &mut Option<Account> {
+        &mut self.keys[index].account
+    }
+}
+
+fn can_set_signer(type_url: &str) -> bool {
+    [
+        update_client::TYPE_URL,
+        recv_packet::TYPE_URL,
+        acknowledgement::TYPE_URL,
+        timeout::TYPE_URL,
+        timeout_on_close::TYPE_URL,
+    ]
+    .contains(&type_url)
+}
+
+/// Replace the signer of the given messages, which must all be of a type
+/// accepted by [`KeyPool::select`] for a key other than the primary one.
+pub fn set_signer(msgs: Vec<Any>, signer: &str) -> Result<Vec<Any>, Error> {
+    msgs.into_iter()
+        .map(|msg| set_msg_signer(msg, signer))
+        .collect()
+}
+
+fn set_msg_signer(msg: Any, signer: &str) -> Result<Any, Error> {
+    fn resign<M: Message + Default>(
+        msg: Any,
+        signer: &str,
+        f: impl FnOnce(&mut M) -> &mut String,
+    ) -> Result<Any, Error> {
+        let mut raw = M::decode(msg.value.as_slice())
+            .map_err(|e| Error::protobuf_decode(msg.type_url.clone(), e))?;
+
+        *f(&mut raw) = signer.to_string();
+
+        Ok(Any {
+            type_url: msg.type_url,
+            value: raw.encode_to_vec(),
+        })
+    }
+
+    match msg.type_url.as_str() {
+        update_client::TYPE_URL => resign(msg, signer, |m: &mut MsgUpdateClient| &mut m.signer),
+        recv_packet::TYPE_URL => resign(msg, signer, |m: &mut MsgRecvPacket| &mut m.signer),
+        acknowledgement::TYPE_URL => {
+            resign(msg, signer, |m: &mut MsgAcknowledgement| &mut m.signer)
+        }
+        timeout::TYPE_URL => resign(msg, signer, |m: &mut MsgTimeout| &mut m.signer),
+        timeout_on_close::TYPE_URL => {
+            resign(msg, signer, |m: &mut MsgTimeoutOnClose| &mut m.signer)
+        }
+        _ => Ok(msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ibc_proto::ibc::core::channel::v1::MsgChannelOpenInit;
+
+    fn any<M: Message>(type_url: &str, msg: M) -> Any {
+        Any {
+            type_url: type_url.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    }
+
+    fn pool(names: &[&str]) -> KeyPool {
+        KeyPool {
+            keys: names.iter().map(|name| PooledKey::new(name)).collect(),
+            next: 0,
+        }
+    }
+
+    #[test]
+    fn select_keys_in_turn() {
+        let mut pool = pool(&["primary", "second", "third"]);
+
+        let packets = vec![any(
+            recv_packet::TYPE_URL,
+            MsgRecvPacket {
+                signer: "primary".to_string(),
+                ..Default::default()
+            },
+        )];
+
+        let selected: Vec<_> = (0..4)
+            .map(|_| {
+                let index = pool.select(&packets);
+                pool.key_name(index).to_string()
+            })
+            .collect();
+        assert_eq!(selected, ["primary", "second", "third", "primary"]);
+
+        // Keys with transactions in flight are skipped
+        let tx_hash = TxHash::new([1; 32]);
+        pool.submitted(1, [tx_hash]);
+        assert_eq!(pool.select(&packets), 2);
+        assert_eq!(pool.select(&packets), 0);
+        assert_eq!(pool.select(&packets), 2);
+
+        // Until their transactions are committed
+        pool.committed(1, &tx_hash);
+        assert_eq!(pool.select(&packets), 0);
+        assert_eq!(pool.select(&packets), 1);
+
+        let handshake = vec![any(
+            "/ibc.core.channel.v1.MsgChannelOpenInit",
+            MsgChannelOpenInit::default(),
+        )];
+        assert_eq!(pool.select(&handshake), PRIMARY_KEY);
+    }
+
+    #[test]
+    fn refresh_only_when_no_key_is_idle() {
+        let mut pool = pool(&["primary", "second"]);
+        let timeout = Duration::from_secs(60);
+
+        let packets = vec![any(recv_packet::TYPE_URL, MsgRecvPacket::default())];
+        let handshake = vec![any(
+            "/ibc.core.channel.v1.MsgChannelOpenInit",
+            MsgChannelOpenInit::default(),
+        )];
+
+        pool.submitted(0, [TxHash::new([1; 32])]);
+        assert!(!pool.needs_refresh(&packets, timeout));
+
+        pool.submitted(1, [TxHash::new([2; 32])]);
+        assert!(pool.needs_refresh(&packets, timeout));
+
+        // Batches which can only be signed by the primary key do not depend on the other keys
+        assert!(!pool.needs_refresh(&handshake, timeout));
+
+        // Unless some transactions are about to be expired
+        assert!(pool.needs_refresh(&handshake, Duration::ZERO));
+    }
+
+    #[test]
+    fn replace_signer() {
+        let msgs = vec![
+            any(
+                update_client::TYPE_URL,
+                MsgUpdateClient {
+                    client_id: "07-tendermint-0".to_string(),
+                    signer: "primary".to_string(),
+                    ..Default::default()
+                },
+            ),
+            any(
+                acknowledgement::TYPE_URL,
+                MsgAcknowledgement {
+                    signer: "primary".to_string(),
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        let msgs = set_signer(msgs, "second").unwrap();
+
+        let update = MsgUpdateClient::decode(msgs[0].value.as_slice()).unwrap();
+        assert_eq!(update.signer, "second");
+        assert_eq!(update.client_id, "07-tendermint-0");
+
+        let ack = MsgAcknowledgement::decode(msgs[1].value.as_slice()).unwrap();
+        assert_eq!(ack.signer, "second");
+    }
+}
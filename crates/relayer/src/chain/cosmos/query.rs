@@ -18,7 +18,10 @@ use crate::error::Error;
 pub mod account;
 pub mod balance;
 pub mod denom_trace;
+pub mod interchain_account;
 pub mod fee;
+pub mod fee_market;
+pub mod results;
 pub mod status;
 pub mod tx;
 pub mod custom;
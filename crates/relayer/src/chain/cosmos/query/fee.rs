@@ -1,13 +1,12 @@
 use http::uri::Uri;
 use ibc_proto::ibc::applications::fee::v1::query_client::QueryClient;
-use ibc_proto::ibc::applications::fee::v1::{
-    QueryCounterpartyPayeeRequest, QueryIncentivizedPacketsForChannelRequest,
-};
+use ibc_proto::ibc::applications::fee::v1::QueryCounterpartyPayeeRequest;
 use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
-use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
+use ibc_relayer_types::core::ics24_host::identifier::ChannelId;
 use ibc_relayer_types::signer::Signer;
 use tonic::Code;
 
+use crate::chain::requests::QueryIncentivizedPacketsRequest;
 use crate::error::Error;
 
 pub async fn query_counterparty_payee(
@@ -44,19 +43,13 @@ pub async fn query_counterparty_payee(
 
 pub async fn query_incentivized_packets(
     grpc_address: &Uri,
-    channel_id: &ChannelId,
-    port_id: &PortId,
+    request: QueryIncentivizedPacketsRequest,
 ) -> Result<Vec<IdentifiedPacketFees>, Error> {
     let mut client = QueryClient::connect(grpc_address.clone())
         .await
         .map_err(Error::grpc_transport)?;
 
-    let request = QueryIncentivizedPacketsForChannelRequest {
-        channel_id: channel_id.to_string(),
-        port_id: port_id.to_string(),
-        pagination: None,
-        query_height: 0,
-    };
+    let request = tonic::Request::new(request.into());
 
     let response = client
         .incentivized_packets_for_channel(request)
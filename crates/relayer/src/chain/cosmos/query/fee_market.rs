@@ -0,0 +1,131 @@
+//! Queries for the current base fee of chains with a fee market, whose
+//! gas price changes from block to block.
+//!
+//! Two fee markets are supported: the `feemarket` module, which reports a
+//! gas price per denomination, and the EIP-1559 fee market of Osmosis'
+//! `txfees` module, which reports the base fee of the chain's fee token.
+
+use http::uri::PathAndQuery;
+use ibc_proto::cosmos::base::v1beta1::DecCoin;
+use tonic::codec::ProstCodec;
+use tonic::transport::Channel;
+use tonic::Code;
+
+use crate::error::Error;
+
+const FEEMARKET_GAS_PRICE_PATH: &str = "/feemarket.feemarket.v1.Query/GasPrice";
+const OSMOSIS_EIP_BASE_FEE_PATH: &str = "/osmosis.txfees.v1beta1.Query/GetEipBaseFee";
+
+/// The number of decimal places of the Cosmos SDK `Dec` type, which
+/// is encoded as an integer string in protobuf messages.
+const DEC_PRECISION: i32 = 18;
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct GasPriceRequest {
+    #[prost(string, tag = "1")]
+    denom: String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct GasPriceResponse {
+    #[prost(message, optional, tag = "1")]
+    price: Option<DecCoin>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct QueryEipBaseFeeRequest {}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct QueryEipBaseFeeResponse {
+    #[prost(string, tag = "1")]
+    base_fee: String,
+}
+
+/// Uses the given gRPC channel to retrieve the current base fee of the chain,
+/// in the given denomination.
+///
+/// The `feemarket` module is queried first, falling back to Osmosis'
+/// EIP-1559 fee market if the chain does not have that module.
+pub async fn query_base_fee(channel: Channel, denom: &str) -> Result<f64, Error> {
+    let request = GasPriceRequest {
+        denom: denom.to_string(),
+    };
+
+    match unary::<_, GasPriceResponse>(channel.clone(), FEEMARKET_GAS_PRICE_PATH, request).await {
+        Ok(response) => {
+            let price = response
+                .price
+                .ok_or_else(|| Error::grpc_response_param("price".to_string()))?;
+
+            parse_dec(&price.amount)
+        }
+        Err(status) if status.code() == Code::Unimplemented => {
+            let response: QueryEipBaseFeeResponse = unary(
+                channel,
+                OSMOSIS_EIP_BASE_FEE_PATH,
+                QueryEipBaseFeeRequest {},
+            )
+            .await
+            .map_err(Error::grpc_status)?;
+
+            parse_dec(&response.base_fee)
+        }
+        Err(status) => Err(Error::grpc_status(status)),
+    }
+}
+
+async fn unary<Req, Res>(
+    channel: Channel,
+    path: &'static str,
+    request: Req,
+) -> Result<Res, tonic::Status>
+where
+    Req: prost::Message + 'static,
+    Res: prost::Message + Default + 'static,
+{
+    let mut client = tonic::client::Grpc::new(channel);
+
+    client
+        .ready()
+        .await
+        .map_err(|e| tonic::Status::unavailable(e.to_string()))?;
+
+    client
+        .unary(
+            tonic::Request::new(request),
+            PathAndQuery::from_static(path),
+            ProstCodec::default(),
+        )
+        .await
+        .map(|r| r.into_inner())
+}
+
+/// Parse a Cosmos SDK `Dec`, either in its protobuf encoding as an integer string
+/// scaled by 10^18, or as a decimal string.
+fn parse_dec(value: &str) -> Result<f64, Error> {
+    let parsed = if value.contains('.') {
+        value.parse::<f64>()
+    } else {
+        value
+            .parse::<f64>()
+            .map(|scaled| scaled / 10f64.powi(DEC_PRECISION))
+    };
+
+    match parsed {
+        Ok(fee) if fee.is_finite() && fee >= 0.0 => Ok(fee),
+        _ => Err(Error::invalid_base_fee(value.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_dec;
+
+    #[test]
+    fn parse_base_fee() {
+        assert_eq!(parse_dec("2500000000000000").unwrap(), 0.0025);
+        assert_eq!(parse_dec("0.0025").unwrap(), 0.0025);
+        assert!(parse_dec("").is_err());
+        assert!(parse_dec("-1.0").is_err());
+    }
+}
@@ -0,0 +1,361 @@
+//! Queries for the results of the execution of blocks and transactions,
+//! which accept the layout served by Tendermint 0.34 nodes as well as
+//! the one served by CometBFT 0.37 and 0.38 nodes.
+//!
+//! Since CometBFT 0.37, the keys and values of the event attributes are
+//! plain strings rather than base64-encoded strings, and since CometBFT 0.38,
+//! the events emitted outside of transactions are reported as
+//! `finalize_block_events` rather than as `begin_block_events` and
+//! `end_block_events`. The encoding of the attributes is selected from the
+//! version reported by the node, see [`AttributeEncoding`], while the events
+//! emitted outside of transactions are read from whichever field is present.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use subtle_encoding::base64;
+use tendermint::block::Height as TmHeight;
+use tendermint_rpc::abci::responses::DeliverTx;
+use tendermint_rpc::abci::Event;
+use tendermint_rpc::endpoint::{block_results, status, tx_search};
+use tendermint_rpc::query::Query;
+use tendermint_rpc::{Client, Error, HttpClient, Method, Order, Request, SimpleRequest};
+use tracing::warn;
+
+/// The value of the `mode` attribute which the Cosmos SDK adds to
+/// the events emitted by the end blocker within `finalize_block_events`.
+const END_BLOCK_MODE: &str = "EndBlock";
+
+/// How the keys and values of the event attributes are encoded by a node.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AttributeEncoding {
+    /// Base64-encoded strings, as served by Tendermint up to 0.34,
+    /// and as expected by the types of `tendermint-rpc`.
+    #[default]
+    Base64,
+
+    /// Plain strings, as served by CometBFT since 0.37.
+    Plain,
+}
+
+impl AttributeEncoding {
+    /// The encoding used by a node reporting the given Tendermint or CometBFT version,
+    /// eg. `0.34.24`, `v0.37.2` or `0.38.0-rc3`.
+    pub fn from_node_version(version: &str) -> Self {
+        let mut parts = version.trim_start_matches('v').split(['.', '-']);
+
+        let major = parts.next().and_then(|part| part.parse::<u64>().ok());
+        let minor = parts.next().and_then(|part| part.parse::<u64>().ok());
+
+        match (major, minor) {
+            (Some(0), Some(minor)) if minor < 37 => Self::Base64,
+            (Some(_), Some(_)) => Self::Plain,
+            _ => {
+                warn!(
+                    "could not parse the version `{}` reported by the node, \
+                    assuming that it encodes event attributes in base64",
+                    version
+                );
+
+                Self::Base64
+            }
+        }
+    }
+
+    /// Query the version of the node behind the given client,
+    /// and return the encoding it uses for the event attributes.
+    pub async fn query(rpc_client: &HttpClient) -> Result<Self, Error> {
+        let status = rpc_client.status().await?;
+
+        Ok(Self::from_node_version(
+            &status.node_info.version.to_string(),
+        ))
+    }
+
+    /// Encode the keys and values of the event attributes within
+    /// the given JSON value in base64, if they are plain strings.
+    pub fn normalize(self, value: &mut Value) {
+        if self == Self::Base64 {
+            return;
+        }
+
+        let mut attributes = vec![];
+        collect_attributes(value, &mut attributes);
+
+        for attribute in attributes {
+            let encoded = base64::encode(attribute.as_str().unwrap_or_default());
+            *attribute = Value::String(String::from_utf8(encoded).unwrap());
+        }
+    }
+}
+
+/// The results of the execution of a block, in either layout.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BlockResults {
+    pub height: TmHeight,
+
+    /// Results of the transactions in the block (might be explicit null)
+    pub txs_results: Option<Vec<DeliverTx>>,
+
+    /// Events emitted before the transactions, up to CometBFT 0.37 (might be explicit null)
+    pub begin_block_events: Option<Vec<Event>>,
+
+    /// Events emitted after the transactions, up to CometBFT 0.37 (might be explicit null)
+    pub end_block_events: Option<Vec<Event>>,
+
+    /// Events emitted outside of the transactions, since CometBFT 0.38 (might be explicit null)
+    pub finalize_block_events: Option<Vec<Event>>,
+}
+
+impl BlockResults {
+    /// The events emitted before the transactions of the block.
+    pub fn begin_block_events(&self) -> Vec<Event> {
+        match (&self.begin_block_events, &self.finalize_block_events) {
+            (Some(events), _) => events.clone(),
+            (None, Some(events)) => events
+                .iter()
+                .filter(|event| !is_end_block_event(event))
+                .cloned()
+                .collect(),
+            (None, None) => vec![],
+        }
+    }
+
+    /// The events emitted after the transactions of the block.
+    pub fn end_block_events(&self) -> Vec<Event> {
+        match (&self.end_block_events, &self.finalize_block_events) {
+            (Some(events), _) => events.clone(),
+            (None, Some(events)) => events
+                .iter()
+                .filter(|event| is_end_block_event(event))
+                .cloned()
+                .collect(),
+            (None, None) => vec![],
+        }
+    }
+}
+
+fn is_end_block_event(event: &Event) -> bool {
+    event
+        .attributes
+        .iter()
+        .any(|tag| tag.key.as_ref() == "mode" && tag.value.as_ref() == END_BLOCK_MODE)
+}
+
+/// Query the results of the execution of the block at the given height.
+pub async fn block_results(
+    rpc_client: &HttpClient,
+    encoding: AttributeEncoding,
+    height: TmHeight,
+) -> Result<BlockResults, Error> {
+    rpc_client
+        .perform(RawRequest(block_results::Request::new(height)))
+        .await?
+        .decode(encoding)
+}
+
+/// Search for the transactions matching the given query, along with their results.
+pub async fn tx_search(
+    rpc_client: &HttpClient,
+    encoding: AttributeEncoding,
+    query: Query,
+    prove: bool,
+    page: u32,
+    per_page: u8,
+    order: Order,
+) -> Result<tx_search::Response, Error> {
+    rpc_client
+        .perform(RawRequest(tx_search::Request::new(
+            query, prove, page, per_page, order,
+        )))
+        .await?
+        .decode(encoding)
+}
+
+/// The heights of the earliest and latest blocks available on a node.
+#[derive(Copy, Clone, Debug, Deserialize)]
+pub struct BlockHeights {
+    pub earliest_block_height: TmHeight,
+    pub latest_block_height: TmHeight,
+}
+
+/// Query the heights of the earliest and latest blocks available on the node.
+///
+/// The earliest height is read from the raw status of the node,
+/// since the status type of `tendermint-rpc` does not include it.
+pub async fn block_heights(rpc_client: &HttpClient) -> Result<BlockHeights, Error> {
+    #[derive(Deserialize)]
+    struct Status {
+        sync_info: BlockHeights,
+    }
+
+    let response = rpc_client.perform(RawRequest(status::Request)).await?;
+
+    serde_json::from_value::<Status>(response.0)
+        .map(|status| status.sync_info)
+        .map_err(Error::serde)
+}
+
+/// A request whose response is kept as raw JSON, to be decoded in either encoding.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct RawRequest<R>(R);
+
+impl<R: Request> Request for RawRequest<R> {
+    type Response = RawResponse;
+
+    fn method(&self) -> Method {
+        self.0.method()
+    }
+}
+
+impl<R: SimpleRequest> SimpleRequest for RawRequest<R> {}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+struct RawResponse(Value);
+
+impl tendermint_rpc::Response for RawResponse {}
+
+impl RawResponse {
+    /// Decode the response into the types of `tendermint-rpc`,
+    /// which expect the event attributes to be encoded in base64.
+    fn decode<T: serde::de::DeserializeOwned>(
+        self,
+        encoding: AttributeEncoding,
+    ) -> Result<T, Error> {
+        let mut value = self.0;
+        encoding.normalize(&mut value);
+
+        serde_json::from_value(value).map_err(Error::serde)
+    }
+}
+
+/// Collect the keys and values of the attributes of all the events within the given JSON value.
+fn collect_attributes<'a>(value: &'a mut Value, attributes: &mut Vec<&'a mut Value>) {
+    match value {
+        Value::Object(object) => {
+            let is_event = object.contains_key("type");
+
+            for (key, value) in object.iter_mut() {
+                if is_event && key == "attributes" {
+                    let tags = value.as_array_mut().into_iter().flatten();
+
+                    for tag in tags.filter_map(Value::as_object_mut) {
+                        for (key, value) in tag.iter_mut() {
+                            if (key == "key" || key == "value") && value.is_string() {
+                                attributes.push(value);
+                            }
+                        }
+                    }
+                } else {
+                    collect_attributes(value, attributes);
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| collect_attributes(value, attributes)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn decode_block_results() {
+        let legacy = json!({
+            "height": "10",
+            "txs_results": null,
+            "begin_block_events": [{
+                "type": "send_packet",
+                "attributes": [{ "key": "cGFja2V0X3NlcXVlbmNl", "value": "MQ==", "index": true }]
+            }],
+            "end_block_events": null,
+            "validator_updates": null,
+            "consensus_param_updates": null
+        });
+
+        let results: BlockResults = RawResponse(legacy)
+            .decode(AttributeEncoding::Base64)
+            .unwrap();
+        let events = results.begin_block_events();
+        assert_eq!(events[0].attributes[0].key.as_ref(), "packet_sequence");
+        assert_eq!(events[0].attributes[0].value.as_ref(), "1");
+        assert!(results.end_block_events().is_empty());
+
+        let finalize_block = json!({
+            "height": "10",
+            "txs_results": [],
+            "finalize_block_events": [
+                {
+                    "type": "send_packet",
+                    "attributes": [
+                        { "key": "packet_sequence", "value": "1", "index": true },
+                        { "key": "mode", "value": "BeginBlock", "index": true }
+                    ]
+                },
+                {
+                    "type": "write_acknowledgement",
+                    "attributes": [
+                        { "key": "packet_sequence", "value": "2", "index": true },
+                        { "key": "mode", "value": "EndBlock", "index": true }
+                    ]
+                }
+            ],
+            "validator_updates": [],
+            "consensus_param_updates": null,
+            "app_hash": "AA=="
+        });
+
+        let results: BlockResults = RawResponse(finalize_block)
+            .decode(AttributeEncoding::Plain)
+            .unwrap();
+        let (begin, end) = (results.begin_block_events(), results.end_block_events());
+        assert_eq!(begin.len(), 1);
+        assert_eq!(begin[0].type_str, "send_packet");
+        assert_eq!(begin[0].attributes[0].value.as_ref(), "1");
+        assert_eq!(end.len(), 1);
+        assert_eq!(end[0].type_str, "write_acknowledgement");
+        assert_eq!(end[0].attributes[0].value.as_ref(), "2");
+    }
+
+    #[test]
+    fn attribute_encoding_from_node_version() {
+        let cases = [
+            ("0.34.24", AttributeEncoding::Base64),
+            ("v0.34.27", AttributeEncoding::Base64),
+            ("0.37.2", AttributeEncoding::Plain),
+            ("v0.37.0-rc2", AttributeEncoding::Plain),
+            ("0.38.0-rc3", AttributeEncoding::Plain),
+            ("1.0.0", AttributeEncoding::Plain),
+            ("unknown", AttributeEncoding::Base64),
+        ];
+
+        for (version, encoding) in cases {
+            assert_eq!(
+                AttributeEncoding::from_node_version(version),
+                encoding,
+                "{version}"
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_plain_attributes() {
+        let mut value = json!({
+            "type": "send_packet",
+            "attributes": [{ "key": "packet_sequence", "value": "1", "index": true }]
+        });
+
+        AttributeEncoding::Base64.normalize(&mut value);
+        assert_eq!(value["attributes"][0]["key"], "packet_sequence");
+
+        AttributeEncoding::Plain.normalize(&mut value);
+        assert_eq!(value["attributes"][0]["key"], "cGFja2V0X3NlcXVlbmNl");
+        assert_eq!(value["attributes"][0]["value"], "MQ==");
+    }
+}
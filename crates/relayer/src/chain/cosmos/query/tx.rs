@@ -6,8 +6,9 @@ use ibc_relayer_types::Height as ICSHeight;
 use tendermint_rpc::abci::transaction::Hash as TxHash;
 use tendermint_rpc::abci::Event;
 use tendermint_rpc::endpoint::tx::Response as TxResponse;
-use tendermint_rpc::{Client, HttpClient, Order, Url};
+use tendermint_rpc::{HttpClient, Order, Url};
 
+use crate::chain::cosmos::query::results::{block_results, tx_search, AttributeEncoding};
 use crate::chain::cosmos::query::{header_query, packet_query, tx_hash_query};
 use crate::chain::cosmos::types::events;
 use crate::chain::requests::{
@@ -23,6 +24,7 @@ pub async fn query_txs(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     request: QueryTxRequest,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     crate::time!("query_txs");
@@ -38,16 +40,17 @@ pub async fn query_txs(
             // same header as the first one, otherwise a subsequent transaction would have
             // failed on chain. Therefore only one Tx is of interest and current API returns
             // the first one.
-            let mut response = rpc_client
-                .tx_search(
-                    header_query(&request),
-                    false,
-                    1,
-                    1, // get only the first Tx matching the query
-                    Order::Ascending,
-                )
-                .await
-                .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+            let mut response = tx_search(
+                rpc_client,
+                encoding,
+                header_query(&request),
+                false,
+                1,
+                1, // get only the first Tx matching the query
+                Order::Ascending,
+            )
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
 
             if response.txs.is_empty() {
                 return Ok(vec![]);
@@ -66,16 +69,17 @@ pub async fn query_txs(
         }
 
         QueryTxRequest::Transaction(tx) => {
-            let mut response = rpc_client
-                .tx_search(
-                    tx_hash_query(&tx),
-                    false,
-                    1,
-                    1, // get only the first Tx matching the query
-                    Order::Ascending,
-                )
-                .await
-                .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+            let mut response = tx_search(
+                rpc_client,
+                encoding,
+                tx_hash_query(&tx),
+                false,
+                1,
+                1, // get only the first Tx matching the query
+                Order::Ascending,
+            )
+            .await
+            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
 
             if response.txs.is_empty() {
                 Ok(vec![])
@@ -100,6 +104,7 @@ pub async fn query_packets_from_txs(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     request: &QueryPacketEventDataRequest,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     crate::time!("query_packets_from_txs");
@@ -109,16 +114,17 @@ pub async fn query_packets_from_txs(
 
     for seq in &request.sequences {
         // query first (and only) Tx that includes the event specified in the query request
-        let mut response = rpc_client
-            .tx_search(
-                packet_query(request, *seq),
-                false,
-                1,
-                1, // get only the first Tx matching the query
-                Order::Ascending,
-            )
-            .await
-            .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+        let mut response = tx_search(
+            rpc_client,
+            encoding,
+            packet_query(request, *seq),
+            false,
+            1,
+            1, // get only the first Tx matching the query
+            Order::Ascending,
+        )
+        .await
+        .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
 
         debug_assert!(
             response.txs.len() <= 1,
@@ -146,6 +152,7 @@ pub async fn query_packets_from_block(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     request: &QueryPacketEventDataRequest,
 ) -> Result<Vec<IbcEventWithHeight>, Error> {
     crate::time!("query_packets_from_block");
@@ -163,8 +170,7 @@ pub async fn query_packets_from_block(
     let height = Height::new(chain_id.version(), u64::from(tm_height))
         .map_err(|_| Error::invalid_height_no_source())?;
 
-    let exact_tx_block_results = rpc_client
-        .block_results(tm_height)
+    let exact_tx_block_results = block_results(rpc_client, encoding, tm_height)
         .await
         .map_err(|e| Error::rpc(rpc_address.clone(), e))?
         .txs_results;
@@ -298,18 +304,20 @@ pub fn filter_matching_event(
 pub async fn query_tx_response(
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     tx_hash: &TxHash,
 ) -> Result<Option<TxResponse>, Error> {
-    let response = rpc_client
-        .tx_search(
-            tx_hash_query(&QueryTxHash(*tx_hash)),
-            false,
-            1,
-            1, // get only the first Tx matching the query
-            Order::Ascending,
-        )
-        .await
-        .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
+    let response = tx_search(
+        rpc_client,
+        encoding,
+        tx_hash_query(&QueryTxHash(*tx_hash)),
+        false,
+        1,
+        1, // get only the first Tx matching the query
+        Order::Ascending,
+    )
+    .await
+    .map_err(|e| Error::rpc(rpc_address.clone(), e))?;
 
     Ok(response.txs.into_iter().next())
 }
@@ -13,7 +13,7 @@ use crate::chain::cosmos::types::account::Account;
 use crate::chain::cosmos::types::config::TxConfig;
 use crate::config::types::Memo;
 use crate::error::Error;
-use crate::keyring::KeyEntry;
+use crate::keyring::KeySigner;
 use crate::sdk_error::sdk_error_from_tx_sync_error_code;
 use crate::{telemetry, time};
 
@@ -46,7 +46,7 @@ const INCORRECT_ACCOUNT_SEQUENCE_ERR: u32 = 32;
 )]
 pub async fn send_tx_with_account_sequence_retry(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -67,7 +67,7 @@ pub async fn send_tx_with_account_sequence_retry(
 
 async fn do_send_tx_with_account_sequence_retry(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: &[Any],
@@ -153,13 +153,13 @@ async fn do_send_tx_with_account_sequence_retry(
 
 async fn refresh_account_and_retry_send_tx_with_account_sequence(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &mut Account,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
     // Re-fetch the account sequence number
-    refresh_account(&config.grpc_address, &key_entry.account, account).await?;
+    refresh_account(&config.grpc_address, key_entry.account(), account).await?;
 
     // Retry after delay
     thread::sleep(Duration::from_millis(ACCOUNT_SEQUENCE_RETRY_DELAY));
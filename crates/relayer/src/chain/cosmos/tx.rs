@@ -14,31 +14,57 @@ use crate::chain::cosmos::wait::wait_tx_succeed;
 use crate::config::types::Memo;
 use crate::error::Error;
 use crate::event::IbcEventWithHeight;
-use crate::keyring::KeyEntry;
+use crate::keyring::{KeyEntry, KeySigner};
+use crate::telemetry;
 
 use super::batch::send_batched_messages_and_wait_commit;
 
 pub async fn estimate_fee_and_send_tx(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
 ) -> Result<Response, Error> {
     let fee = estimate_tx_fees(config, key_entry, account, tx_memo, messages).await?;
 
-    send_tx_with_fee(config, key_entry, account, tx_memo, messages, &fee).await
+    let reservation = config.fee_budget.spend(&config.chain_id, &fee)?;
+
+    let result = send_tx_with_fee(config, key_entry, account, tx_memo, messages, &fee).await;
+
+    let response = match result {
+        Ok(response) => response,
+        Err(e) => {
+            config.fee_budget.release(reservation);
+            return Err(e);
+        }
+    };
+
+    // The fee is only deducted if the transaction passed `CheckTx`
+    if response.code.is_err() {
+        config.fee_budget.release(reservation);
+    } else {
+        telemetry!({
+            for coin in &fee.amount {
+                if let Ok(amount) = coin.amount.parse::<u64>() {
+                    ibc_telemetry::global().fees_paid(&config.chain_id, &coin.denom, amount);
+                }
+            }
+        });
+    }
+
+    Ok(response)
 }
 
 async fn send_tx_with_fee(
     config: &TxConfig,
-    key_entry: &KeyEntry,
+    key_entry: &KeySigner,
     account: &Account,
     tx_memo: &Memo,
     messages: &[Any],
     fee: &Fee,
 ) -> Result<Response, Error> {
-    let tx_bytes = sign_and_encode_tx(config, key_entry, account, tx_memo, messages, fee)?;
+    let tx_bytes = sign_and_encode_tx(config, key_entry, account, tx_memo, messages, fee).await?;
 
     let response = broadcast_tx_sync(&config.rpc_client, &config.rpc_address, tx_bytes).await?;
 
@@ -80,9 +106,10 @@ pub async fn simple_send_tx(
         .await?
         .into();
 
+    let key = KeySigner::Local(key_entry.clone());
+
     let response =
-        estimate_fee_and_send_tx(config, key_entry, &account, &Default::default(), &messages)
-            .await?;
+        estimate_fee_and_send_tx(config, &key, &account, &Default::default(), &messages).await?;
 
     if response.code.is_err() {
         return Err(Error::check_tx(response));
@@ -91,6 +118,7 @@ pub async fn simple_send_tx(
     let response = wait_tx_succeed(
         &config.rpc_client,
         &config.rpc_address,
+        config.attribute_encoding,
         &config.rpc_timeout,
         &response.hash,
     )
@@ -114,7 +142,7 @@ pub async fn batched_send_tx(
         config,
         config.max_msg_num,
         config.max_tx_size,
-        key_entry,
+        &KeySigner::Local(key_entry.clone()),
         &mut account,
         &Default::default(),
         messages,
@@ -5,6 +5,8 @@ use ibc_proto::google::protobuf::Any;
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use tendermint_rpc::{HttpClient, Url};
 
+use crate::chain::cosmos::budget::FeeBudgetTracker;
+use crate::chain::cosmos::query::results::AttributeEncoding;
 use crate::chain::cosmos::types::gas::GasConfig;
 use crate::config::types::{MaxMsgNum, MaxTxSize};
 use crate::config::{AddressType, ChainConfig};
@@ -16,12 +18,16 @@ pub struct TxConfig {
     pub gas_config: GasConfig,
     pub rpc_client: HttpClient,
     pub rpc_address: Url,
+    /// The encoding of the event attributes served by the node,
+    /// which defaults to base64 until it is set from the version of the node.
+    pub attribute_encoding: AttributeEncoding,
     pub grpc_address: Uri,
     pub rpc_timeout: Duration,
     pub address_type: AddressType,
     pub max_msg_num: MaxMsgNum,
     pub max_tx_size: MaxTxSize,
     pub extension_options: Vec<Any>,
+    pub fee_budget: FeeBudgetTracker,
 }
 
 impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
@@ -36,6 +42,12 @@ impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
 
         let gas_config = GasConfig::from(config);
 
+        let fee_budget = FeeBudgetTracker::for_chain(
+            &config.id,
+            config.fee_budget.clone(),
+            config.gas_price.denom.clone(),
+        );
+
         let extension_options = config
             .extension_options
             .iter()
@@ -47,12 +59,14 @@ impl<'a> TryFrom<&'a ChainConfig> for TxConfig {
             gas_config,
             rpc_client,
             rpc_address: config.rpc_addr.clone(),
+            attribute_encoding: AttributeEncoding::default(),
             grpc_address,
             rpc_timeout: config.rpc_timeout,
             address_type: config.address_type.clone(),
             max_msg_num: config.max_msg_num,
             max_tx_size: config.max_tx_size,
             extension_options,
+            fee_budget,
         })
     }
 }
@@ -4,10 +4,11 @@ use ibc_relayer_types::core::ics02_client::height::HeightErrorDetail;
 use ibc_relayer_types::core::ics04_channel::error::Error;
 use ibc_relayer_types::core::ics04_channel::events::{
     AcknowledgePacket, Attributes, CloseConfirm, CloseInit, EventType, OpenAck, OpenConfirm,
-    OpenInit, OpenTry, SendPacket, TimeoutPacket, WriteAcknowledgement, PKT_ACK_ATTRIBUTE_KEY,
-    PKT_DATA_ATTRIBUTE_KEY, PKT_DST_CHANNEL_ATTRIBUTE_KEY, PKT_DST_PORT_ATTRIBUTE_KEY,
-    PKT_SEQ_ATTRIBUTE_KEY, PKT_SRC_CHANNEL_ATTRIBUTE_KEY, PKT_SRC_PORT_ATTRIBUTE_KEY,
-    PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY, PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY,
+    OpenInit, OpenTry, SendPacket, TimeoutPacket, UpgradeAttributes, UpgradeInit,
+    WriteAcknowledgement, PKT_ACK_ATTRIBUTE_KEY, PKT_DATA_ATTRIBUTE_KEY,
+    PKT_DST_CHANNEL_ATTRIBUTE_KEY, PKT_DST_PORT_ATTRIBUTE_KEY, PKT_SEQ_ATTRIBUTE_KEY,
+    PKT_SRC_CHANNEL_ATTRIBUTE_KEY, PKT_SRC_PORT_ATTRIBUTE_KEY, PKT_TIMEOUT_HEIGHT_ATTRIBUTE_KEY,
+    PKT_TIMEOUT_TIMESTAMP_ATTRIBUTE_KEY, UPGRADE_SEQUENCE_ATTRIBUTE_KEY,
 };
 use ibc_relayer_types::core::ics04_channel::events::{ReceivePacket, TimeoutOnClosePacket};
 use ibc_relayer_types::core::ics04_channel::packet::Packet;
@@ -60,6 +61,46 @@ impl_try_from_raw_obj_for_event!(
     CloseConfirm
 );
 
+impl TryFrom<RawObject<'_>> for UpgradeInit {
+    type Error = EventError;
+
+    fn try_from(obj: RawObject<'_>) -> Result<Self, Self::Error> {
+        let namespace = Self::event_type().as_str();
+        let sequence = extract_attribute(
+            &obj,
+            &format!("{}.{}", namespace, UPGRADE_SEQUENCE_ATTRIBUTE_KEY),
+        )?;
+
+        let attributes = UpgradeAttributes {
+            port_id: extract_attribute(&obj, &format!("{}.port_id", namespace))?
+                .parse()
+                .map_err(EventError::parse)?,
+            channel_id: extract_attribute(&obj, &format!("{}.channel_id", namespace))?
+                .parse()
+                .map_err(EventError::parse)?,
+            counterparty_port_id: extract_attribute(
+                &obj,
+                &format!("{}.counterparty_port_id", namespace),
+            )?
+            .parse()
+            .map_err(EventError::parse)?,
+            counterparty_channel_id: maybe_extract_attribute(
+                &obj,
+                &format!("{}.counterparty_channel_id", namespace),
+            )
+            .and_then(|v| v.parse().ok()),
+            upgrade_sequence: sequence
+                .parse::<u64>()
+                .map_err(|e| {
+                    EventError::channel(Error::invalid_string_as_sequence(sequence.clone(), e))
+                })?
+                .into(),
+        };
+
+        Ok(attributes.into())
+    }
+}
+
 macro_rules! impl_try_from_raw_obj_for_packet {
     ($($packet:ty),+) => {
         $(impl TryFrom<RawObject<'_>> for $packet {
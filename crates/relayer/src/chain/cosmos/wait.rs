@@ -11,6 +11,7 @@ use tendermint_rpc::{HttpClient, Url};
 use tokio::time::sleep;
 use tracing::{debug, trace};
 
+use crate::chain::cosmos::query::results::AttributeEncoding;
 use crate::chain::cosmos::query::tx::query_tx_response;
 use crate::chain::cosmos::types::events::from_tx_response_event;
 use crate::chain::cosmos::types::tx::{TxStatus, TxSyncResult};
@@ -26,6 +27,7 @@ pub async fn wait_for_block_commits(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     rpc_timeout: &Duration,
     tx_sync_results: &mut [TxSyncResult],
 ) -> Result<(), Error> {
@@ -65,8 +67,14 @@ pub async fn wait_for_block_commits(
 
             for tx_sync_result in tx_sync_results.iter_mut() {
                 // ignore error
-                let _ =
-                    update_tx_sync_result(chain_id, rpc_client, rpc_address, tx_sync_result).await;
+                let _ = update_tx_sync_result(
+                    chain_id,
+                    rpc_client,
+                    rpc_address,
+                    encoding,
+                    tx_sync_result,
+                )
+                .await;
             }
         }
     }
@@ -76,11 +84,17 @@ async fn update_tx_sync_result(
     chain_id: &ChainId,
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     tx_sync_result: &mut TxSyncResult,
 ) -> Result<(), Error> {
     if let TxStatus::Pending { message_count } = tx_sync_result.status {
-        let response =
-            query_tx_response(rpc_client, rpc_address, &tx_sync_result.response.hash).await?;
+        let response = query_tx_response(
+            rpc_client,
+            rpc_address,
+            encoding,
+            &tx_sync_result.response.hash,
+        )
+        .await?;
 
         if let Some(response) = response {
             tx_sync_result.status = TxStatus::ReceivedResponse;
@@ -120,10 +134,11 @@ fn all_tx_results_found(tx_sync_results: &[TxSyncResult]) -> bool {
 pub async fn wait_tx_succeed(
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     timeout: &Duration,
     tx_hash: &TxHash,
 ) -> Result<TxResponse, Error> {
-    let response = wait_tx_hash(rpc_client, rpc_address, timeout, tx_hash).await?;
+    let response = wait_tx_hash(rpc_client, rpc_address, encoding, timeout, tx_hash).await?;
 
     let response_code = response.tx_result.code;
     if response_code.is_err() {
@@ -136,13 +151,14 @@ pub async fn wait_tx_succeed(
 pub async fn wait_tx_hash(
     rpc_client: &HttpClient,
     rpc_address: &Url,
+    encoding: AttributeEncoding,
     timeout: &Duration,
     tx_hash: &TxHash,
 ) -> Result<TxResponse, Error> {
     let start_time = Instant::now();
 
     loop {
-        let response = query_tx_response(rpc_client, rpc_address, tx_hash).await?;
+        let response = query_tx_response(rpc_client, rpc_address, encoding, tx_hash).await?;
 
         match response {
             None => {
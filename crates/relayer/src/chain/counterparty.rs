@@ -567,7 +567,7 @@ pub fn unreceived_acknowledgements(
 
 /// A structure to display pending packet commitment IDs
 /// at one end of a channel.
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct PendingPackets {
     /// Not yet received on the counterparty chain.
     pub unreceived_packets: Vec<Sequence>,
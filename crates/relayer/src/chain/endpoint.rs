@@ -1,5 +1,6 @@
 use alloc::sync::Arc;
 use core::convert::TryFrom;
+use std::path::Path;
 
 use tokio::runtime::Runtime as TokioRuntime;
 
@@ -13,6 +14,7 @@ use ibc_relayer_types::core::ics03_connection::connection::{
 use ibc_relayer_types::core::ics03_connection::version::{get_compatible_versions, Version};
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd};
 use ibc_relayer_types::core::ics04_channel::packet::{PacketMsgType, Sequence};
+use ibc_relayer_types::core::ics04_channel::upgrade::{ErrorReceipt, Upgrade};
 use ibc_relayer_types::core::ics23_commitment::commitment::{
     CommitmentPrefix, CommitmentProofBytes,
 };
@@ -24,6 +26,7 @@ use ibc_relayer_types::proofs::{ConsensusProof, Proofs};
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::Height as ICSHeight;
+use ibc_relayer_types::applications::ics29_fee::packet_fee::IdentifiedPacketFees;
 use ibc_relayer_types::applications::ics31_icq::response::CrossChainQueryResponse;
 
 use tendermint_rpc::endpoint::broadcast::tx_sync::Response as TxResponse;
@@ -40,7 +43,7 @@ use crate::denom::DenomTrace;
 use crate::error::{Error, QUERY_PROOF_EXPECT_MSG};
 use crate::event::monitor::{EventReceiver, TxMonitorCmd};
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{KeyEntry, KeyRing};
+use crate::keyring::{KeyEntry, KeyRing, KeySigner};
 use crate::light_client::AnyHeader;
 use crate::misbehaviour::MisbehaviourEvidence;
 
@@ -91,9 +94,13 @@ pub trait ChainEndpoint: Sized {
     fn bootstrap(config: ChainConfig, rt: Arc<TokioRuntime>) -> Result<Self, Error>;
 
     /// Initializes and returns the event monitor (if any) associated with this chain.
+    ///
+    /// The event monitor may persist its progress under `state_dir`, the directory in
+    /// which the relaying state is persisted, if enabled.
     fn init_event_monitor(
         &self,
         rt: Arc<TokioRuntime>,
+        state_dir: Option<&Path>,
     ) -> Result<(EventReceiver, TxMonitorCmd), Error>;
 
     /// Shutdown the chain runtime
@@ -112,14 +119,14 @@ pub trait ChainEndpoint: Sized {
 
     fn get_signer(&self) -> Result<Signer, Error>;
 
-    /// Get the signing key
-    fn get_key(&mut self) -> Result<KeyEntry, Error> {
+    /// Get the signing key, which may be held by a remote signer
+    fn get_key(&mut self) -> Result<KeySigner, Error> {
         crate::time!("get_key");
 
         // Get the key from key seed file
         let key = self
             .keybase()
-            .get_key(&self.config().key_name)
+            .get_key_signer(&self.config().key_name)
             .map_err(|e| Error::key_not_found(self.config().key_name.clone(), e))?;
 
         Ok(key)
@@ -184,6 +191,14 @@ pub trait ChainEndpoint: Sized {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the address of the interchain account registered by the given owner
+    /// over the given connection of this controller chain.
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<String, Error>;
+
     fn query_commitment_prefix(&self) -> Result<CommitmentPrefix, Error>;
 
     fn query_compatible_versions(&self) -> Result<Vec<Version>, Error> {
@@ -274,6 +289,22 @@ pub trait ChainEndpoint: Sized {
         include_proof: IncludeProof,
     ) -> Result<(ChannelEnd, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed for a channel end,
+    /// which is only stored while the upgrade is in progress.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
+    /// Performs a query to retrieve the receipt of the last upgrade of a
+    /// channel end which failed.
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve the client state for the channel associated
     /// with a given channel identifier.
     fn query_channel_client_state(
@@ -368,6 +399,13 @@ pub trait ChainEndpoint: Sized {
         request: QueryHostConsensusStateRequest,
     ) -> Result<Self::ConsensusState, Error>;
 
+    /// Query the ICS-29 fees escrowed for the packets sent over a channel
+    /// which have not been relayed yet.
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error>;
+
     fn build_client_state(
         &self,
         height: ICSHeight,
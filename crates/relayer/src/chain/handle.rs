@@ -14,10 +14,12 @@ use ibc_relayer_types::{
         ics04_channel::{
             channel::{ChannelEnd, IdentifiedChannelEnd},
             packet::{PacketMsgType, Sequence},
+            upgrade::{ErrorReceipt, Upgrade},
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
     },
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     proofs::Proofs,
     signer::Signer,
@@ -36,7 +38,7 @@ use crate::{
         monitor::{EventBatch, Result as MonitorResult},
         IbcEventWithHeight,
     },
-    keyring::KeyEntry,
+    keyring::{KeyEntry, KeySigner},
     light_client::AnyHeader,
     misbehaviour::MisbehaviourEvidence,
 };
@@ -129,7 +131,7 @@ pub enum ChainRequest {
     },
 
     GetKey {
-        reply_to: ReplyTo<KeyEntry>,
+        reply_to: ReplyTo<KeySigner>,
     },
 
     AddKey {
@@ -158,6 +160,12 @@ pub enum ChainRequest {
         reply_to: ReplyTo<DenomTrace>,
     },
 
+    QueryInterchainAccount {
+        owner: Signer,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<String>,
+    },
+
     QueryApplicationStatus {
         reply_to: ReplyTo<ChainStatus>,
     },
@@ -268,6 +276,18 @@ pub enum ChainRequest {
         reply_to: ReplyTo<(ChannelEnd, Option<MerkleProof>)>,
     },
 
+    QueryUpgrade {
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    },
+
+    QueryUpgradeError {
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
+    },
+
     QueryChannelClientState {
         request: QueryChannelClientStateRequest,
         reply_to: ReplyTo<Option<IdentifiedAnyClientState>>,
@@ -348,6 +368,11 @@ pub enum ChainRequest {
         reply_to: ReplyTo<AnyConsensusState>,
     },
 
+    QueryIncentivizedPackets {
+        request: QueryIncentivizedPacketsRequest,
+        reply_to: ReplyTo<Vec<IdentifiedPacketFees>>,
+    },
+
     MaybeRegisterCounterpartyPayee {
         channel_id: ChannelId,
         port_id: PortId,
@@ -396,7 +421,7 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
 
     fn config(&self) -> Result<ChainConfig, Error>;
 
-    fn get_key(&self) -> Result<KeyEntry, Error>;
+    fn get_key(&self) -> Result<KeySigner, Error>;
 
     fn add_key(&self, key_name: String, key: KeyEntry) -> Result<(), Error>;
 
@@ -419,6 +444,14 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
     /// Query the denomination trace given a trace hash.
     fn query_denom_trace(&self, hash: String) -> Result<DenomTrace, Error>;
 
+    /// Query the address of the interchain account registered by the given owner
+    /// over the given connection of this controller chain.
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<String, Error>;
+
     /// Query the latest height and timestamp the application is at
     fn query_application_status(&self) -> Result<ChainStatus, Error>;
 
@@ -519,6 +552,22 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         include_proof: IncludeProof,
     ) -> Result<(ChannelEnd, Option<MerkleProof>), Error>;
 
+    /// Performs a query to retrieve the upgrade proposed for a channel end,
+    /// which is only stored while the upgrade is in progress.
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error>;
+
+    /// Performs a query to retrieve the receipt of the last upgrade of a
+    /// channel end which failed.
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error>;
+
     /// Performs a query to retrieve the client state for the channel associated
     /// with a given channel identifier.
     fn query_channel_client_state(
@@ -656,6 +705,13 @@ pub trait ChainHandle: Clone + Display + Send + Sync + Debug + 'static {
         request: QueryHostConsensusStateRequest,
     ) -> Result<AnyConsensusState, Error>;
 
+    /// Query the ICS-29 fees escrowed for the packets sent over a channel
+    /// which have not been relayed yet.
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error>;
+
     fn maybe_register_counterparty_payee(
         &self,
         channel_id: ChannelId,
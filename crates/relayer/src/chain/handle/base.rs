@@ -10,11 +10,13 @@ use ibc_relayer_types::{
         ics03_connection::version::Version,
         ics04_channel::channel::{ChannelEnd, IdentifiedChannelEnd},
         ics04_channel::packet::{PacketMsgType, Sequence},
+        ics04_channel::upgrade::{ErrorReceipt, Upgrade},
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::ChainId,
         ics24_host::identifier::ChannelId,
         ics24_host::identifier::{ClientId, ConnectionId, PortId},
     },
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     proofs::Proofs,
     signer::Signer,
@@ -31,7 +33,7 @@ use crate::{
     denom::DenomTrace,
     error::Error,
     event::IbcEventWithHeight,
-    keyring::KeyEntry,
+    keyring::{KeyEntry, KeySigner},
     light_client::AnyHeader,
     misbehaviour::MisbehaviourEvidence,
 };
@@ -130,7 +132,7 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::Config { reply_to })
     }
 
-    fn get_key(&self) -> Result<KeyEntry, Error> {
+    fn get_key(&self) -> Result<KeySigner, Error> {
         self.send(|reply_to| ChainRequest::GetKey { reply_to })
     }
 
@@ -166,6 +168,18 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryDenomTrace { hash, reply_to })
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+    ) -> Result<String, Error> {
+        self.send(|reply_to| ChainRequest::QueryInterchainAccount {
+            owner,
+            connection_id,
+            reply_to,
+        })
+    }
+
     fn query_application_status(&self) -> Result<ChainStatus, Error> {
         self.send(|reply_to| ChainRequest::QueryApplicationStatus { reply_to })
     }
@@ -294,6 +308,30 @@ impl ChainHandle for BaseChainHandle {
         })
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(Upgrade, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgrade {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+    ) -> Result<(ErrorReceipt, Option<MerkleProof>), Error> {
+        self.send(|reply_to| ChainRequest::QueryUpgradeError {
+            request,
+            include_proof,
+            reply_to,
+        })
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -485,6 +523,13 @@ impl ChainHandle for BaseChainHandle {
         self.send(|reply_to| ChainRequest::QueryHostConsensusState { request, reply_to })
     }
 
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+    ) -> Result<Vec<IdentifiedPacketFees>, Error> {
+        self.send(|reply_to| ChainRequest::QueryIncentivizedPackets { request, reply_to })
+    }
+
     fn maybe_register_counterparty_payee(
         &self,
         channel_id: ChannelId,
@@ -1,6 +1,7 @@
 use alloc::sync::Arc;
 use core::ops::Add;
 use core::time::Duration;
+use std::path::Path;
 
 use crossbeam_channel as channel;
 use tendermint_testgen::light_block::TmLightBlock;
@@ -38,7 +39,7 @@ use crate::denom::DenomTrace;
 use crate::error::Error;
 use crate::event::monitor::{EventReceiver, EventSender, TxMonitorCmd};
 use crate::event::IbcEventWithHeight;
-use crate::keyring::{KeyEntry, KeyRing};
+use crate::keyring::{KeyEntry, KeyRing, KeySigner};
 use crate::light_client::Verified;
 use crate::light_client::{mock::LightClient as MockLightClient, LightClient};
 use crate::misbehaviour::MisbehaviourEvidence;
@@ -109,6 +110,7 @@ impl ChainEndpoint for MockChain {
     fn init_event_monitor(
         &self,
         _rt: Arc<Runtime>,
+        _state_dir: Option<&Path>,
     ) -> Result<(EventReceiver, TxMonitorCmd), Error> {
         let (tx, _) = crossbeam_channel::unbounded();
         Ok((self.event_receiver.clone(), tx))
@@ -183,7 +185,7 @@ impl ChainEndpoint for MockChain {
         self.config.clone()
     }
 
-    fn get_key(&mut self) -> Result<KeyEntry, Error> {
+    fn get_key(&mut self) -> Result<KeySigner, Error> {
         unimplemented!()
     }
 
@@ -3,6 +3,7 @@ use core::fmt::{self, Display};
 use crate::error::Error;
 
 use ibc_proto::cosmos::base::query::v1beta1::PageRequest as RawPageRequest;
+use ibc_proto::ibc::applications::fee::v1::QueryIncentivizedPacketsForChannelRequest as RawQueryIncentivizedPacketsForChannelRequest;
 use ibc_proto::ibc::core::channel::v1::{
     QueryChannelClientStateRequest as RawQueryChannelClientStateRequest,
     QueryChannelsRequest as RawQueryChannelsRequest,
@@ -247,6 +248,22 @@ pub struct QueryChannelRequest {
     pub height: QueryHeight,
 }
 
+/// Used to query the upgrade proposed for a channel end.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
+/// Used to query the receipt of the last failed upgrade of a channel end.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryUpgradeErrorRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub height: QueryHeight,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryChannelClientStateRequest {
     pub port_id: PortId,
@@ -384,6 +401,26 @@ impl From<QueryNextSequenceReceiveRequest> for RawQueryNextSequenceReceiveReques
     }
 }
 
+/// Used to query the ICS-29 fees escrowed for the packets sent over a channel
+/// which have not been relayed yet.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueryIncentivizedPacketsRequest {
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub pagination: Option<PageRequest>,
+}
+
+impl From<QueryIncentivizedPacketsRequest> for RawQueryIncentivizedPacketsForChannelRequest {
+    fn from(request: QueryIncentivizedPacketsRequest) -> Self {
+        RawQueryIncentivizedPacketsForChannelRequest {
+            port_id: request.port_id.to_string(),
+            channel_id: request.channel_id.to_string(),
+            pagination: request.pagination.map(|pagination| pagination.into()),
+            query_height: 0,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryHostConsensusStateRequest {
     pub height: QueryHeight,
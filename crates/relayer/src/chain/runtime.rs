@@ -1,4 +1,5 @@
 use alloc::sync::Arc;
+use std::path::PathBuf;
 use std::thread;
 
 use crossbeam_channel as channel;
@@ -15,10 +16,12 @@ use ibc_relayer_types::{
         ics04_channel::{
             channel::{ChannelEnd, IdentifiedChannelEnd},
             packet::{PacketMsgType, Sequence},
+            upgrade::{ErrorReceipt, Upgrade},
         },
         ics23_commitment::{commitment::CommitmentPrefix, merkle::MerkleProof},
         ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId},
     },
+    applications::ics29_fee::packet_fee::IdentifiedPacketFees,
     applications::ics31_icq::response::CrossChainQueryResponse,
     proofs::Proofs,
     signer::Signer,
@@ -39,7 +42,7 @@ use crate::{
         monitor::{EventBatch, EventReceiver, MonitorCmd, Result as MonitorResult, TxMonitorCmd},
         IbcEventWithHeight,
     },
-    keyring::KeyEntry,
+    keyring::{KeyEntry, KeySigner},
     light_client::AnyHeader,
     misbehaviour::MisbehaviourEvidence,
 };
@@ -53,10 +56,11 @@ use super::{
         QueryClientConnectionsRequest, QueryClientStateRequest, QueryClientStatesRequest,
         QueryConnectionChannelsRequest, QueryConnectionRequest, QueryConnectionsRequest,
         QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHostConsensusStateRequest,
-        QueryNextSequenceReceiveRequest, QueryPacketAcknowledgementRequest,
-        QueryPacketAcknowledgementsRequest, QueryPacketCommitmentRequest,
-        QueryPacketCommitmentsRequest, QueryPacketReceiptRequest, QueryTxRequest,
-        QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest, QueryUpgradedClientStateRequest,
+        QueryIncentivizedPacketsRequest, QueryNextSequenceReceiveRequest,
+        QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementsRequest,
+        QueryPacketCommitmentRequest, QueryPacketCommitmentsRequest, QueryPacketReceiptRequest,
+        QueryTxRequest, QueryUnreceivedAcksRequest, QueryUnreceivedPacketsRequest,
+        QueryUpgradeErrorRequest, QueryUpgradeRequest, QueryUpgradedClientStateRequest,
         QueryUpgradedConsensusStateRequest,
     },
     tracking::TrackedMsgs,
@@ -143,6 +147,9 @@ pub struct ChainRuntime<Endpoint: ChainEndpoint> {
     /// Interface to the event monitor
     event_monitor_ctrl: EventMonitorCtrl,
 
+    /// The directory in which the relaying state is persisted, if enabled
+    state_dir: Option<PathBuf>,
+
     #[allow(dead_code)]
     rt: Arc<TokioRuntime>, // Making this future-proof, so we keep the runtime around.
 }
@@ -152,15 +159,18 @@ where
     Endpoint: ChainEndpoint + Send + 'static,
 {
     /// Spawns a new runtime for a specific Chain implementation.
+    ///
+    /// If `state_dir` is set, the event monitor of the chain persists its progress in it.
     pub fn spawn<Handle: ChainHandle>(
         config: ChainConfig,
+        state_dir: Option<PathBuf>,
         rt: Arc<TokioRuntime>,
     ) -> Result<Handle, Error> {
         // Similar to `from_config`.
         let chain = Endpoint::bootstrap(config, rt.clone())?;
 
         // Instantiate & spawn the runtime
-        let (handle, _) = Self::init(chain, rt);
+        let (handle, _) = Self::init(chain, state_dir, rt);
 
         Ok(handle)
     }
@@ -168,9 +178,10 @@ where
     /// Initializes a runtime for a given chain, and spawns the associated thread
     fn init<Handle: ChainHandle>(
         chain: Endpoint,
+        state_dir: Option<PathBuf>,
         rt: Arc<TokioRuntime>,
     ) -> (Handle, thread::JoinHandle<()>) {
-        let chain_runtime = Self::new(chain, rt);
+        let chain_runtime = Self::new(chain, state_dir, rt);
 
         // Get a handle to the runtime
         let handle: Handle = chain_runtime.handle();
@@ -187,7 +198,7 @@ where
     }
 
     /// Basic constructor
-    fn new(chain: Endpoint, rt: Arc<TokioRuntime>) -> Self {
+    fn new(chain: Endpoint, state_dir: Option<PathBuf>, rt: Arc<TokioRuntime>) -> Self {
         let (request_sender, request_receiver) = channel::unbounded();
 
         Self {
@@ -197,6 +208,7 @@ where
             request_receiver,
             event_bus: EventBus::new(),
             event_monitor_ctrl: EventMonitorCtrl::none(),
+            state_dir,
         }
     }
 
@@ -316,6 +328,10 @@ where
                             self.query_denom_trace(hash, reply_to)?
                         },
 
+                        ChainRequest::QueryInterchainAccount { owner, connection_id, reply_to } => {
+                            self.query_interchain_account(owner, connection_id, reply_to)?
+                        },
+
                         ChainRequest::QueryApplicationStatus { reply_to } => {
                             self.query_application_status(reply_to)?
                         },
@@ -376,6 +392,14 @@ where
                             self.query_channel(request, include_proof, reply_to)?
                         },
 
+                        ChainRequest::QueryUpgrade { request, include_proof, reply_to } => {
+                            self.query_upgrade(request, include_proof, reply_to)?
+                        },
+
+                        ChainRequest::QueryUpgradeError { request, include_proof, reply_to } => {
+                            self.query_upgrade_error(request, include_proof, reply_to)?
+                        },
+
                         ChainRequest::QueryChannelClientState { request, reply_to } => {
                             self.query_channel_client_state(request, reply_to)?
                         },
@@ -428,6 +452,10 @@ where
                             self.query_host_consensus_state(request, reply_to)?
                         },
 
+                        ChainRequest::QueryIncentivizedPackets { request, reply_to } => {
+                            self.query_incentivized_packets(request, reply_to)?
+                        },
+
                         ChainRequest::MaybeRegisterCounterpartyPayee { channel_id, port_id, counterparty_payee, reply_to } => {
                             self.maybe_register_counterparty_payee(&channel_id, &port_id, &counterparty_payee, reply_to)?
                         },
@@ -458,7 +486,9 @@ where
     }
 
     fn enable_event_monitor(&mut self) -> Result<(), Error> {
-        let (event_receiver, tx_monitor_cmd) = self.chain.init_event_monitor(self.rt.clone())?;
+        let (event_receiver, tx_monitor_cmd) = self
+            .chain
+            .init_event_monitor(self.rt.clone(), self.state_dir.as_deref())?;
 
         self.event_monitor_ctrl
             .enable(event_receiver, tx_monitor_cmd);
@@ -511,6 +541,16 @@ where
         reply_to.send(denom_trace).map_err(Error::send)
     }
 
+    fn query_interchain_account(
+        &self,
+        owner: Signer,
+        connection_id: ConnectionId,
+        reply_to: ReplyTo<String>,
+    ) -> Result<(), Error> {
+        let address = self.chain.query_interchain_account(owner, connection_id);
+        reply_to.send(address).map_err(Error::send)
+    }
+
     fn query_application_status(&self, reply_to: ReplyTo<ChainStatus>) -> Result<(), Error> {
         let latest_timestamp = self.chain.query_application_status();
         reply_to.send(latest_timestamp).map_err(Error::send)
@@ -526,7 +566,7 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
-    fn get_key(&mut self, reply_to: ReplyTo<KeyEntry>) -> Result<(), Error> {
+    fn get_key(&mut self, reply_to: ReplyTo<KeySigner>) -> Result<(), Error> {
         let result = self.chain.get_key();
         reply_to.send(result).map_err(Error::send)
     }
@@ -754,6 +794,26 @@ where
         reply_to.send(result).map_err(Error::send)
     }
 
+    fn query_upgrade(
+        &self,
+        request: QueryUpgradeRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(Upgrade, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_upgrade(request, include_proof);
+        reply_to.send(result).map_err(Error::send)
+    }
+
+    fn query_upgrade_error(
+        &self,
+        request: QueryUpgradeErrorRequest,
+        include_proof: IncludeProof,
+        reply_to: ReplyTo<(ErrorReceipt, Option<MerkleProof>)>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_upgrade_error(request, include_proof);
+        reply_to.send(result).map_err(Error::send)
+    }
+
     fn query_channel_client_state(
         &self,
         request: QueryChannelClientStateRequest,
@@ -909,6 +969,17 @@ where
         Ok(())
     }
 
+    fn query_incentivized_packets(
+        &self,
+        request: QueryIncentivizedPacketsRequest,
+        reply_to: ReplyTo<Vec<IdentifiedPacketFees>>,
+    ) -> Result<(), Error> {
+        let result = self.chain.query_incentivized_packets(request);
+        reply_to.send(result).map_err(Error::send)?;
+
+        Ok(())
+    }
+
     fn maybe_register_counterparty_payee(
         &mut self,
         channel_id: &ChannelId,
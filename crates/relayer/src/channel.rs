@@ -6,6 +6,7 @@ use serde::Serialize;
 use tracing::{debug, error, info, warn};
 
 pub use error::ChannelError;
+use ibc_relayer_types::clients::ics09_localhost::{sentinel_proofs, LOCALHOST_CONNECTION_ID};
 use ibc_relayer_types::core::ics04_channel::channel::{
     ChannelEnd, Counterparty, IdentifiedChannelEnd, Order, State,
 };
@@ -19,6 +20,7 @@ use ibc_relayer_types::core::ics24_host::identifier::{
     ChainId, ChannelId, ClientId, ConnectionId, PortId,
 };
 use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::tx_msg::Msg;
 use ibc_relayer_types::Height;
 
@@ -39,6 +41,7 @@ use crate::util::retry::RetryResult;
 use crate::util::task::Next;
 
 pub mod error;
+pub mod upgrade;
 pub mod version;
 use version::Version;
 
@@ -405,6 +408,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         &self.b_side.connection_id
     }
 
+    /// Whether this channel is built on top of the localhost connection,
+    /// between two modules of the same chain.
+    pub fn is_localhost(&self) -> bool {
+        self.dst_connection_id() == LOCALHOST_CONNECTION_ID
+    }
+
     pub fn src_port_id(&self) -> &PortId {
         &self.a_side.port_id
     }
@@ -699,7 +708,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 
     /// Executes the channel handshake protocol (ICS004)
-    fn handshake(&mut self) -> Result<(), ChannelError> {
+    pub fn handshake(&mut self) -> Result<(), ChannelError> {
         let max_block_times = self.max_block_times()?;
 
         retry_with_index(handshake_retry::default_strategy(max_block_times), |_| {
@@ -747,13 +756,38 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         &mut self,
         state: State,
     ) -> Result<(Option<IbcEvent>, Next), ChannelError> {
-        let event = match (state, self.counterparty_state()?) {
+        let counterparty_state = self.counterparty_state()?;
+
+        // Abort the upgrade of the destination end if the source end
+        // did not complete its side of the upgrade before the deadline
+        if counterparty_state.is_upgrading() && self.dst_upgrade_timed_out()? {
+            let event = self.build_chan_upgrade_timeout_and_send()?;
+            return Ok((Some(event), Next::Abort));
+        }
+
+        let event = match (state, counterparty_state) {
             (State::Init, State::Uninitialized) => Some(self.build_chan_open_try_and_send()?),
             (State::Init, State::Init) => Some(self.build_chan_open_try_and_send()?),
             (State::TryOpen, State::Init) => Some(self.build_chan_open_ack_and_send()?),
             (State::TryOpen, State::TryOpen) => Some(self.build_chan_open_ack_and_send()?),
             (State::Open, State::TryOpen) => Some(self.build_chan_open_confirm_and_send()?),
-            (State::Open, State::Open) => return Ok((None, Next::Abort)),
+            (State::Open, State::Open) => {
+                // Resume an upgrade which was proposed while the relayer was not running
+                if self.src_upgrade_pending()? {
+                    Some(self.build_chan_upgrade_try_and_send()?)
+                } else {
+                    return Ok((None, Next::Abort));
+                }
+            }
+
+            // The source end accepted the upgrade proposed by the destination end
+            (State::Flushing, State::Open) => Some(self.build_chan_upgrade_ack_and_send()?),
+
+            // Once both ends of an upgrading channel have flushed their packets in flight,
+            // or the source end is already open with the upgrade, open the destination end.
+            (State::Flushcomplete, State::Flushcomplete) | (State::Open, State::Flushcomplete) => {
+                Some(self.build_chan_upgrade_open_and_send()?)
+            }
 
             // If the counterparty state is already Open but current state is TryOpen,
             // return anyway as the final step is to be done by the counterparty worker.
@@ -764,9 +798,9 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
 
         // Abort if the channel is at OpenAck or OpenConfirm stage, as there is nothing more for the worker to do
         match event {
-            Some(IbcEvent::OpenConfirmChannel(_)) | Some(IbcEvent::OpenAckChannel(_)) => {
-                Ok((event, Next::Abort))
-            }
+            Some(IbcEvent::OpenConfirmChannel(_))
+            | Some(IbcEvent::OpenAckChannel(_))
+            | Some(IbcEvent::UpgradeOpenChannel(_)) => Ok((event, Next::Abort)),
             _ => Ok((event, Next::Continue)),
         }
     }
@@ -799,6 +833,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             IbcEvent::OpenTryChannel(_) => State::TryOpen,
             IbcEvent::OpenAckChannel(_) => State::Open,
             IbcEvent::OpenConfirmChannel(_) => State::Open,
+            IbcEvent::UpgradeInitChannel(_)
+            | IbcEvent::UpgradeTryChannel(_)
+            | IbcEvent::UpgradeAckChannel(_)
+            | IbcEvent::UpgradeConfirmChannel(_)
+            | IbcEvent::UpgradeOpenChannel(_)
+            | IbcEvent::UpgradeErrorChannel(_)
+            | IbcEvent::FlushCompleteChannel(_) => return self.step_upgrade_event(event, index),
             _ => State::Uninitialized,
         };
 
@@ -806,6 +847,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
     }
 
     pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, ChannelError> {
+        // The localhost client is updated by the chain itself at every block
+        if self.is_localhost() {
+            return Ok(vec![]);
+        }
+
         let client = ForeignClient::restore(
             self.dst_client_id().clone(),
             self.dst_chain().clone(),
@@ -817,6 +863,24 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
         })
     }
 
+    /// Builds the proofs of the channel end on the source chain at the given height.
+    ///
+    /// The localhost client reads the channel end from the store of the chain,
+    /// so channels on top of the localhost connection only carry the sentinel proof.
+    fn build_channel_proofs(
+        &self,
+        src_channel_id: &ChannelId,
+        query_height: Height,
+    ) -> Result<Proofs, ChannelError> {
+        if self.is_localhost() {
+            return Ok(sentinel_proofs(query_height.increment()));
+        }
+
+        self.src_chain()
+            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
+            .map_err(ChannelError::channel_proof)
+    }
+
     pub fn build_chan_open_init(&self) -> Result<Vec<Any>, ChannelError> {
         let signer = self
             .dst_chain()
@@ -999,10 +1063,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
@@ -1119,10 +1180,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
@@ -1227,10 +1285,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
@@ -1398,10 +1453,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
             .query_latest_height()
             .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
 
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, query_height)
-            .map_err(ChannelError::channel_proof)?;
+        let proofs = self.build_channel_proofs(src_channel_id, query_height)?;
 
         // Build message(s) to update client on destination
         let mut msgs = self.build_update_client_on_dst(proofs.height())?;
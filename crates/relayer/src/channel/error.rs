@@ -154,6 +154,17 @@ define_error! {
                     e.counterparty_channel_id)
             },
 
+        InvalidUpgradeState
+            {
+                chain_id: ChainId,
+                channel_id: ChannelId,
+                state: State,
+            }
+            | e | {
+                format_args!("channel '{}' on chain '{}' is in state '{}', which does not allow this step of the channel upgrade",
+                    e.channel_id, e.chain_id, e.state)
+            },
+
         MissingEvent
             { description: String }
             | e | {
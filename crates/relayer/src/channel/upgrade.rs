@@ -0,0 +1,693 @@
+//! Relaying of the channel upgrade handshake.
+//!
+//! The upgrade handshake moves an open channel to new parameters, i.e. a new
+//! version, ordering or connection, without closing it. Each step is submitted
+//! to the destination chain and proves the channel end and, depending on the step,
+//! the upgrade or the error receipt stored on the source chain.
+
+use ibc_proto::google::protobuf::Any;
+use tracing::{error, info};
+
+use ibc_relayer_types::clients::ics09_localhost::sentinel_proofs;
+use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, State};
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_ack::MsgChannelUpgradeAck;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_cancel::MsgChannelUpgradeCancel;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_confirm::MsgChannelUpgradeConfirm;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_init::MsgChannelUpgradeInit;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_open::MsgChannelUpgradeOpen;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_timeout::MsgChannelUpgradeTimeout;
+use ibc_relayer_types::core::ics04_channel::msgs::chan_upgrade_try::MsgChannelUpgradeTry;
+use ibc_relayer_types::core::ics04_channel::upgrade::{Upgrade, UpgradeFields};
+use ibc_relayer_types::core::ics23_commitment::commitment::CommitmentProofBytes;
+use ibc_relayer_types::core::ics23_commitment::merkle::MerkleProof;
+use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ConnectionId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::proofs::Proofs;
+use ibc_relayer_types::signer::Signer;
+use ibc_relayer_types::tx_msg::Msg;
+use ibc_relayer_types::Height;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{
+    IncludeProof, QueryChannelRequest, QueryConnectionRequest, QueryHeight,
+    QueryUpgradeErrorRequest, QueryUpgradeRequest,
+};
+use crate::chain::tracking::TrackedMsgs;
+use crate::error::Error as RelayerError;
+use crate::foreign_client::HasExpiredOrFrozenError;
+use crate::util::retry::RetryResult;
+use crate::util::task::Next;
+
+use super::error::ChannelError;
+use super::Channel;
+
+/// Converts the proof returned by a query into the proof carried by a message.
+fn proof_bytes(proof: Option<MerkleProof>) -> Result<CommitmentProofBytes, ChannelError> {
+    proof
+        .ok_or_else(RelayerError::empty_response_proof)
+        .and_then(|proof| {
+            CommitmentProofBytes::try_from(proof).map_err(RelayerError::malformed_proof)
+        })
+        .map_err(ChannelError::channel_proof)
+}
+
+impl<ChainA: ChainHandle, ChainB: ChainHandle> Channel<ChainA, ChainB> {
+    /// Queries the channel end on the source chain at the given height, along with its proof.
+    fn query_src_channel_with_proof(
+        &self,
+        src_channel_id: &ChannelId,
+        query_height: Height,
+    ) -> Result<(ChannelEnd, Option<MerkleProof>), ChannelError> {
+        self.src_chain()
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(query_height),
+                },
+                self.upgrade_include_proof(),
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))
+    }
+
+    /// The localhost client verifies the upgrade handshake against the store of the chain,
+    /// so no proofs are queried for channels on top of the localhost connection.
+    fn upgrade_include_proof(&self) -> IncludeProof {
+        if self.is_localhost() {
+            IncludeProof::No
+        } else {
+            IncludeProof::Yes
+        }
+    }
+
+    /// Builds the proofs of the channel end and of the upgrade on the source chain
+    /// at the given height, and returns them along with the channel end and the upgrade.
+    fn build_channel_upgrade_proofs(
+        &self,
+        src_channel_id: &ChannelId,
+        query_height: Height,
+    ) -> Result<(ChannelEnd, Upgrade, Proofs), ChannelError> {
+        let (channel, maybe_channel_proof) =
+            self.query_src_channel_with_proof(src_channel_id, query_height)?;
+
+        let (upgrade, maybe_upgrade_proof) = self
+            .src_chain()
+            .query_upgrade(
+                QueryUpgradeRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(query_height),
+                },
+                self.upgrade_include_proof(),
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        if self.is_localhost() {
+            return Ok((channel, upgrade, sentinel_proofs(query_height.increment())));
+        }
+
+        let proofs = Proofs::new(
+            proof_bytes(maybe_channel_proof)?,
+            None,
+            None,
+            Some(proof_bytes(maybe_upgrade_proof)?),
+            query_height.increment(),
+        )
+        .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        Ok((channel, upgrade, proofs))
+    }
+
+    /// Builds the proof of the channel end on the source chain at the given height,
+    /// and returns it along with the channel end.
+    fn build_channel_end_proofs(
+        &self,
+        src_channel_id: &ChannelId,
+        query_height: Height,
+    ) -> Result<(ChannelEnd, Proofs), ChannelError> {
+        let (channel, maybe_channel_proof) =
+            self.query_src_channel_with_proof(src_channel_id, query_height)?;
+
+        if self.is_localhost() {
+            return Ok((channel, sentinel_proofs(query_height.increment())));
+        }
+
+        let proofs = Proofs::new(
+            proof_bytes(maybe_channel_proof)?,
+            None,
+            None,
+            None,
+            query_height.increment(),
+        )
+        .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?;
+
+        Ok((channel, proofs))
+    }
+
+    /// Queries the latest channel end on the given side of this channel.
+    fn query_latest_channel_end<Chain: ChainHandle>(
+        chain: &Chain,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<ChannelEnd, ChannelError> {
+        chain
+            .query_channel(
+                QueryChannelRequest {
+                    port_id: port_id.clone(),
+                    channel_id: channel_id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map(|(channel, _)| channel)
+            .map_err(|e| ChannelError::query(chain.id(), e))
+    }
+
+    /// Returns whether the source channel end proposed an upgrade which
+    /// the destination channel end has not accepted yet, e.g. because
+    /// the upgrade was initiated while the relayer was not running.
+    pub fn src_upgrade_pending(&self) -> Result<bool, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let src_channel =
+            Self::query_latest_channel_end(self.src_chain(), self.src_port_id(), src_channel_id)?;
+        let dst_channel =
+            Self::query_latest_channel_end(self.dst_chain(), self.dst_port_id(), dst_channel_id)?;
+
+        if src_channel.upgrade_sequence() <= dst_channel.upgrade_sequence() {
+            return Ok(false);
+        }
+
+        // The upgrade is only stored while it is in progress, and
+        // is deleted once it was cancelled or timed out.
+        let upgrade = self.src_chain().query_upgrade(
+            QueryUpgradeRequest {
+                port_id: self.src_port_id().clone(),
+                channel_id: src_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        );
+
+        Ok(upgrade.is_ok())
+    }
+
+    /// Queries the upgrade in progress of the destination channel end, if any.
+    fn query_dst_upgrade(&self) -> Result<Option<Upgrade>, ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        // The upgrade is only stored while it is in progress
+        let upgrade = self.dst_chain().query_upgrade(
+            QueryUpgradeRequest {
+                port_id: self.dst_port_id().clone(),
+                channel_id: dst_channel_id.clone(),
+                height: QueryHeight::Latest,
+            },
+            IncludeProof::No,
+        );
+
+        Ok(upgrade.ok().map(|(upgrade, _)| upgrade))
+    }
+
+    /// Returns whether the upgrade of the destination channel end can be timed out,
+    /// i.e. whether the source chain passed the deadline of that upgrade before
+    /// its channel end flushed its packets in flight or was opened with the upgrade.
+    pub fn dst_upgrade_timed_out(&self) -> Result<bool, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let upgrade = match self.query_dst_upgrade()? {
+            Some(upgrade) => upgrade,
+            None => return Ok(false),
+        };
+
+        let src_channel =
+            Self::query_latest_channel_end(self.src_chain(), self.src_port_id(), src_channel_id)?;
+
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+        let dst_channel =
+            Self::query_latest_channel_end(self.dst_chain(), self.dst_port_id(), dst_channel_id)?;
+
+        // The source end completed its side of the upgrade, which can no longer time out
+        let completed = src_channel.state == State::Flushcomplete
+            || (src_channel.state == State::Open
+                && src_channel.upgrade_sequence() >= dst_channel.upgrade_sequence());
+
+        if completed {
+            return Ok(false);
+        }
+
+        let status = self
+            .src_chain()
+            .query_application_status()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        Ok(upgrade.timeout.has_elapsed(status.height, status.timestamp))
+    }
+
+    /// The connections of the destination chain matching the connection hops proposed
+    /// by the source channel end, i.e. the counterparties of the proposed connections.
+    fn counterparty_connection_hops(
+        &self,
+        connection_hops: &[ConnectionId],
+    ) -> Result<Vec<ConnectionId>, ChannelError> {
+        connection_hops
+            .iter()
+            .map(|connection_id| {
+                let (connection, _) = self
+                    .src_chain()
+                    .query_connection(
+                        QueryConnectionRequest {
+                            connection_id: connection_id.clone(),
+                            height: QueryHeight::Latest,
+                        },
+                        IncludeProof::No,
+                    )
+                    .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+                connection
+                    .counterparty()
+                    .connection_id()
+                    .cloned()
+                    .ok_or_else(ChannelError::missing_counterparty_connection)
+            })
+            .collect()
+    }
+
+    /// Opens the destination channel end once both ends have flushed their packets
+    /// in flight, or once the source end is already open with the upgrade.
+    ///
+    /// Otherwise, this is left to the flush completion on the other end,
+    /// or to the worker checking the channel ends on new blocks.
+    fn build_chan_upgrade_open_if_flushed(&self) -> Result<Option<IbcEvent>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let src_channel =
+            Self::query_latest_channel_end(self.src_chain(), self.src_port_id(), src_channel_id)?;
+
+        match (src_channel.state, self.counterparty_state()?) {
+            (State::Flushcomplete | State::Open, State::Flushcomplete) => {
+                Ok(Some(self.build_chan_upgrade_open_and_send()?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn src_query_height(&self) -> Result<Height, ChannelError> {
+        self.src_chain()
+            .query_latest_height()
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))
+    }
+
+    fn dst_signer_and_channel_id(&self) -> Result<(Signer, ChannelId), ChannelError> {
+        let dst_channel_id = self
+            .dst_channel_id()
+            .ok_or_else(ChannelError::missing_counterparty_channel_id)?;
+
+        let signer = self
+            .dst_chain()
+            .get_signer()
+            .map_err(|e| ChannelError::fetch_signer(self.dst_chain().id(), e))?;
+
+        Ok((signer, dst_channel_id.clone()))
+    }
+
+    /// Builds the message which proposes to upgrade the destination channel end
+    /// to the given fields.
+    ///
+    /// Note that the chain only accepts this message when it is signed by the authority
+    /// configured for channel upgrades, which defaults to the governance module.
+    pub fn build_chan_upgrade_init(&self, fields: UpgradeFields) -> Result<Vec<Any>, ChannelError> {
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let new_msg = MsgChannelUpgradeInit {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            fields,
+            signer,
+        };
+
+        Ok(vec![new_msg.to_any()])
+    }
+
+    pub fn build_chan_upgrade_init_and_send(
+        &self,
+        fields: UpgradeFields,
+    ) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_init(fields)?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeInit", |event| {
+            matches!(event, IbcEvent::UpgradeInitChannel(_))
+        })
+    }
+
+    pub fn build_chan_upgrade_try(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let query_height = self.src_query_height()?;
+        let (src_channel, upgrade, proofs) =
+            self.build_channel_upgrade_proofs(src_channel_id, query_height)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let proposed_upgrade_connection_hops =
+            self.counterparty_connection_hops(&upgrade.fields.connection_hops)?;
+
+        let new_msg = MsgChannelUpgradeTry {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            proposed_upgrade_connection_hops,
+            counterparty_upgrade_fields: upgrade.fields,
+            counterparty_upgrade_sequence: *src_channel.upgrade_sequence(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_try_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_try()?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeTry", |event| {
+            matches!(event, IbcEvent::UpgradeTryChannel(_))
+        })
+    }
+
+    pub fn build_chan_upgrade_ack(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let query_height = self.src_query_height()?;
+        let (_, upgrade, proofs) =
+            self.build_channel_upgrade_proofs(src_channel_id, query_height)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let new_msg = MsgChannelUpgradeAck {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            counterparty_upgrade: upgrade,
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_ack_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_ack()?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeAck", |event| {
+            matches!(event, IbcEvent::UpgradeAckChannel(_))
+        })
+    }
+
+    pub fn build_chan_upgrade_confirm(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let query_height = self.src_query_height()?;
+        let (src_channel, upgrade, proofs) =
+            self.build_channel_upgrade_proofs(src_channel_id, query_height)?;
+
+        // The counterparty must have acknowledged the upgrade
+        if !src_channel.state.is_upgrading() {
+            return Err(ChannelError::invalid_upgrade_state(
+                self.src_chain().id(),
+                src_channel_id.clone(),
+                src_channel.state,
+            ));
+        }
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let new_msg = MsgChannelUpgradeConfirm {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            counterparty_channel_state: src_channel.state,
+            counterparty_upgrade: upgrade,
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_confirm_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_confirm()?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeConfirm", |event| {
+            matches!(event, IbcEvent::UpgradeConfirmChannel(_))
+        })
+    }
+
+    pub fn build_chan_upgrade_open(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let query_height = self.src_query_height()?;
+        let (src_channel, proofs) = self.build_channel_end_proofs(src_channel_id, query_height)?;
+
+        // The counterparty must have flushed its packets in flight, or be open with the upgrade
+        if !matches!(src_channel.state, State::Flushcomplete | State::Open) {
+            return Err(ChannelError::invalid_upgrade_state(
+                self.src_chain().id(),
+                src_channel_id.clone(),
+                src_channel.state,
+            ));
+        }
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let new_msg = MsgChannelUpgradeOpen {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            counterparty_channel_state: src_channel.state,
+            counterparty_upgrade_sequence: *src_channel.upgrade_sequence(),
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_open_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_open()?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeOpen", |event| {
+            matches!(event, IbcEvent::UpgradeOpenChannel(_))
+        })
+    }
+
+    /// Builds the message which aborts the upgrade of the destination channel end,
+    /// because the counterparty did not progress before the upgrade timed out.
+    pub fn build_chan_upgrade_timeout(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let query_height = self.src_query_height()?;
+        let (src_channel, proofs) = self.build_channel_end_proofs(src_channel_id, query_height)?;
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let new_msg = MsgChannelUpgradeTimeout {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            counterparty_channel: src_channel,
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_timeout_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_timeout()?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeTimeout", |event| {
+            matches!(event, IbcEvent::UpgradeTimeoutChannel(_))
+        })
+    }
+
+    /// Builds the message which aborts the upgrade of the destination channel end,
+    /// because the upgrade failed on the counterparty and an error receipt was written.
+    pub fn build_chan_upgrade_cancel(&self) -> Result<Vec<Any>, ChannelError> {
+        let src_channel_id = self
+            .src_channel_id()
+            .ok_or_else(ChannelError::missing_local_channel_id)?;
+
+        let query_height = self.src_query_height()?;
+
+        let (error_receipt, maybe_error_receipt_proof) = self
+            .src_chain()
+            .query_upgrade_error(
+                QueryUpgradeErrorRequest {
+                    port_id: self.src_port_id().clone(),
+                    channel_id: src_channel_id.clone(),
+                    height: QueryHeight::Specific(query_height),
+                },
+                self.upgrade_include_proof(),
+            )
+            .map_err(|e| ChannelError::query(self.src_chain().id(), e))?;
+
+        let proofs = if self.is_localhost() {
+            sentinel_proofs(query_height.increment())
+        } else {
+            Proofs::new(
+                proof_bytes(maybe_error_receipt_proof)?,
+                None,
+                None,
+                None,
+                query_height.increment(),
+            )
+            .map_err(|e| ChannelError::channel_proof(RelayerError::malformed_proof(e)))?
+        };
+
+        let mut msgs = self.build_update_client_on_dst(proofs.height())?;
+
+        let (signer, dst_channel_id) = self.dst_signer_and_channel_id()?;
+
+        let new_msg = MsgChannelUpgradeCancel {
+            port_id: self.dst_port_id().clone(),
+            channel_id: dst_channel_id,
+            error_receipt,
+            proofs,
+            signer,
+        };
+
+        msgs.push(new_msg.to_any());
+        Ok(msgs)
+    }
+
+    pub fn build_chan_upgrade_cancel_and_send(&self) -> Result<IbcEvent, ChannelError> {
+        let dst_msgs = self.build_chan_upgrade_cancel()?;
+
+        self.send_upgrade_msgs(dst_msgs, "ChannelUpgradeCancel", |event| {
+            matches!(event, IbcEvent::UpgradeCancelChannel(_))
+        })
+    }
+
+    /// Submits the given upgrade messages to the destination chain and returns
+    /// the event emitted by the upgrade step.
+    fn send_upgrade_msgs(
+        &self,
+        dst_msgs: Vec<Any>,
+        name: &'static str,
+        is_expected_event: impl Fn(&IbcEvent) -> bool,
+    ) -> Result<IbcEvent, ChannelError> {
+        let tm = TrackedMsgs::new_static(dst_msgs, name);
+
+        let events = self
+            .dst_chain()
+            .send_messages_and_wait_commit(tm)
+            .map_err(|e| ChannelError::submit(self.dst_chain().id(), e))?;
+
+        let result = events
+            .into_iter()
+            .find(|event_with_height| {
+                is_expected_event(&event_with_height.event)
+                    || matches!(event_with_height.event, IbcEvent::ChainError(_))
+            })
+            .ok_or_else(|| {
+                ChannelError::missing_event(format!("no {} event was in the response", name))
+            })?;
+
+        match &result.event {
+            IbcEvent::ChainError(e) => Err(ChannelError::tx_response(e.clone())),
+            event if is_expected_event(event) => {
+                info!("🎊  {} => {}", self.dst_chain().id(), result);
+                Ok(result.event)
+            }
+            _ => Err(ChannelError::invalid_event(result.event)),
+        }
+    }
+
+    /// Relays the step of the upgrade handshake which follows the given event
+    /// emitted by the source chain.
+    pub fn upgrade_handshake_step(
+        &mut self,
+        event: &IbcEvent,
+    ) -> Result<(Option<IbcEvent>, Next), ChannelError> {
+        let event = match event {
+            IbcEvent::UpgradeInitChannel(_) => Some(self.build_chan_upgrade_try_and_send()?),
+            IbcEvent::UpgradeTryChannel(_) => Some(self.build_chan_upgrade_ack_and_send()?),
+            IbcEvent::UpgradeAckChannel(_) => Some(self.build_chan_upgrade_confirm_and_send()?),
+
+            // The destination end can only be opened once both ends have flushed
+            // their packets in flight, which completes either when the upgrade is
+            // confirmed or when the last packet in flight is acknowledged or timed out.
+            IbcEvent::UpgradeConfirmChannel(_)
+            | IbcEvent::UpgradeOpenChannel(_)
+            | IbcEvent::FlushCompleteChannel(_) => self.build_chan_upgrade_open_if_flushed()?,
+
+            // The upgrade failed on the source end, which wrote an error receipt
+            // proving to the destination end that its upgrade must be cancelled.
+            IbcEvent::UpgradeErrorChannel(_) => match self.query_dst_upgrade()? {
+                Some(_) => Some(self.build_chan_upgrade_cancel_and_send()?),
+                None => return Ok((None, Next::Abort)),
+            },
+
+            _ => None,
+        };
+
+        match event {
+            Some(IbcEvent::UpgradeOpenChannel(_)) | Some(IbcEvent::UpgradeCancelChannel(_)) => {
+                Ok((event, Next::Abort))
+            }
+            _ => Ok((event, Next::Continue)),
+        }
+    }
+
+    pub fn step_upgrade_event(&mut self, event: &IbcEvent, index: u64) -> RetryResult<Next, u64> {
+        match self.upgrade_handshake_step(event) {
+            Err(e) => {
+                if e.is_expired_or_frozen_error() {
+                    error!("failed to upgrade channel on frozen client: {}", e);
+                    RetryResult::Err(index)
+                } else {
+                    error!("failed to relay channel upgrade after {}: {}", event, e);
+                    RetryResult::Retry(index)
+                }
+            }
+            Ok((Some(ev), handshake_completed)) => {
+                info!("channel upgrade step completed with events: {}", ev);
+                RetryResult::Ok(handshake_completed)
+            }
+            Ok((None, handshake_completed)) => RetryResult::Ok(handshake_completed),
+        }
+    }
+}
@@ -8,10 +8,20 @@ use ibc_proto::protobuf::Protobuf;
 use serde::{Deserialize, Serialize};
 
 use ibc_proto::google::protobuf::Any;
+use ibc_relayer_types::clients::ics06_solomachine::client_state::{
+    ClientState as SmClientState, SOLOMACHINE_CLIENT_STATE_TYPE_URL,
+    SOLOMACHINE_V3_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::client_state::{
     ClientState as TmClientState, UpgradeOptions as TmUpgradeOptions,
     TENDERMINT_CLIENT_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::client_state::{
+    ClientState as WasmClientState, WASM_CLIENT_STATE_TYPE_URL,
+};
+use ibc_relayer_types::clients::ics09_localhost::client_state::{
+    ClientState as LocalhostClientState, LOCALHOST_CLIENT_STATE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::client_state::{
     downcast_client_state, ClientState, UpgradeOptions,
 };
@@ -52,6 +62,9 @@ impl UpgradeOptions for AnyUpgradeOptions {}
 #[serde(tag = "type")]
 pub enum AnyClientState {
     Tendermint(TmClientState),
+    Solomachine(SmClientState),
+    Wasm(WasmClientState),
+    Localhost(LocalhostClientState),
 
     #[cfg(test)]
     Mock(MockClientState),
@@ -61,6 +74,9 @@ impl AnyClientState {
     pub fn latest_height(&self) -> Height {
         match self {
             Self::Tendermint(tm_state) => tm_state.latest_height(),
+            Self::Solomachine(sm_state) => sm_state.latest_height(),
+            Self::Wasm(wasm_state) => wasm_state.latest_height(),
+            Self::Localhost(lh_state) => lh_state.latest_height(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.latest_height(),
@@ -70,6 +86,9 @@ impl AnyClientState {
     pub fn frozen_height(&self) -> Option<Height> {
         match self {
             Self::Tendermint(tm_state) => tm_state.frozen_height(),
+            Self::Solomachine(sm_state) => sm_state.frozen_height(),
+            Self::Wasm(wasm_state) => wasm_state.frozen_height(),
+            Self::Localhost(lh_state) => lh_state.frozen_height(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.frozen_height(),
@@ -79,6 +98,9 @@ impl AnyClientState {
     pub fn trust_threshold(&self) -> Option<TrustThreshold> {
         match self {
             AnyClientState::Tendermint(state) => Some(state.trust_threshold),
+            AnyClientState::Solomachine(_) => None,
+            AnyClientState::Wasm(state) => Some(state.inner.trust_threshold),
+            AnyClientState::Localhost(_) => None,
 
             #[cfg(test)]
             AnyClientState::Mock(_) => None,
@@ -88,6 +110,9 @@ impl AnyClientState {
     pub fn max_clock_drift(&self) -> Duration {
         match self {
             AnyClientState::Tendermint(state) => state.max_clock_drift,
+            AnyClientState::Solomachine(_) => Duration::new(0, 0),
+            AnyClientState::Wasm(state) => state.inner.max_clock_drift,
+            AnyClientState::Localhost(_) => Duration::new(0, 0),
 
             #[cfg(test)]
             AnyClientState::Mock(_) => Duration::new(0, 0),
@@ -97,6 +122,9 @@ impl AnyClientState {
     pub fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(state) => state.client_type(),
+            Self::Solomachine(state) => state.client_type(),
+            Self::Wasm(state) => state.client_type(),
+            Self::Localhost(state) => state.client_type(),
 
             #[cfg(test)]
             Self::Mock(state) => state.client_type(),
@@ -106,6 +134,9 @@ impl AnyClientState {
     pub fn refresh_period(&self) -> Option<Duration> {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.refresh_time(),
+            AnyClientState::Solomachine(_) => None,
+            AnyClientState::Wasm(wasm_state) => wasm_state.inner.refresh_time(),
+            AnyClientState::Localhost(_) => None,
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.refresh_time(),
@@ -127,6 +158,16 @@ impl TryFrom<Any> for AnyClientState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            SOLOMACHINE_CLIENT_STATE_TYPE_URL | SOLOMACHINE_V3_CLIENT_STATE_TYPE_URL => {
+                Ok(AnyClientState::Solomachine(SmClientState::try_from(raw)?))
+            }
+
+            WASM_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Wasm(WasmClientState::try_from(raw)?)),
+
+            LOCALHOST_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Localhost(
+                LocalhostClientState::try_from(raw)?,
+            )),
+
             #[cfg(test)]
             MOCK_CLIENT_STATE_TYPE_URL => Ok(AnyClientState::Mock(
                 Protobuf::<RawMockClientState>::decode_vec(&raw.value)
@@ -146,6 +187,9 @@ impl From<AnyClientState> for Any {
                 value: Protobuf::<RawClientState>::encode_vec(&value)
                     .expect("encoding to `Any` from `AnyClientState::Tendermint`"),
             },
+            AnyClientState::Solomachine(value) => value.into(),
+            AnyClientState::Wasm(value) => value.into(),
+            AnyClientState::Localhost(value) => value.into(),
             #[cfg(test)]
             AnyClientState::Mock(value) => Any {
                 type_url: MOCK_CLIENT_STATE_TYPE_URL.to_string(),
@@ -160,6 +204,9 @@ impl ClientState for AnyClientState {
     fn chain_id(&self) -> ChainId {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.chain_id(),
+            AnyClientState::Solomachine(sm_state) => sm_state.chain_id(),
+            AnyClientState::Wasm(wasm_state) => wasm_state.chain_id(),
+            AnyClientState::Localhost(lh_state) => lh_state.chain_id(),
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.chain_id(),
@@ -194,6 +241,17 @@ impl ClientState for AnyClientState {
                 upgrade_options.as_tm_upgrade_options().unwrap(),
                 chain_id,
             ),
+            AnyClientState::Solomachine(sm_state) => {
+                sm_state.upgrade(upgrade_height, upgrade_options, chain_id)
+            }
+            AnyClientState::Wasm(wasm_state) => wasm_state.inner.upgrade(
+                upgrade_height,
+                upgrade_options.as_tm_upgrade_options().unwrap(),
+                chain_id,
+            ),
+            AnyClientState::Localhost(lh_state) => {
+                lh_state.upgrade(upgrade_height, upgrade_options, chain_id)
+            }
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => {
@@ -205,6 +263,9 @@ impl ClientState for AnyClientState {
     fn expired(&self, elapsed_since_latest: Duration) -> bool {
         match self {
             AnyClientState::Tendermint(tm_state) => tm_state.expired(elapsed_since_latest),
+            AnyClientState::Solomachine(sm_state) => sm_state.expired(elapsed_since_latest),
+            AnyClientState::Wasm(wasm_state) => wasm_state.expired(elapsed_since_latest),
+            AnyClientState::Localhost(lh_state) => lh_state.expired(elapsed_since_latest),
 
             #[cfg(test)]
             AnyClientState::Mock(mock_state) => mock_state.expired(elapsed_since_latest),
@@ -218,6 +279,24 @@ impl From<TmClientState> for AnyClientState {
     }
 }
 
+impl From<SmClientState> for AnyClientState {
+    fn from(cs: SmClientState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmClientState> for AnyClientState {
+    fn from(cs: WasmClientState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
+impl From<LocalhostClientState> for AnyClientState {
+    fn from(cs: LocalhostClientState) -> Self {
+        Self::Localhost(cs)
+    }
+}
+
 #[cfg(test)]
 impl From<MockClientState> for AnyClientState {
     fn from(cs: MockClientState) -> Self {
@@ -234,6 +313,12 @@ impl From<&dyn ClientState> for AnyClientState {
 
         if let Some(cs) = downcast_client_state::<TmClientState>(client_state) {
             AnyClientState::from(cs.clone())
+        } else if let Some(cs) = downcast_client_state::<SmClientState>(client_state) {
+            AnyClientState::from(cs.clone())
+        } else if let Some(cs) = downcast_client_state::<WasmClientState>(client_state) {
+            AnyClientState::from(cs.clone())
+        } else if let Some(cs) = downcast_client_state::<LocalhostClientState>(client_state) {
+            AnyClientState::from(cs.clone())
         } else {
             unreachable!()
         }
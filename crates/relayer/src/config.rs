@@ -1,17 +1,26 @@
 //! Relayer configuration
 
+pub mod dynamic_gas;
 pub mod error;
+pub mod event_source;
+pub mod fee_budget;
 pub mod filter;
 pub mod gas_multiplier;
 pub mod proof_specs;
 pub mod types;
+pub mod wasm_checksum;
 
 use alloc::collections::BTreeMap;
 use core::{
     fmt::{Display, Error as FmtError, Formatter},
     time::Duration,
 };
-use std::{fs, fs::File, io::Write, path::Path};
+use std::{
+    fs,
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use ibc_proto::google::protobuf::Any;
 use serde_derive::{Deserialize, Serialize};
@@ -28,9 +37,13 @@ use crate::error::Error as RelayerError;
 use crate::extension_options::ExtensionOptionDynamicFeeTx;
 use crate::keyring::Store;
 
+pub use dynamic_gas::DynamicGasPrice;
 pub use error::Error;
+pub use event_source::EventSourceMode;
+pub use fee_budget::FeeBudget;
+pub use wasm_checksum::WasmChecksum;
 
-pub use filter::PacketFilter;
+pub use filter::{FeeFilter, PacketFilter};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GasPrice {
@@ -110,6 +123,10 @@ pub mod default {
         Duration::from_secs(10)
     }
 
+    pub fn remote_signer_timeout() -> Duration {
+        Duration::from_secs(10)
+    }
+
     pub fn clock_drift() -> Duration {
         Duration::from_secs(5)
     }
@@ -138,6 +155,8 @@ pub struct Config {
     pub rest: RestConfig,
     #[serde(default)]
     pub telemetry: TelemetryConfig,
+    #[serde(default)]
+    pub state: StateConfig,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub chains: Vec<ChainConfig>,
 }
@@ -201,6 +220,7 @@ impl Default for ModeConfig {
                 enabled: true,
                 refresh: true,
                 misbehaviour: false,
+                misbehaviour_scan_interval: None,
             },
             connections: Connections { enabled: false },
             channels: Channels { enabled: false },
@@ -220,6 +240,15 @@ pub struct Clients {
     pub refresh: bool,
     #[serde(default)]
     pub misbehaviour: bool,
+    /// If set, periodically scan all the consensus states of the clients for
+    /// misbehaviour, starting at startup, in addition to checking the client
+    /// updates observed while running.
+    #[serde(
+        default,
+        with = "humantime_serde",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub misbehaviour_scan_interval: Option<Duration>,
 }
 
 #[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
@@ -303,6 +332,10 @@ pub struct TelemetryConfig {
     pub enabled: bool,
     pub host: String,
     pub port: u16,
+    #[serde(default)]
+    pub traces: TracesConfig,
+    #[serde(default)]
+    pub buckets: TelemetryBuckets,
 }
 
 /// Default values for the telemetry configuration.
@@ -314,6 +347,53 @@ impl Default for TelemetryConfig {
             enabled: false,
             host: "127.0.0.1".to_string(),
             port: 3001,
+            traces: TracesConfig::default(),
+            buckets: TelemetryBuckets::default(),
+        }
+    }
+}
+
+/// The upper bounds of the buckets of the packet latency histograms, in milliseconds.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TelemetryBuckets {
+    /// Buckets of the latency from the block in which a packet is sent
+    /// to the block in which it is received
+    pub packet_recv_latency: Vec<u64>,
+    /// Buckets of the latency from the block in which a packet is received
+    /// to the block in which its acknowledgement is relayed
+    pub packet_ack_latency: Vec<u64>,
+}
+
+impl Default for TelemetryBuckets {
+    fn default() -> Self {
+        let buckets = vec![1000, 2000, 5000, 10000, 20000, 30000, 60000, 120000, 300000];
+
+        Self {
+            packet_recv_latency: buckets.clone(),
+            packet_ack_latency: buckets,
+        }
+    }
+}
+
+/// Configuration of the export of the traces of the packet lifecycle
+/// to an OpenTelemetry collector, with OTLP over HTTP.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TracesConfig {
+    pub enabled: bool,
+    /// The base URL of the OTLP/HTTP endpoint of the collector,
+    /// to which traces are posted under `/v1/traces`
+    pub endpoint: String,
+    pub service_name: String,
+}
+
+impl Default for TracesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://127.0.0.1:4318".to_string(),
+            service_name: "hermes".to_string(),
         }
     }
 }
@@ -336,6 +416,28 @@ impl Default for RestConfig {
     }
 }
 
+/// Configuration of the on-disk persistence of the relaying state,
+/// ie. the transactions awaiting confirmation and the packet clearing progress.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StateConfig {
+    pub enabled: bool,
+    /// The directory in which the state is stored.
+    /// Defaults to `$HOME/.hermes/state` when unspecified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dir: Option<PathBuf>,
+}
+
+/// The remote signer holding the keys of a chain whose `key_store_type` is `Remote`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// The base URL of the signing service, eg. `https://signer.internal:8443`
+    pub url: String,
+    #[serde(default = "default::remote_signer_timeout", with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
 /// It defines the address generation method
 /// TODO: Ethermint `pk_type` to be restricted
 /// after the Cosmos SDK release with ethsecp256k1
@@ -376,10 +478,18 @@ pub struct ChainConfig {
     pub rpc_addr: tendermint_rpc::Url,
     pub websocket_addr: tendermint_rpc::Url,
     pub grpc_addr: tendermint_rpc::Url,
+    /// RPC addresses of witness nodes, whose light blocks are cross-checked
+    /// against the ones of the node at `rpc_addr` by the light client.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub witness_rpc_addrs: Vec<tendermint_rpc::Url>,
     #[serde(default = "default::rpc_timeout", with = "humantime_serde")]
     pub rpc_timeout: Duration,
     pub account_prefix: String,
     pub key_name: String,
+    /// Additional keys used to submit transactions alongside `key_name`,
+    /// so that transactions are not serialized on a single account sequence.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_names: Vec<String>,
     #[serde(default)]
     pub key_store_type: Store,
     pub store_prefix: String,
@@ -413,6 +523,11 @@ pub struct ChainConfig {
     #[serde(default, with = "humantime_serde")]
     pub trusting_period: Option<Duration>,
 
+    /// The directory in which the light blocks verified by the light client are persisted,
+    /// so that they are reused across restarts. When unspecified, they are only kept in memory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light_block_store_dir: Option<PathBuf>,
+
     #[serde(default)]
     pub memo_prefix: Memo,
 
@@ -441,15 +556,51 @@ pub struct ChainConfig {
 
     pub gas_price: GasPrice,
 
+    #[serde(default)]
+    pub dynamic_gas_price: DynamicGasPrice,
+
+    /// How to collect the IBC events emitted by the chain
+    #[serde(default)]
+    pub event_source: EventSourceMode,
+
     #[serde(default)]
     pub packet_filter: PacketFilter,
 
+    #[serde(default, skip_serializing_if = "FeeFilter::is_empty")]
+    pub fee_filter: FeeFilter,
+
+    #[serde(default, skip_serializing_if = "FeeBudget::is_unlimited")]
+    pub fee_budget: FeeBudget,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_signer: Option<RemoteSignerConfig>,
+
+    /// Checksum of the `08-wasm` contract which hosts the clients created on this chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wasm_checksum: Option<WasmChecksum>,
+
     #[serde(default)]
     pub address_type: AddressType,
     #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
     pub extension_options: Vec<ExtensionOption>,
 }
 
+impl ChainConfig {
+    /// The names of all the keys used to submit transactions to this chain,
+    /// starting with `key_name`, followed by the additional `key_names`.
+    pub fn all_key_names(&self) -> Vec<&str> {
+        let mut names = vec![self.key_name.as_str()];
+
+        for name in &self.key_names {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+
+        names
+    }
+}
+
 /// Attempt to load and parse the TOML config file as a `Config`.
 pub fn load(path: impl AsRef<Path>) -> Result<Config, Error> {
     let config_toml = std::fs::read_to_string(&path).map_err(Error::io)?;
@@ -0,0 +1,80 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Configuration of the gas price discovered from the fee market of a chain,
+/// for chains whose base fee changes from block to block.
+///
+/// When enabled, the gas price of every transaction is the current base fee
+/// of the chain, times the `multiplier`, bounded by `max`. The static `gas_price`
+/// of the chain is used whenever the base fee cannot be queried.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DynamicGasPrice {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "DynamicGasPrice::default_multiplier")]
+    pub multiplier: f64,
+    #[serde(default = "DynamicGasPrice::default_max")]
+    pub max: f64,
+}
+
+impl DynamicGasPrice {
+    const DEFAULT_MULTIPLIER: f64 = 1.1;
+    const DEFAULT_MAX: f64 = 0.6;
+
+    /// The smallest allowed multiplier, so that the gas price
+    /// never falls below the base fee of the chain.
+    pub const MIN_MULTIPLIER: f64 = 1.0;
+
+    fn default_multiplier() -> f64 {
+        Self::DEFAULT_MULTIPLIER
+    }
+
+    fn default_max() -> f64 {
+        Self::DEFAULT_MAX
+    }
+
+    /// The gas price to use given the current base fee of the chain.
+    pub fn price_from_base_fee(&self, base_fee: f64) -> f64 {
+        (base_fee * self.multiplier).min(self.max)
+    }
+}
+
+impl Default for DynamicGasPrice {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            multiplier: Self::DEFAULT_MULTIPLIER,
+            max: Self::DEFAULT_MAX,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dynamic_gas_price() {
+        #[derive(Debug, Deserialize)]
+        struct DummyConfig {
+            dynamic_gas_price: DynamicGasPrice,
+        }
+
+        let config: DummyConfig = toml::from_str("dynamic_gas_price = { enabled = true }").unwrap();
+
+        assert_eq!(
+            config.dynamic_gas_price,
+            DynamicGasPrice {
+                enabled: true,
+                ..DynamicGasPrice::default()
+            }
+        );
+
+        let config: DummyConfig =
+            toml::from_str("dynamic_gas_price = { enabled = true, multiplier = 1.5, max = 0.5 }")
+                .unwrap();
+
+        assert_eq!(config.dynamic_gas_price.price_from_base_fee(0.25), 0.375);
+        assert_eq!(config.dynamic_gas_price.price_from_base_fee(0.5), 0.5);
+    }
+}
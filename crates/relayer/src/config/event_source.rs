@@ -0,0 +1,66 @@
+use core::time::Duration;
+
+use serde_derive::{Deserialize, Serialize};
+
+/// How the relayer collects the IBC events emitted by a chain.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "lowercase")]
+pub enum EventSourceMode {
+    /// Events are pushed by the node over a WebSocket subscription, at `websocket_addr`.
+    Push,
+    /// Events are pulled from the results of each new block, fetched from the RPC
+    /// endpoint at `rpc_addr` every `interval`, for nodes whose WebSocket endpoint
+    /// is throttled or disabled.
+    Pull {
+        #[serde(
+            default = "EventSourceMode::default_pull_interval",
+            with = "humantime_serde"
+        )]
+        interval: Duration,
+    },
+}
+
+impl EventSourceMode {
+    fn default_pull_interval() -> Duration {
+        Duration::from_secs(1)
+    }
+}
+
+impl Default for EventSourceMode {
+    fn default() -> Self {
+        Self::Push
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_event_source() {
+        #[derive(Debug, Deserialize)]
+        struct DummyConfig {
+            event_source: EventSourceMode,
+        }
+
+        let config: DummyConfig = toml::from_str("event_source = { mode = 'push' }").unwrap();
+        assert_eq!(config.event_source, EventSourceMode::Push);
+
+        let config: DummyConfig =
+            toml::from_str("event_source = { mode = 'pull', interval = '500ms' }").unwrap();
+        assert_eq!(
+            config.event_source,
+            EventSourceMode::Pull {
+                interval: Duration::from_millis(500)
+            }
+        );
+
+        let config: DummyConfig = toml::from_str("event_source = { mode = 'pull' }").unwrap();
+        assert_eq!(
+            config.event_source,
+            EventSourceMode::Pull {
+                interval: Duration::from_secs(1)
+            }
+        );
+    }
+}
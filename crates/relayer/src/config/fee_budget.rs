@@ -0,0 +1,28 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Limits on the fees the relayer is allowed to spend on a chain,
+/// expressed in the denomination of the chain's `gas_price`.
+///
+/// Transactions whose fee would exceed any of the limits are not submitted,
+/// which pauses relaying to the chain until enough of the rolling budgets frees up.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeeBudget {
+    /// The maximum fee of a single transaction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_tx: Option<u64>,
+    /// The maximum total fees paid over the last hour
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fees_per_hour: Option<u64>,
+    /// The maximum total fees paid over the last day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_fees_per_day: Option<u64>,
+}
+
+impl FeeBudget {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_fee_per_tx.is_none()
+            && self.max_fees_per_hour.is_none()
+            && self.max_fees_per_day.is_none()
+    }
+}
@@ -3,6 +3,8 @@
 use core::fmt;
 use core::str::FromStr;
 
+use ibc_relayer_types::applications::ics29_fee::packet_fee::PacketFee;
+use ibc_relayer_types::applications::transfer::amount::Amount;
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, PortId};
 use itertools::Itertools;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -145,6 +147,99 @@ impl Serialize for ChannelFilters {
     }
 }
 
+/// Minimum ICS-29 fees that packets must be incentivized with in order to be relayed.
+///
+/// Packets sent over channels without a matching entry are relayed regardless
+/// of the fees attached to them.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FeeFilter(Vec<ChannelMinFees>);
+
+impl FeeFilter {
+    /// Create a new fee filter from the given list of per-channel minimum fees.
+    pub fn new(filters: Vec<ChannelMinFees>) -> Self {
+        Self(filters)
+    }
+
+    /// Returns true if there are no filters, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the minimum fees that apply to packets sent over the channel with
+    /// [`PortId`] and [`ChannelId`], if any. When several entries match,
+    /// the first one takes precedence.
+    pub fn min_fees(&self, port_id: &PortId, channel_id: &ChannelId) -> Option<&ChannelMinFees> {
+        self.0
+            .iter()
+            .find(|fees| fees.port_id.matches(port_id) && fees.channel_id.matches(channel_id))
+    }
+}
+
+/// The minimum fees required for relaying packets over a set of channels.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChannelMinFees {
+    pub port_id: PortFilterMatch,
+    pub channel_id: ChannelFilterMatch,
+
+    /// The accepted minimum receive fees. A packet qualifies for relaying
+    /// if its total receive fee satisfies any of these.
+    #[serde(default)]
+    pub recv: Vec<MinFee>,
+}
+
+impl ChannelMinFees {
+    /// Indicates whether a packet incentivized with the given fees should be relayed.
+    ///
+    /// The receive fees of all the [`PacketFee`]s escrowed for the packet are summed up
+    /// per denomination before being compared against the minimums.
+    pub fn is_satisfied_by(&self, packet_fees: &[PacketFee]) -> bool {
+        if self.recv.is_empty() {
+            return true;
+        }
+
+        self.recv.iter().any(|min_fee| {
+            let total = packet_fees
+                .iter()
+                .flat_map(|packet_fee| packet_fee.fee.recv_fee.iter())
+                .filter(|coin| coin.denom == min_fee.denom)
+                .try_fold(Amount::from(0u64), |total, coin| {
+                    total.checked_add(coin.amount)
+                });
+
+            // An overflowing total is certainly above any minimum we can configure
+            total.map_or(true, |total| total >= Amount::from(min_fee.amount))
+        })
+    }
+}
+
+impl fmt::Display for ChannelMinFees {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}: {}",
+            self.port_id,
+            self.channel_id,
+            self.recv.iter().join(" or ")
+        )
+    }
+}
+
+/// A minimum amount of tokens in the given denomination.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MinFee {
+    pub amount: u64,
+    pub denom: String,
+}
+
+impl fmt::Display for MinFee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
 /// Newtype wrapper for expressing wildcard patterns compiled to a [`regex::Regex`].
 #[derive(Clone, Debug)]
 pub struct Wildcard {
@@ -503,6 +598,66 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn fee_filter_min_fees() {
+        use ibc_relayer_types::applications::ics29_fee::packet_fee::Fee;
+        use ibc_relayer_types::applications::transfer::coin::RawCoin;
+
+        #[derive(Deserialize)]
+        struct Config {
+            fee_filter: FeeFilter,
+        }
+
+        let toml_content = r#"
+            [[fee_filter]]
+            port_id = 'transfer'
+            channel_id = 'channel-0'
+            recv = [
+              { amount = 20, denom = 'stake' },
+              { amount = 100, denom = 'uatom' },
+            ]
+
+            [[fee_filter]]
+            port_id = 'transfer'
+            channel_id = 'channel-*'
+            recv = []
+            "#;
+
+        let config: Config = toml::from_str(toml_content).expect("could not parse fee filter");
+        let filter = config.fee_filter;
+
+        let transfer = PortId::from_str("transfer").unwrap();
+
+        assert!(filter
+            .min_fees(&PortId::from_str("ica").unwrap(), &ChannelId::new(0))
+            .is_none());
+        assert!(filter
+            .min_fees(&transfer, &ChannelId::new(1))
+            .unwrap()
+            .is_satisfied_by(&[]));
+
+        let min_fees = filter.min_fees(&transfer, &ChannelId::new(0)).unwrap();
+
+        let packet_fee = |amount: u64, denom: &str| PacketFee {
+            fee: Fee {
+                recv_fee: vec![RawCoin {
+                    denom: denom.to_string(),
+                    amount: amount.into(),
+                }],
+                ack_fee: vec![],
+                timeout_fee: vec![],
+            },
+            refund_address: "cosmos1refund".parse().unwrap(),
+        };
+
+        assert!(!min_fees.is_satisfied_by(&[]));
+        assert!(!min_fees.is_satisfied_by(&[packet_fee(10, "stake")]));
+        assert!(!min_fees.is_satisfied_by(&[packet_fee(50, "uatom")]));
+        assert!(min_fees.is_satisfied_by(&[packet_fee(20, "stake")]));
+        assert!(min_fees.is_satisfied_by(&[packet_fee(10, "stake"), packet_fee(10, "stake")]));
+        assert!(min_fees.is_satisfied_by(&[packet_fee(10, "stake"), packet_fee(100, "uatom")]));
+    }
+
     #[test]
     fn to_string_wildcards() {
         let wildcard = "ica*".parse::<Wildcard>().unwrap();
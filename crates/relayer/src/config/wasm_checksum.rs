@@ -0,0 +1,117 @@
+use serde::de::Unexpected;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+flex_error::define_error! {
+    Error {
+        InvalidHex
+            { value: String }
+            |e| {
+                format_args!("`wasm_checksum` must be a hex-encoded string, found '{}'", e.value)
+            },
+
+        InvalidLength
+            { length: usize }
+            |e| {
+                format_args!("`wasm_checksum` must be {} bytes long, found {} bytes",
+                    WasmChecksum::LENGTH, e.length)
+            },
+    }
+}
+
+/// The SHA-256 checksum of the code of a Wasm light client contract,
+/// which identifies that contract on the chain hosting it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmChecksum(Vec<u8>);
+
+impl WasmChecksum {
+    const LENGTH: usize = 32;
+
+    pub fn new(bytes: Vec<u8>) -> Result<Self, Error> {
+        if bytes.len() != Self::LENGTH {
+            return Err(Error::invalid_length(bytes.len()));
+        }
+
+        Ok(Self(bytes))
+    }
+
+    pub fn from_hex(value: &str) -> Result<Self, Error> {
+        let bytes = hex::decode(value).map_err(|_| Error::invalid_hex(value.to_string()))?;
+
+        Self::new(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for WasmChecksum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        WasmChecksum::from_hex(&value).map_err(|_| {
+            D::Error::invalid_value(
+                Unexpected::Str(&value),
+                &format!("a hex-encoded checksum of {} bytes", Self::LENGTH).as_str(),
+            )
+        })
+    }
+}
+
+impl Serialize for WasmChecksum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        hex::encode(&self.0).serialize(serializer)
+    }
+}
+
+impl From<WasmChecksum> for Vec<u8> {
+    fn from(checksum: WasmChecksum) -> Self {
+        checksum.0
+    }
+}
+
+#[cfg(test)]
+#[allow(dead_code)] // the field of the struct `DummyConfig` defined below is never accessed
+mod tests {
+    use super::*;
+
+    use serde::Deserialize;
+    use test_log::test;
+
+    #[derive(Debug, Deserialize)]
+    struct DummyConfig {
+        wasm_checksum: WasmChecksum,
+    }
+
+    #[test]
+    fn parse_wasm_checksum() {
+        let config = toml::from_str::<DummyConfig>(&format!(
+            "wasm_checksum = '{}'",
+            "ab".repeat(WasmChecksum::LENGTH)
+        ))
+        .unwrap();
+
+        assert_eq!(config.wasm_checksum.as_bytes(), &[0xab; 32]);
+    }
+
+    #[test]
+    fn parse_invalid_wasm_checksum() {
+        let err = toml::from_str::<DummyConfig>("wasm_checksum = 'abcd'")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("expected a hex-encoded checksum of 32 bytes"));
+
+        let err = toml::from_str::<DummyConfig>("wasm_checksum = 'not hex'")
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("expected a hex-encoded checksum of 32 bytes"));
+    }
+}
@@ -4,9 +4,16 @@ use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensus
 #[cfg(test)]
 use ibc_proto::ibc::mock::ConsensusState as RawMockConsensusState;
 use ibc_proto::protobuf::Protobuf;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::{
+    ConsensusState as SmConsensusState, SOLOMACHINE_CONSENSUS_STATE_TYPE_URL,
+    SOLOMACHINE_V3_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::consensus_state::{
     ConsensusState as TmConsensusState, TENDERMINT_CONSENSUS_STATE_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::{
+    ConsensusState as WasmConsensusState, WASM_CONSENSUS_STATE_TYPE_URL,
+};
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::consensus_state::{
     downcast_consensus_state, ConsensusState,
@@ -25,6 +32,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type")]
 pub enum AnyConsensusState {
     Tendermint(TmConsensusState),
+    Solomachine(SmConsensusState),
+    Wasm(WasmConsensusState),
 
     #[cfg(test)]
     Mock(MockConsensusState),
@@ -34,6 +43,8 @@ impl AnyConsensusState {
     pub fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(cs_state) => cs_state.timestamp.into(),
+            Self::Solomachine(cs_state) => cs_state.timestamp(),
+            Self::Wasm(cs_state) => cs_state.timestamp(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.timestamp(),
@@ -43,11 +54,24 @@ impl AnyConsensusState {
     pub fn client_type(&self) -> ClientType {
         match self {
             AnyConsensusState::Tendermint(_cs) => ClientType::Tendermint,
+            AnyConsensusState::Solomachine(_cs) => ClientType::Solomachine,
+            AnyConsensusState::Wasm(_cs) => ClientType::Wasm,
 
             #[cfg(test)]
             AnyConsensusState::Mock(_cs) => ClientType::Mock,
         }
     }
+
+    /// Whether this consensus state matches the given one, comparing the wrapped
+    /// Tendermint state of clients hosted by a Wasm contract with a Tendermint state.
+    pub fn matches(&self, other: &AnyConsensusState) -> bool {
+        match (self, other) {
+            (Self::Wasm(wasm), Self::Tendermint(tm)) | (Self::Tendermint(tm), Self::Wasm(wasm)) => {
+                &wasm.inner == tm
+            }
+            _ => self == other,
+        }
+    }
 }
 
 impl Protobuf<Any> for AnyConsensusState {}
@@ -64,6 +88,14 @@ impl TryFrom<Any> for AnyConsensusState {
                     .map_err(Error::decode_raw_client_state)?,
             )),
 
+            SOLOMACHINE_CONSENSUS_STATE_TYPE_URL | SOLOMACHINE_V3_CONSENSUS_STATE_TYPE_URL => Ok(
+                AnyConsensusState::Solomachine(SmConsensusState::try_from(value)?),
+            ),
+
+            WASM_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Wasm(
+                WasmConsensusState::try_from(value)?,
+            )),
+
             #[cfg(test)]
             MOCK_CONSENSUS_STATE_TYPE_URL => Ok(AnyConsensusState::Mock(
                 Protobuf::<RawMockConsensusState>::decode_vec(&value.value)
@@ -83,6 +115,8 @@ impl From<AnyConsensusState> for Any {
                 value: Protobuf::<RawConsensusState>::encode_vec(&value)
                     .expect("encoding to `Any` from `AnyConsensusState::Tendermint`"),
             },
+            AnyConsensusState::Solomachine(value) => value.into(),
+            AnyConsensusState::Wasm(value) => value.into(),
             #[cfg(test)]
             AnyConsensusState::Mock(value) => Any {
                 type_url: MOCK_CONSENSUS_STATE_TYPE_URL.to_string(),
@@ -106,6 +140,18 @@ impl From<TmConsensusState> for AnyConsensusState {
     }
 }
 
+impl From<SmConsensusState> for AnyConsensusState {
+    fn from(cs: SmConsensusState) -> Self {
+        Self::Solomachine(cs)
+    }
+}
+
+impl From<WasmConsensusState> for AnyConsensusState {
+    fn from(cs: WasmConsensusState) -> Self {
+        Self::Wasm(cs)
+    }
+}
+
 impl From<&dyn ConsensusState> for AnyConsensusState {
     fn from(cs: &dyn ConsensusState) -> Self {
         #[cfg(test)]
@@ -115,6 +161,10 @@ impl From<&dyn ConsensusState> for AnyConsensusState {
 
         if let Some(cs) = downcast_consensus_state::<TmConsensusState>(cs) {
             AnyConsensusState::from(cs.clone())
+        } else if let Some(cs) = downcast_consensus_state::<SmConsensusState>(cs) {
+            AnyConsensusState::from(cs.clone())
+        } else if let Some(cs) = downcast_consensus_state::<WasmConsensusState>(cs) {
+            AnyConsensusState::from(cs.clone())
         } else {
             unreachable!()
         }
@@ -166,6 +216,8 @@ impl ConsensusState for AnyConsensusState {
     fn root(&self) -> &CommitmentRoot {
         match self {
             Self::Tendermint(cs_state) => cs_state.root(),
+            Self::Solomachine(cs_state) => cs_state.root(),
+            Self::Wasm(cs_state) => cs_state.root(),
 
             #[cfg(test)]
             Self::Mock(mock_state) => mock_state.root(),
@@ -176,3 +228,32 @@ impl ConsensusState for AnyConsensusState {
         AnyConsensusState::timestamp(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint::{Hash, Time};
+
+    fn tm_consensus_state(root: &[u8]) -> TmConsensusState {
+        TmConsensusState::new(
+            CommitmentRoot::from_bytes(root),
+            Time::unix_epoch(),
+            Hash::None,
+        )
+    }
+
+    #[test]
+    fn wasm_consensus_state_matches_wrapped_state() {
+        let host_state = AnyConsensusState::Tendermint(tm_consensus_state(b"root"));
+
+        let client_state =
+            AnyConsensusState::Wasm(WasmConsensusState::new(tm_consensus_state(b"root")));
+        assert!(client_state.matches(&host_state));
+        assert!(host_state.matches(&client_state));
+
+        let other_state =
+            AnyConsensusState::Wasm(WasmConsensusState::new(tm_consensus_state(b"other")));
+        assert!(!other_state.matches(&host_state));
+    }
+}
@@ -39,6 +39,7 @@ use crate::chain::cosmos::version;
 use crate::chain::cosmos::BLOCK_MAX_BYTES_MAX_FRACTION;
 use crate::event::monitor;
 use crate::keyring::errors::Error as KeyringError;
+use crate::light_client::tendermint::store::LightBlockStoreError;
 use crate::sdk_error::SdkError;
 
 define_error! {
@@ -98,6 +99,14 @@ define_error! {
             { param: String }
             |e| { format!("missing parameter in GRPC response: {}", e.param) },
 
+        FeeBudgetExceeded
+            { chain_id: ChainId, reason: String }
+            |e| { format!("fee budget of chain {} exceeded: {}", e.chain_id, e.reason) },
+
+        InvalidBaseFee
+            { value: String }
+            |e| { format!("invalid base fee in fee market query response: '{}'", e.value) },
+
         Decode
             [ TendermintProtoError ]
             |_| { "error decoding protobuf" },
@@ -116,6 +125,33 @@ define_error! {
             [ LightClientIoError ]
             |e| { format!("light client error for RPC address {0}", e.address) },
 
+        LightClientFork
+            {
+                chain_id: ChainId,
+                height: u64,
+                primary: String,
+                witness: String,
+            }
+            |e| {
+                format!("light client of chain {0} detected a fork at height {1}: the header of the primary node {2} differs from the header of the witness node {3}",
+                    e.chain_id, e.height, e.primary, e.witness)
+            },
+
+        LightClientNoWitness
+            {
+                chain_id: ChainId,
+                height: u64,
+            }
+            |e| {
+                format!("light client of chain {0} could not cross-check the header at height {1} against any of its witness nodes",
+                    e.chain_id, e.height)
+            },
+
+        LightBlockStore
+            { chain_id: ChainId }
+            [ LightBlockStoreError ]
+            |e| { format!("failed to open the light block store of chain {}", e.chain_id) },
+
         ChainNotCaughtUp
             {
                 address: String,
@@ -14,7 +14,10 @@ use ibc_relayer_types::{
     },
     core::ics04_channel::{
         error::Error as ChannelError,
-        events::{self as channel_events, Attributes as ChannelAttributes},
+        events::{
+            self as channel_events, Attributes as ChannelAttributes,
+            UpgradeAttributes as ChannelUpgradeAttributes,
+        },
         packet::Packet,
         timeout::TimeoutHeight,
     },
@@ -28,6 +31,7 @@ use crate::light_client::decode_header;
 
 pub mod bus;
 pub mod monitor;
+pub mod pull;
 pub mod rpc;
 
 #[derive(Clone, Debug, Serialize)]
@@ -109,6 +113,33 @@ pub fn ibc_event_try_from_abci_event(abci_event: &AbciEvent) -> Result<IbcEvent,
             channel_close_confirm_try_from_abci_event(abci_event)
                 .map_err(IbcEventError::channel)?,
         )),
+        Ok(IbcEventType::UpgradeInitChannel) => Ok(IbcEvent::UpgradeInitChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeTryChannel) => Ok(IbcEvent::UpgradeTryChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeAckChannel) => Ok(IbcEvent::UpgradeAckChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeConfirmChannel) => Ok(IbcEvent::UpgradeConfirmChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeOpenChannel) => Ok(IbcEvent::UpgradeOpenChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeTimeoutChannel) => Ok(IbcEvent::UpgradeTimeoutChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeCancelChannel) => Ok(IbcEvent::UpgradeCancelChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::UpgradeErrorChannel) => Ok(IbcEvent::UpgradeErrorChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
+        Ok(IbcEventType::FlushCompleteChannel) => Ok(IbcEvent::FlushCompleteChannel(
+            channel_upgrade_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
+        )),
         Ok(IbcEventType::SendPacket) => Ok(IbcEvent::SendPacket(
             send_packet_try_from_abci_event(abci_event).map_err(IbcEventError::channel)?,
         )),
@@ -245,6 +276,15 @@ pub fn channel_close_confirm_try_from_abci_event(
     }
 }
 
+/// Parses any of the events emitted by the steps of the channel upgrade handshake,
+/// which all carry the same attributes.
+pub fn channel_upgrade_try_from_abci_event<E>(abci_event: &AbciEvent) -> Result<E, ChannelError>
+where
+    E: From<ChannelUpgradeAttributes>,
+{
+    channel_upgrade_extract_attributes_from_tx(abci_event).map(E::from)
+}
+
 pub fn send_packet_try_from_abci_event(
     abci_event: &AbciEvent,
 ) -> Result<channel_events::SendPacket, ChannelError> {
@@ -393,6 +433,40 @@ fn channel_extract_attributes_from_tx(
     Ok(attr)
 }
 
+fn channel_upgrade_extract_attributes_from_tx(
+    event: &AbciEvent,
+) -> Result<ChannelUpgradeAttributes, ChannelError> {
+    let mut attr = ChannelUpgradeAttributes::default();
+
+    for tag in &event.attributes {
+        let key = tag.key.as_ref();
+        let value = tag.value.as_ref();
+        match key {
+            channel_events::PORT_ID_ATTRIBUTE_KEY => {
+                attr.port_id = value.parse().map_err(ChannelError::identifier)?
+            }
+            channel_events::CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.channel_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_PORT_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_port_id = value.parse().map_err(ChannelError::identifier)?;
+            }
+            channel_events::COUNTERPARTY_CHANNEL_ID_ATTRIBUTE_KEY => {
+                attr.counterparty_channel_id = value.parse().ok();
+            }
+            channel_events::UPGRADE_SEQUENCE_ATTRIBUTE_KEY => {
+                attr.upgrade_sequence = value
+                    .parse::<u64>()
+                    .map_err(|e| ChannelError::invalid_string_as_sequence(value.to_string(), e))?
+                    .into();
+            }
+            _ => {}
+        }
+    }
+
+    Ok(attr)
+}
+
 fn extract_packet_and_write_ack_from_tx(
     event: &AbciEvent,
 ) -> Result<(Packet, Vec<u8>), ChannelError> {
@@ -560,6 +634,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_upgrade_event_to_abci_event() {
+        let attributes = ChannelUpgradeAttributes {
+            port_id: "test_port".parse().unwrap(),
+            channel_id: "channel-0".parse().unwrap(),
+            counterparty_port_id: "counterparty_test_port".parse().unwrap(),
+            counterparty_channel_id: Some("channel-1".parse().unwrap()),
+            upgrade_sequence: Sequence::from(2),
+        };
+        let upgrade_init = channel_events::UpgradeInit::from(attributes.clone());
+        let upgrade_cancel = channel_events::UpgradeCancel::from(attributes.clone());
+        let upgrade_error = channel_events::UpgradeError::from(attributes.clone());
+        let flush_complete = channel_events::FlushComplete::from(attributes.clone());
+
+        let abci_events = vec![
+            AbciEvent::from(upgrade_init),
+            AbciEvent::from(upgrade_cancel),
+            AbciEvent::from(upgrade_error),
+            AbciEvent::from(flush_complete),
+        ];
+
+        for abci_event in abci_events {
+            match ibc_event_try_from_abci_event(&abci_event).ok() {
+                Some(ibc_event) => match ibc_event {
+                    IbcEvent::UpgradeInitChannel(_)
+                    | IbcEvent::UpgradeCancelChannel(_)
+                    | IbcEvent::UpgradeErrorChannel(_)
+                    | IbcEvent::FlushCompleteChannel(_) => {
+                        assert_eq!(
+                            ibc_event.channel_upgrade_attributes(),
+                            Some(attributes.clone())
+                        )
+                    }
+                    _ => panic!("unexpected event type"),
+                },
+                None => panic!("converted event was wrong"),
+            }
+        }
+    }
+
     #[test]
     fn packet_event_to_abci_event() {
         let packet = Packet {
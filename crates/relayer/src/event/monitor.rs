@@ -21,7 +21,7 @@ use ibc_relayer_types::{
 };
 
 use crate::{
-    chain::tracking::TrackingId,
+    chain::{cosmos::query::results::AttributeEncoding, tracking::TrackingId},
     telemetry,
     util::{
         retry::{retry_with_index, RetryResult},
@@ -29,6 +29,7 @@ use crate::{
     },
 };
 
+mod compat;
 mod error;
 pub use error::*;
 
@@ -79,6 +80,10 @@ pub enum MonitorCmd {
 /// The default events that are queried are:
 /// - [`EventType::NewBlock`](tendermint_rpc::query::EventType::NewBlock)
 /// - [`EventType::Tx`](tendermint_rpc::query::EventType::Tx)
+///
+/// The events of nodes which encode the event attributes as plain strings,
+/// ie. CometBFT 0.37 and above, are received over a separate WebSocket
+/// connection, as the WebSocket client of `tendermint-rpc` cannot decode them.
 pub struct EventMonitor {
     chain_id: ChainId,
     /// WebSocket to collect events from
@@ -95,6 +100,8 @@ pub struct EventMonitor {
     rx_cmd: channel::Receiver<MonitorCmd>,
     /// Node Address
     node_addr: Url,
+    /// How the node encodes the event attributes
+    attribute_encoding: AttributeEncoding,
     /// Queries
     event_queries: Vec<Query>,
     /// All subscriptions combined in a single stream
@@ -151,6 +158,7 @@ impl EventMonitor {
     pub fn new(
         chain_id: ChainId,
         node_addr: Url,
+        attribute_encoding: AttributeEncoding,
         rt: Arc<TokioRuntime>,
     ) -> Result<(Self, EventReceiver, TxMonitorCmd)> {
         let (tx_batch, rx_batch) = channel::unbounded();
@@ -178,6 +186,7 @@ impl EventMonitor {
             tx_err,
             rx_cmd,
             node_addr,
+            attribute_encoding,
             subscriptions: Box::new(futures::stream::empty()),
         };
 
@@ -192,6 +201,23 @@ impl EventMonitor {
     /// Clear the current subscriptions, and subscribe again to all queries.
     #[instrument(name = "event_monitor.subscribe", skip_all, fields(chain = %self.chain_id))]
     pub fn subscribe(&mut self) -> Result<()> {
+        if self.attribute_encoding == AttributeEncoding::Plain {
+            trace!("subscribing to all queries over a separate connection");
+
+            self.subscriptions = self
+                .rt
+                .block_on(compat::subscribe(
+                    &self.node_addr,
+                    &self.event_queries,
+                    self.attribute_encoding,
+                ))
+                .map_err(Error::client_subscription_failed)?;
+
+            trace!("subscribed to all queries");
+
+            return Ok(());
+        }
+
         let mut subscriptions = vec![];
 
         for query in &self.event_queries {
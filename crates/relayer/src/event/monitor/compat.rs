@@ -0,0 +1,196 @@
+//! Subscriptions to the events of CometBFT 0.37 and 0.38 nodes.
+//!
+//! Since CometBFT 0.37, the keys and values of the event attributes are plain strings,
+//! which the WebSocket client of `tendermint-rpc` fails to decode, and then silently
+//! drops the event. These subscriptions are therefore made over a WebSocket connection
+//! of their own, on which the attributes of each event are encoded in base64 before
+//! the event is decoded.
+
+use async_tungstenite::tokio::connect_async;
+use async_tungstenite::tungstenite::{Error as WebSocketError, Message};
+use futures::{future, stream, SinkExt, StreamExt};
+use serde_json::{json, Value};
+use tendermint_rpc::{event::Event as RpcEvent, query::Query, Error as RpcError, Url};
+
+use crate::chain::cosmos::query::results::AttributeEncoding;
+
+use super::{SubscriptionResult, SubscriptionStream};
+
+/// Connect to the WebSocket endpoint at the given address, subscribe to
+/// the given queries, and return the events matching any of them.
+///
+/// The stream ends with an error once the connection is closed.
+pub async fn subscribe(
+    addr: &Url,
+    queries: &[Query],
+    encoding: AttributeEncoding,
+) -> Result<Box<SubscriptionStream>, RpcError> {
+    let (mut socket, _response) = connect_async(addr.to_string())
+        .await
+        .map_err(RpcError::tungstenite)?;
+
+    for (id, query) in queries.iter().enumerate() {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": id.to_string(),
+            "method": "subscribe",
+            "params": { "query": query.to_string() },
+        });
+
+        socket
+            .send(Message::Text(request.to_string()))
+            .await
+            .map_err(RpcError::tungstenite)?;
+    }
+
+    let closed = stream::once(future::ready(Err(RpcError::client_internal(format!(
+        "WebSocket connection to {addr} was closed"
+    )))));
+
+    let events = socket
+        .filter_map(move |message| future::ready(decode_message(message, encoding)))
+        .chain(closed);
+
+    Ok(Box::new(Box::pin(events)))
+}
+
+/// Decode the event carried by a message received from the node, if any.
+fn decode_message(
+    message: Result<Message, WebSocketError>,
+    encoding: AttributeEncoding,
+) -> Option<SubscriptionResult> {
+    match message {
+        Ok(Message::Text(text)) => decode_event(&text, encoding).transpose(),
+        Ok(_) => None,
+        Err(e) => Some(Err(RpcError::tungstenite(e))),
+    }
+}
+
+/// Decode the event carried by a JSON-RPC message, skipping the responses
+/// to the subscription requests, which carry no event.
+fn decode_event(text: &str, encoding: AttributeEncoding) -> Result<Option<RpcEvent>, RpcError> {
+    let mut message: Value = serde_json::from_str(text).map_err(RpcError::serde)?;
+
+    if let Some(error) = message.get("error") {
+        return Err(RpcError::server(error.to_string()));
+    }
+
+    let mut result = match message.get_mut("result") {
+        Some(result) if result.get("data").is_some() => result.take(),
+        _ => return Ok(None),
+    };
+
+    encoding.normalize(&mut result);
+
+    serde_json::from_value(result)
+        .map(Some)
+        .map_err(RpcError::serde)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tendermint_rpc::event::EventData;
+
+    #[test]
+    fn decode_plain_tx_event() {
+        let message = json!({
+            "jsonrpc": "2.0",
+            "id": "3",
+            "result": {
+                "query": "message.module = 'ibc_channel'",
+                "data": {
+                    "type": "tendermint/event/Tx",
+                    "value": {
+                        "TxResult": {
+                            "height": "42",
+                            "index": 0,
+                            "tx": "AA==",
+                            "result": {
+                                "events": [{
+                                    "type": "send_packet",
+                                    "attributes": [
+                                        { "key": "packet_sequence", "value": "1", "index": true }
+                                    ]
+                                }]
+                            }
+                        }
+                    }
+                },
+                "events": { "tx.height": ["42"] }
+            }
+        });
+
+        let event = decode_event(&message.to_string(), AttributeEncoding::Plain)
+            .unwrap()
+            .unwrap();
+
+        match event.data {
+            EventData::Tx { tx_result } => {
+                assert_eq!(tx_result.height, 42);
+
+                let attribute = &tx_result.result.events[0].attributes[0];
+                assert_eq!(attribute.key.as_ref(), "packet_sequence");
+                assert_eq!(attribute.value.as_ref(), "1");
+            }
+            data => panic!("unexpected event data: {data:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_finalize_block_event() {
+        let message = json!({
+            "jsonrpc": "2.0",
+            "id": "0",
+            "result": {
+                "query": "tm.event = 'NewBlock'",
+                "data": {
+                    "type": "tendermint/event/NewBlock",
+                    "value": {
+                        "block": null,
+                        "block_id": { "hash": "", "parts": { "total": 0, "hash": "" } },
+                        "result_finalize_block": {
+                            "events": [{
+                                "type": "send_packet",
+                                "attributes": [
+                                    { "key": "mode", "value": "EndBlock", "index": true }
+                                ]
+                            }],
+                            "tx_results": [],
+                            "validator_updates": [],
+                            "app_hash": ""
+                        }
+                    }
+                },
+                "events": { "send_packet.mode": ["EndBlock"] }
+            }
+        });
+
+        let event = decode_event(&message.to_string(), AttributeEncoding::Plain)
+            .unwrap()
+            .unwrap();
+
+        assert!(matches!(event.data, EventData::NewBlock { .. }));
+        assert_eq!(event.events.unwrap()["send_packet.mode"], ["EndBlock"]);
+    }
+
+    #[test]
+    fn skip_subscription_responses() {
+        let response = json!({ "jsonrpc": "2.0", "id": "0", "result": {} });
+        let event = decode_event(&response.to_string(), AttributeEncoding::Plain).unwrap();
+        assert!(event.is_none());
+
+        let cancelled = json!({
+            "jsonrpc": "2.0",
+            "id": "0",
+            "error": {
+                "code": -32000,
+                "message": "Server error",
+                "data": "subscription was cancelled"
+            }
+        });
+        let error = decode_event(&cancelled.to_string(), AttributeEncoding::Plain).unwrap_err();
+        assert!(error.to_string().contains("subscription was cancelled"));
+    }
+}
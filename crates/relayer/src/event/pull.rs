@@ -0,0 +1,350 @@
+//! An alternative to the [`EventMonitor`](super::monitor::EventMonitor) for nodes whose
+//! WebSocket endpoint is throttled or disabled, which pulls the events of every new block
+//! from the RPC endpoint instead of subscribing to them.
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::fs;
+use std::path::PathBuf;
+
+use crossbeam_channel as channel;
+use serde::{Deserialize, Serialize};
+use tendermint::block::Height as TmHeight;
+use tendermint_rpc::HttpClient;
+use tokio::runtime::Runtime as TokioRuntime;
+use tracing::{debug, error, instrument, trace, warn};
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::chain::cosmos::query::results::{block_heights, block_results, AttributeEncoding};
+use crate::chain::tracking::TrackingId;
+use crate::event::monitor::{
+    Error, EventBatch, EventReceiver, MonitorCmd, Next, Result, TxMonitorCmd,
+};
+use crate::event::rpc::get_all_events_from_block_results;
+
+/// How many times the events of a block are pulled before giving up on that block.
+const MAX_BLOCK_RETRIES: u32 = 5;
+
+/// Polls the node for new blocks every `interval`, and sends the IBC events of each
+/// new block as an [`EventBatch`], in the same form as the event monitor does.
+///
+/// Every block is processed in turn from the first block seen onwards, so that
+/// no events are missed when the node cannot be reached for a while. A block whose
+/// events still cannot be pulled after [`MAX_BLOCK_RETRIES`] attempts is skipped.
+///
+/// If a cursor file is given, the height of the last block processed is persisted
+/// in it, so that a restarted monitor resumes from the block following that one.
+pub struct PullEventMonitor {
+    chain_id: ChainId,
+    /// Client to fetch the blocks from
+    rpc_client: HttpClient,
+    /// How the node encodes the event attributes
+    attribute_encoding: AttributeEncoding,
+    /// How often to check for new blocks
+    interval: Duration,
+    /// Channel to handler where the monitor for this chain sends the events
+    tx_batch: channel::Sender<Result<EventBatch>>,
+    /// Channel where to receive commands
+    rx_cmd: channel::Receiver<MonitorCmd>,
+    /// The height of the last block whose events were sent
+    last_height: Option<TmHeight>,
+    /// How many times pulling the block following `last_height` has failed
+    failures: u32,
+    /// Where `last_height` is persisted, if anywhere
+    cursor: Option<Cursor>,
+    /// Tokio runtime
+    rt: Arc<TokioRuntime>,
+}
+
+impl PullEventMonitor {
+    pub fn new(
+        chain_id: ChainId,
+        rpc_client: HttpClient,
+        attribute_encoding: AttributeEncoding,
+        interval: Duration,
+        cursor_file: Option<PathBuf>,
+        rt: Arc<TokioRuntime>,
+    ) -> (Self, EventReceiver, TxMonitorCmd) {
+        let (tx_batch, rx_batch) = channel::unbounded();
+        let (tx_cmd, rx_cmd) = channel::unbounded();
+
+        let cursor = cursor_file.map(Cursor::new);
+        let last_height = cursor.as_ref().and_then(Cursor::load);
+
+        if let Some(last_height) = last_height {
+            debug!(
+                chain = %chain_id,
+                %last_height,
+                "resuming pulling events after the last block processed"
+            );
+        }
+
+        let monitor = Self {
+            chain_id,
+            rpc_client,
+            attribute_encoding,
+            interval,
+            tx_batch,
+            rx_cmd,
+            last_height,
+            failures: 0,
+            cursor,
+            rt,
+        };
+
+        (monitor, rx_batch, tx_cmd)
+    }
+
+    #[instrument(
+        name = "pull_event_monitor",
+        level = "error",
+        skip_all,
+        fields(chain = %self.chain_id)
+    )]
+    pub fn run(mut self) {
+        debug!(interval = ?self.interval, "starting pull event monitor");
+
+        loop {
+            if let Next::Abort = self.pull_new_blocks() {
+                break;
+            }
+
+            match self.rx_cmd.recv_timeout(self.interval) {
+                Ok(MonitorCmd::Shutdown) | Err(channel::RecvTimeoutError::Disconnected) => break,
+                Err(channel::RecvTimeoutError::Timeout) => {}
+            }
+        }
+
+        debug!("pull event monitor is shutting down");
+    }
+
+    /// Send the events of all the blocks committed since the last block processed,
+    /// and persist the height of the last block processed, if it changed.
+    fn pull_new_blocks(&mut self) -> Next {
+        let last_height = self.last_height;
+        let next = self.pull_blocks();
+
+        if let (Some(cursor), Some(height)) = (&self.cursor, self.last_height) {
+            if self.last_height != last_height {
+                cursor.save(height);
+            }
+        }
+
+        next
+    }
+
+    fn pull_blocks(&mut self) -> Next {
+        let heights = match self.rt.block_on(block_heights(&self.rpc_client)) {
+            Ok(heights) => heights,
+            Err(e) => {
+                error!("failed to query the latest height: {}", e);
+                return Next::Continue;
+            }
+        };
+
+        let latest_height = heights.latest_block_height;
+        let mut height = start_height(
+            self.last_height,
+            heights.earliest_block_height,
+            latest_height,
+        );
+
+        if let Some(last_height) = self.last_height {
+            if height > last_height.increment() {
+                warn!(
+                    earliest_height = %height,
+                    "skipping blocks {} to {} which were pruned by the node, \
+                    their IBC events will only be relayed by packet clearing",
+                    last_height.increment(),
+                    height.value() - 1,
+                );
+            }
+        }
+
+        while height <= latest_height {
+            if let Ok(MonitorCmd::Shutdown) = self.rx_cmd.try_recv() {
+                return Next::Abort;
+            }
+
+            // On failure, the same block is tried again after the next interval,
+            // until it has failed too many times, in which case it is skipped
+            if let Err(e) = self.pull_block(height) {
+                self.failures += 1;
+
+                if self.failures < MAX_BLOCK_RETRIES {
+                    error!(
+                        %height,
+                        attempt = self.failures,
+                        "failed to pull the events of block: {}",
+                        e
+                    );
+                    return Next::Continue;
+                }
+
+                warn!(
+                    %height,
+                    "skipping block after failing to pull its events {} times, \
+                    its IBC events will only be relayed by packet clearing: {}",
+                    self.failures,
+                    e
+                );
+            }
+
+            self.failures = 0;
+            self.last_height = Some(height);
+            height = height.increment();
+        }
+
+        Next::Continue
+    }
+
+    fn pull_block(&self, height: TmHeight) -> Result<()> {
+        trace!(%height, "pulling the events of block");
+
+        let block_results = self
+            .rt
+            .block_on(block_results(
+                &self.rpc_client,
+                self.attribute_encoding,
+                height,
+            ))
+            .map_err(Error::rpc)?;
+
+        let events = get_all_events_from_block_results(&self.chain_id, &block_results)
+            .map_err(Error::collect_events_failed)?;
+
+        // The events include at least the `NewBlock` event
+        let height = events[0].height;
+
+        let batch = EventBatch {
+            chain_id: self.chain_id.clone(),
+            tracking_id: TrackingId::new_uuid(),
+            height,
+            events,
+        };
+
+        self.tx_batch
+            .send(Ok(batch))
+            .map_err(|_| Error::channel_send_failed())
+    }
+}
+
+/// The height of the first block to pull: the block following the last block processed,
+/// or the latest block if none was processed yet. Blocks which were already pruned by the
+/// node, ie. below its earliest block, cannot be pulled anymore and are skipped.
+fn start_height(
+    last_height: Option<TmHeight>,
+    earliest_height: TmHeight,
+    latest_height: TmHeight,
+) -> TmHeight {
+    match last_height {
+        Some(last_height) => last_height.increment().max(earliest_height),
+        None => latest_height,
+    }
+}
+
+/// The file in which the height of the last block processed is persisted.
+struct Cursor {
+    file: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CursorState {
+    last_height: TmHeight,
+}
+
+impl Cursor {
+    fn new(file: PathBuf) -> Self {
+        Self { file }
+    }
+
+    fn load(&self) -> Option<TmHeight> {
+        if !self.file.exists() {
+            return None;
+        }
+
+        let state = fs::read_to_string(&self.file)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| {
+                serde_json::from_str::<CursorState>(&contents).map_err(|e| e.to_string())
+            });
+
+        match state {
+            Ok(state) => Some(state.last_height),
+            Err(e) => {
+                warn!(
+                    file = %self.file.display(),
+                    "ignoring invalid event cursor, pulling events from the latest block: {}", e
+                );
+                None
+            }
+        }
+    }
+
+    /// Failures are only logged, since they only cause blocks to be pulled again after a restart.
+    fn save(&self, last_height: TmHeight) {
+        if let Err(e) = self.write(&CursorState { last_height }) {
+            warn!(file = %self.file.display(), "failed to persist event cursor: {}", e);
+        }
+    }
+
+    fn write(&self, state: &CursorState) -> std::io::Result<()> {
+        let contents = serde_json::to_string(state)?;
+
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temporary file first so that a crash
+        // cannot leave behind a partially written cursor.
+        let tmp_file = self.file.with_extension("json.tmp");
+
+        fs::write(&tmp_file, contents)?;
+        fs::rename(&tmp_file, &self.file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    #[test]
+    fn persist_and_reload_cursor() {
+        let dir = TempDir::new().unwrap();
+        let cursor = Cursor::new(dir.path().join("ibc-0").join("pull_events.json"));
+
+        assert_eq!(cursor.load(), None);
+
+        cursor.save(TmHeight::from(42_u32));
+        assert_eq!(cursor.load(), Some(TmHeight::from(42_u32)));
+
+        let reloaded = Cursor::new(cursor.file.clone());
+        assert_eq!(reloaded.load(), Some(TmHeight::from(42_u32)));
+
+        fs::write(&cursor.file, "not json").unwrap();
+        assert_eq!(cursor.load(), None);
+    }
+
+    #[test]
+    fn start_height_skips_pruned_blocks() {
+        let height = |h: u32| TmHeight::from(h);
+
+        // Nothing processed yet, start from the latest block
+        assert_eq!(start_height(None, height(10), height(100)), height(100));
+
+        // Resume after the last block processed
+        assert_eq!(
+            start_height(Some(height(50)), height(10), height(100)),
+            height(51)
+        );
+
+        // The blocks following the last block processed were pruned
+        assert_eq!(
+            start_height(Some(height(5)), height(10), height(100)),
+            height(10)
+        );
+    }
+}
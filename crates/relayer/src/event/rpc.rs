@@ -9,6 +9,7 @@ use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 use ibc_relayer_types::applications::ics31_icq::events::CrossChainQueryPacket;
 use ibc_relayer_types::events::IbcEvent;
 
+use crate::chain::cosmos::query::results::BlockResults;
 use crate::chain::cosmos::types::events::channel::RawObject;
 use crate::event::monitor::queries;
 
@@ -188,6 +189,59 @@ pub fn get_all_events(
     Ok(events_with_height)
 }
 
+/// Extract the IBC events of a block from the results of its execution, as returned by
+/// the `/block_results` RPC endpoint, in the same form as the events received over
+/// a WebSocket subscription to the queries of [`queries::all`].
+///
+/// The [`NewBlock`](ClientEvents::NewBlock) event comes first, followed by the events
+/// in the order they were emitted: in `BeginBlock`, by the transactions of the block,
+/// and in `EndBlock`, which CometBFT 0.38 reports together as `FinalizeBlock` events.
+pub fn get_all_events_from_block_results(
+    chain_id: &ChainId,
+    block_results: &BlockResults,
+) -> Result<Vec<IbcEventWithHeight>, String> {
+    let height = Height::new(
+        ChainId::chain_version(chain_id.to_string().as_str()),
+        u64::from(block_results.height),
+    )
+    .map_err(|_| String::from("block_results.height: invalid header height of 0"))?;
+
+    let mut events_with_height = vec![IbcEventWithHeight::new(
+        ClientEvents::NewBlock::new(height).into(),
+        height,
+    )];
+
+    // The events of failed transactions are not committed
+    let tx_events = block_results
+        .txs_results
+        .iter()
+        .flatten()
+        .filter(|tx_result| tx_result.code.is_ok())
+        .flat_map(|tx_result| tx_result.events.iter());
+
+    let begin_block_events = block_results.begin_block_events();
+    let end_block_events = block_results.end_block_events();
+
+    for abci_event in begin_block_events
+        .iter()
+        .chain(tx_events)
+        .chain(end_block_events.iter())
+    {
+        if let Ok(ibc_event) = ibc_event_try_from_abci_event(abci_event) {
+            if event_is_type_client(&ibc_event)
+                || event_is_type_connection(&ibc_event)
+                || event_is_type_channel(&ibc_event)
+                || event_is_type_cross_chain_query(&ibc_event)
+            {
+                tracing::trace!("extracted {}", ibc_event);
+                events_with_height.push(IbcEventWithHeight::new(ibc_event, height));
+            }
+        }
+    }
+
+    Ok(events_with_height)
+}
+
 fn event_is_type_client(ev: &IbcEvent) -> bool {
     matches!(
         ev,
@@ -217,6 +271,15 @@ fn event_is_type_channel(ev: &IbcEvent) -> bool {
             | IbcEvent::OpenConfirmChannel(_)
             | IbcEvent::CloseInitChannel(_)
             | IbcEvent::CloseConfirmChannel(_)
+            | IbcEvent::UpgradeInitChannel(_)
+            | IbcEvent::UpgradeTryChannel(_)
+            | IbcEvent::UpgradeAckChannel(_)
+            | IbcEvent::UpgradeConfirmChannel(_)
+            | IbcEvent::UpgradeOpenChannel(_)
+            | IbcEvent::UpgradeTimeoutChannel(_)
+            | IbcEvent::UpgradeCancelChannel(_)
+            | IbcEvent::UpgradeErrorChannel(_)
+            | IbcEvent::FlushCompleteChannel(_)
             | IbcEvent::SendPacket(_)
             | IbcEvent::ReceivePacket(_)
             | IbcEvent::WriteAcknowledgement(_)
@@ -303,6 +366,12 @@ fn extract_block_events(
         extract_events(height, block_events, "channel_close_confirm", "channel_id"),
         height,
     );
+    // Upgrades initiated through a governance proposal are emitted at end block.
+    append_events::<ChannelEvents::UpgradeInit>(
+        &mut events,
+        extract_events(height, block_events, "channel_upgrade_init", "channel_id"),
+        height,
+    );
     if let Ok(ccq) = CrossChainQueryPacket::extract_query_event(block_events) {
         events.push(IbcEventWithHeight::new(ccq, height));
     }
@@ -4,6 +4,8 @@
 //! i.e. they are *foreign* to the relayer. In contrast, the term "local client"
 //! refers to light clients running *locally* as part of the relayer.
 
+pub mod solomachine;
+
 use core::{fmt, time::Duration};
 use std::thread;
 use std::time::Instant;
@@ -13,7 +15,11 @@ use itertools::Itertools;
 use tracing::{debug, error, info, instrument, trace, warn};
 
 use flex_error::define_error;
+use ibc_relayer_types::clients::ics08_wasm::client_state::ClientState as WasmClientState;
+use ibc_relayer_types::clients::ics08_wasm::consensus_state::ConsensusState as WasmConsensusState;
+use ibc_relayer_types::clients::ics08_wasm::header::Header as WasmHeader;
 use ibc_relayer_types::core::ics02_client::client_state::ClientState;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error as ClientError;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
 use ibc_relayer_types::core::ics02_client::header::Header;
@@ -33,8 +39,9 @@ use crate::chain::client::ClientSettings;
 use crate::chain::handle::ChainHandle;
 use crate::chain::requests::{
     IncludeProof, PageRequest, QueryClientEventRequest, QueryClientStateRequest,
-    QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight, QueryTxRequest,
-    QueryUpgradedClientStateRequest, QueryUpgradedConsensusStateRequest,
+    QueryConsensusStateRequest, QueryConsensusStatesRequest, QueryHeight,
+    QueryHostConsensusStateRequest, QueryTxRequest, QueryUpgradedClientStateRequest,
+    QueryUpgradedConsensusStateRequest,
 };
 use crate::chain::tracking::TrackedMsgs;
 use crate::client_state::AnyClientState;
@@ -42,7 +49,7 @@ use crate::consensus_state::{AnyConsensusState, AnyConsensusStateWithHeight};
 use crate::error::Error as RelayerError;
 use crate::event::IbcEventWithHeight;
 use crate::light_client::AnyHeader;
-use crate::misbehaviour::MisbehaviourEvidence;
+use crate::misbehaviour::{AnyMisbehaviour, ConflictingHeaderEvidence, MisbehaviourEvidence};
 use crate::telemetry;
 use crate::util::collate::CollatedIterExt;
 use crate::util::pretty::{PrettyDuration, PrettySlice};
@@ -215,6 +222,17 @@ define_error! {
                     e.client_id, e.chain_id, e.event)
             },
 
+        MismatchClientType
+            {
+                client_id: ClientId,
+                expected_client_type: ClientType,
+                actual_client_type: ClientType,
+            }
+            |e| {
+                format_args!("client {0} has type {1}, expected a client of type {2}",
+                    e.client_id, e.actual_client_type, e.expected_client_type)
+            },
+
         MismatchChainId
             {
                 client_id: ClientId,
@@ -596,6 +614,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 e,
             )
         })?;
+        let wasm_checksum = dst_config.wasm_checksum.clone();
         let settings = ClientSettings::for_create_command(options, &src_config, &dst_config);
 
         let client_state: AnyClientState = self
@@ -624,6 +643,19 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 )
             })?;
 
+        // Wrap the client in an `08-wasm` client if the destination chain hosts it in a contract
+        let (client_state, consensus_state) = match (wasm_checksum, client_state, consensus_state) {
+            (
+                Some(checksum),
+                AnyClientState::Tendermint(client_state),
+                AnyConsensusState::Tendermint(consensus_state),
+            ) => (
+                WasmClientState::new(checksum.into(), client_state).into(),
+                WasmConsensusState::new(consensus_state).into(),
+            ),
+            (_, client_state, consensus_state) => (client_state, consensus_state),
+        };
+
         //TODO Get acct_prefix
         let msg = MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
             .map_err(ForeignClientError::client)?;
@@ -1150,6 +1182,15 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         self.wait_for_header_validation_delay(&client_state, &header)?;
 
+        let (header, support) = if let AnyClientState::Wasm(_) = client_state {
+            (
+                wrap_wasm_header(header),
+                support.into_iter().map(wrap_wasm_header).collect(),
+            )
+        } else {
+            (header, support)
+        };
+
         let mut msgs = vec![];
 
         for header in support {
@@ -1483,6 +1524,11 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                 })?
         };
 
+        if let AnyClientState::Wasm(_) = client_state {
+            debug!("skipping misbehaviour detection for a client hosted by a Wasm contract");
+            return Ok(None);
+        }
+
         let consensus_state_heights = if let Some(event) = update {
             vec![event.consensus_height()]
         } else {
@@ -1616,6 +1662,31 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
     fn submit_evidence(
         &self,
         evidence: MisbehaviourEvidence,
+    ) -> Result<Vec<IbcEvent>, ForeignClientError> {
+        self.submit_evidence_msgs(evidence.supporting_headers, Some(evidence.misbehaviour))
+    }
+
+    #[instrument(
+        name = "foreign_client.submit_conflicting_header",
+        level = "error",
+        skip(self),
+        fields(client = %self)
+    )]
+    fn submit_conflicting_header(
+        &self,
+        evidence: ConflictingHeaderEvidence,
+    ) -> Result<Vec<IbcEvent>, ForeignClientError> {
+        let mut headers = evidence.supporting_headers;
+        headers.push(evidence.header);
+
+        self.submit_evidence_msgs(headers, None)
+    }
+
+    /// Submits the given headers as client updates, followed by the given misbehaviour, if any.
+    fn submit_evidence_msgs(
+        &self,
+        headers: Vec<AnyHeader>,
+        misbehaviour: Option<AnyMisbehaviour>,
     ) -> Result<Vec<IbcEvent>, ForeignClientError> {
         let signer = self.dst_chain().get_signer().map_err(|e| {
             ForeignClientError::misbehaviour(
@@ -1629,7 +1700,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
 
         let mut msgs = vec![];
 
-        for header in evidence.supporting_headers {
+        for header in headers {
             msgs.push(
                 MsgUpdateClient {
                     header: header.into(),
@@ -1640,14 +1711,16 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
             );
         }
 
-        msgs.push(
-            MsgSubmitMisbehaviour {
-                misbehaviour: evidence.misbehaviour.into(),
-                client_id: self.id.clone(),
-                signer,
-            }
-            .to_any(),
-        );
+        if let Some(misbehaviour) = misbehaviour {
+            msgs.push(
+                MsgSubmitMisbehaviour {
+                    misbehaviour: misbehaviour.into(),
+                    client_id: self.id.clone(),
+                    signer,
+                }
+                .to_any(),
+            );
+        }
 
         let tm = TrackedMsgs::new_static(msgs, "evidence");
 
@@ -1667,6 +1740,134 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         Ok(events.into_iter().map(|ev| ev.event).collect())
     }
 
+    /// Scans all the consensus states of the client for misbehaviour, including the ones
+    /// installed by client updates that were not observed by the relayer, e.g. because
+    /// they happened while it was not running or because their events were pruned.
+    ///
+    /// Every consensus state of the client is compared with the consensus state of the
+    /// source chain at the same height. For the lowest consensus state which conflicts with
+    /// the source chain, the header of the source chain at that height is built from the
+    /// closest lower consensus state of the client which matches the source chain. Since
+    /// the client already holds a different consensus state at the height of that header,
+    /// updating the client with it freezes the client.
+    pub fn scan_misbehaviour(
+        &self,
+    ) -> Result<Option<ConflictingHeaderEvidence>, ForeignClientError> {
+        let (client_state, _) = self
+            .dst_chain()
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::misbehaviour(
+                    format!("failed querying client state on dst chain {}", self.id),
+                    e,
+                )
+            })?;
+
+        if client_state.is_frozen() {
+            return Err(ForeignClientError::expired_or_frozen(
+                self.id().clone(),
+                self.dst_chain.id(),
+                "client state reports that client is frozen".into(),
+            ));
+        }
+
+        let mut consensus_states = self.fetch_consensus_states()?;
+        consensus_states.reverse();
+
+        debug!(
+            total = %consensus_states.len(),
+            "scanning consensus states for misbehaviour",
+        );
+
+        let start_time = Instant::now();
+
+        let scanned = consensus_states.into_iter().map(|consensus_state| {
+            let height = consensus_state.height;
+
+            let matches = match self.src_chain.query_host_consensus_state(
+                QueryHostConsensusStateRequest {
+                    height: QueryHeight::Specific(height),
+                },
+            ) {
+                Ok(src_consensus_state) => {
+                    Some(src_consensus_state.matches(&consensus_state.consensus_state))
+                }
+                Err(e) => {
+                    // The source chain may have pruned the block at that height
+                    debug!(
+                        target = %height,
+                        "cannot compare consensus state, failed to query the source chain at that height: {}", e
+                    );
+                    None
+                }
+            };
+
+            (height, matches)
+        });
+
+        for (trusted_height, target_height) in conflicting_heights(scanned) {
+            warn!(
+                target = %target_height,
+                trusted = %trusted_height,
+                "consensus state of the client does not match the source chain"
+            );
+
+            let (header, supporting_headers) = match self.src_chain.build_header(
+                trusted_height,
+                target_height,
+                client_state.clone(),
+            ) {
+                Ok(headers) => headers,
+
+                Err(e) if e.is_trusted_state_outside_trusting_period_error() => {
+                    debug!(
+                        target = %target_height,
+                        trusted = %trusted_height,
+                        "cannot build the header of the source chain from a consensus state outside of the trusting period"
+                    );
+
+                    continue;
+                }
+
+                Err(e) => {
+                    return Err(ForeignClientError::misbehaviour(
+                        format!(
+                            "failed to build the header of the source chain for {} at height {}",
+                            self.id, target_height,
+                        ),
+                        e,
+                    ))
+                }
+            };
+
+            debug!(
+                "finished scanning for misbehaviour after {:?}",
+                start_time.elapsed()
+            );
+
+            return Ok(Some(ConflictingHeaderEvidence {
+                client_id: self.id.clone(),
+                conflicting_height: target_height,
+                trusted_height,
+                header,
+                supporting_headers,
+            }));
+        }
+
+        debug!(
+            "finished scanning for misbehaviour after {:?}",
+            start_time.elapsed()
+        );
+
+        Ok(None)
+    }
+
     #[instrument(
         name = "foreign_client.detect_misbehaviour_and_submit_evidence",
         level = "error",
@@ -1678,12 +1879,43 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
         update_event: Option<&UpdateClient>,
     ) -> MisbehaviourResults {
         // check evidence of misbehaviour for all updates or one
-        let result = match self.detect_misbehaviour(update_event) {
+        let detected = self.detect_misbehaviour(update_event);
+
+        self.submit_detected_evidence(
+            detected,
+            |evidence| self.submit_evidence(evidence),
+            update_event.is_some(),
+        )
+    }
+
+    #[instrument(
+        name = "foreign_client.scan_misbehaviour_and_submit_evidence",
+        level = "error",
+        skip(self),
+        fields(client = %self)
+    )]
+    pub fn scan_misbehaviour_and_submit_evidence(&self) -> MisbehaviourResults {
+        let detected = self.scan_misbehaviour();
+
+        self.submit_detected_evidence(
+            detected,
+            |evidence| self.submit_conflicting_header(evidence),
+            false,
+        )
+    }
+
+    fn submit_detected_evidence<Evidence: fmt::Display>(
+        &self,
+        detected: Result<Option<Evidence>, ForeignClientError>,
+        submit: impl FnOnce(Evidence) -> Result<Vec<IbcEvent>, ForeignClientError>,
+        single_update: bool,
+    ) -> MisbehaviourResults {
+        let result = match detected {
             Err(e) => Err(e),
             Ok(None) => Ok(vec![]), // no evidence found
             Ok(Some(detected)) => {
                 error!(
-                    misbehaviour = %detected.misbehaviour,
+                    misbehaviour = %detected,
                     "misbehaviour detected, sending evidence"
                 );
 
@@ -1695,7 +1927,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                     1
                 );
 
-                self.submit_evidence(detected)
+                submit(detected)
             }
         };
 
@@ -1740,7 +1972,7 @@ impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcCh
                     MisbehaviourResults::CannotExecute
                 }
 
-                _ if update_event.is_some() => MisbehaviourResults::CannotExecute,
+                _ if single_update => MisbehaviourResults::CannotExecute,
 
                 _ => {
                     warn!("misbehaviour checking result: {}", e);
@@ -1772,6 +2004,49 @@ pub enum MisbehaviourResults {
     VerificationError,
 }
 
+/// Given the heights of the consensus states of a client in ascending order, along with
+/// whether each matches the consensus state of the source chain at that height, if known,
+/// returns the heights of the consensus states which conflict with the source chain, each
+/// paired with the height of the closest lower consensus state which matches it.
+///
+/// Conflicting consensus states below the lowest matching one are skipped, since there is
+/// no consensus state of the client from which the header of the source chain can be verified.
+fn conflicting_heights(
+    scanned: impl IntoIterator<Item = (Height, Option<bool>)>,
+) -> impl Iterator<Item = (Height, Height)> {
+    scanned
+        .into_iter()
+        .scan(None, |trusted, (height, matches)| {
+            let conflict = match matches {
+                Some(true) => {
+                    *trusted = Some(height);
+                    None
+                }
+                Some(false) if trusted.is_none() => {
+                    error!(
+                        target = %height,
+                        "consensus state of the client conflicts with the source chain, \
+                        but no lower consensus state matches the source chain to submit evidence of misbehaviour"
+                    );
+                    None
+                }
+                Some(false) => trusted.map(|trusted| (trusted, height)),
+                None => None,
+            };
+
+            Some(conflict)
+        })
+        .flatten()
+}
+
+/// Wraps a Tendermint header for a client hosted by a Wasm contract.
+fn wrap_wasm_header(header: AnyHeader) -> AnyHeader {
+    match header {
+        AnyHeader::Tendermint(header) => WasmHeader::new(header).into(),
+        header => header,
+    }
+}
+
 pub fn extract_client_id(event: &IbcEvent) -> Result<&ClientId, ForeignClientError> {
     match event {
         IbcEvent::CreateClient(ev) => Ok(ev.client_id()),
@@ -1781,3 +2056,47 @@ pub fn extract_client_id(event: &IbcEvent) -> Result<&ClientId, ForeignClientErr
         )),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn height(h: u64) -> Height {
+        Height::new(0, h).unwrap()
+    }
+
+    #[test]
+    fn conflicting_heights_are_paired_with_closest_matching_height() {
+        let scanned = vec![
+            (height(5), Some(false)),
+            (height(10), Some(true)),
+            (height(20), None),
+            (height(30), Some(false)),
+            (height(40), Some(true)),
+            (height(50), Some(false)),
+            (height(60), Some(false)),
+        ];
+
+        let conflicts = conflicting_heights(scanned).collect::<Vec<_>>();
+
+        assert_eq!(
+            conflicts,
+            vec![
+                (height(10), height(30)),
+                (height(40), height(50)),
+                (height(40), height(60)),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_conflicting_heights_when_consensus_states_match() {
+        let scanned = vec![
+            (height(10), Some(true)),
+            (height(20), None),
+            (height(30), Some(true)),
+        ];
+
+        assert_eq!(conflicting_heights(scanned).count(), 0);
+    }
+}
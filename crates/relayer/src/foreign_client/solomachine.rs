@@ -0,0 +1,269 @@
+//! Creation and update of solo machine clients.
+//!
+//! A solo machine is a standalone process, e.g. an off-chain service, which is
+//! represented on a chain by a `06-solomachine` client. Instead of verifying
+//! headers against a validator set, such a client verifies signatures made with
+//! the key of the solo machine. Here, that key is held in the Hermes keyring,
+//! or by the remote signer of the keyring.
+//!
+//! Solo machine clients are handled by [`ForeignClient`], whose source chain is
+//! then never queried, as the headers of the client are signed by the solo machine.
+//! Since a solo machine client state records no chain identifier, its diversifier
+//! stands in for it, eg. when [`ForeignClient::find`] checks the source chain of
+//! the client, which must then be identified by the diversifier.
+
+use ibc_proto::google::protobuf::Any;
+use tracing::info;
+
+use ibc_relayer_types::clients::ics06_solomachine::client_state::ClientState as SmClientState;
+use ibc_relayer_types::clients::ics06_solomachine::consensus_state::ConsensusState as SmConsensusState;
+use ibc_relayer_types::clients::ics06_solomachine::header::Header as SmHeader;
+use ibc_relayer_types::clients::ics06_solomachine::Version as SmVersion;
+use ibc_relayer_types::core::ics02_client::client_type::ClientType;
+use ibc_relayer_types::core::ics02_client::msgs::create_client::MsgCreateClient;
+use ibc_relayer_types::core::ics02_client::msgs::update_client::MsgUpdateClient;
+use ibc_relayer_types::core::ics24_host::identifier::ClientId;
+use ibc_relayer_types::timestamp::Timestamp;
+use ibc_relayer_types::tx_msg::Msg;
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::requests::{IncludeProof, QueryClientStateRequest, QueryHeight};
+use crate::chain::tracking::TrackedMsgs;
+use crate::client_state::AnyClientState;
+use crate::config::{AddressType, ChainConfig};
+use crate::error::Error as RelayerError;
+use crate::event::IbcEventWithHeight;
+use crate::keyring::{self, errors::Error as KeyringError, KeyRing, KeySigner};
+
+use super::{extract_client_id, ForeignClient, ForeignClientError};
+
+/// The type URL of the secp256k1 public keys of the keyring.
+const SECP256K1_PUBKEY_TYPE_URL: &str = "/cosmos.crypto.secp256k1.PubKey";
+
+/// The signing key and the diversifier of a solo machine.
+#[derive(Clone, Debug)]
+pub struct SoloMachine {
+    key: KeySigner,
+    diversifier: String,
+}
+
+impl SoloMachine {
+    pub fn new(key: KeySigner, diversifier: String) -> Self {
+        Self { key, diversifier }
+    }
+
+    /// Load the key with the given name from the keyring of the given chain.
+    pub fn from_keyring(
+        config: &ChainConfig,
+        key_name: &str,
+        diversifier: String,
+    ) -> Result<Self, KeyringError> {
+        let key = KeyRing::from_chain_config(config)?.get_key_signer(key_name)?;
+
+        Ok(Self::new(key, diversifier))
+    }
+
+    pub fn diversifier(&self) -> &str {
+        &self.diversifier
+    }
+
+    /// The public key of the solo machine, as a `/cosmos.crypto.secp256k1.PubKey`.
+    pub fn public_key(&self) -> Any {
+        Any {
+            type_url: SECP256K1_PUBKEY_TYPE_URL.to_string(),
+            value: prost::Message::encode_to_vec(&self.key.public_key().to_pub().to_bytes()),
+        }
+    }
+
+    pub fn sign(&self, bytes: Vec<u8>) -> Result<Vec<u8>, KeyringError> {
+        match &self.key {
+            KeySigner::Local(key) => keyring::sign_message(key, bytes, &AddressType::Cosmos),
+            KeySigner::Remote(key) => key.sign(&bytes, &AddressType::Cosmos),
+        }
+    }
+}
+
+impl<DstChain: ChainHandle, SrcChain: ChainHandle> ForeignClient<DstChain, SrcChain> {
+    /// Creates a new solo machine client on `dst_chain`, for the given solo machine.
+    ///
+    /// The client is encoded in the given version of the solo machine client,
+    /// which must be the one of the ibc-go version run by `dst_chain`.
+    pub fn new_solomachine(
+        dst_chain: DstChain,
+        src_chain: SrcChain,
+        solo_machine: &SoloMachine,
+        version: SmVersion,
+    ) -> Result<Self, ForeignClientError> {
+        let mut client = ForeignClient {
+            id: ClientId::default(),
+            dst_chain,
+            src_chain,
+        };
+
+        let event_with_height =
+            client.build_create_solomachine_client_and_send(solo_machine, version)?;
+        client.id = extract_client_id(&event_with_height.event)?.clone();
+
+        info!(id = %client.id, "🍭 solo machine client was created successfully");
+
+        Ok(client)
+    }
+
+    /// Lower-level interface for preparing a message to create a solo machine client.
+    pub fn build_create_solomachine_client(
+        &self,
+        solo_machine: &SoloMachine,
+        version: SmVersion,
+    ) -> Result<MsgCreateClient, ForeignClientError> {
+        let signer = self.dst_chain.get_signer().map_err(|e| {
+            ForeignClientError::client_create(
+                self.dst_chain.id(),
+                "failed while fetching the dst chain signer".to_string(),
+                e,
+            )
+        })?;
+
+        let consensus_state = SmConsensusState::new(
+            version,
+            solo_machine.public_key(),
+            solo_machine.diversifier().to_string(),
+            Timestamp::now().nanoseconds(),
+        );
+
+        let client_state = SmClientState::new(1, consensus_state.clone())
+            .map_err(|e| ForeignClientError::client(e.into()))?;
+
+        MsgCreateClient::new(client_state.into(), consensus_state.into(), signer)
+            .map_err(ForeignClientError::client)
+    }
+
+    /// Returns the event emitted by the creation of the solo machine client.
+    pub fn build_create_solomachine_client_and_send(
+        &self,
+        solo_machine: &SoloMachine,
+        version: SmVersion,
+    ) -> Result<IbcEventWithHeight, ForeignClientError> {
+        let new_msg = self.build_create_solomachine_client(solo_machine, version)?;
+
+        let res = self
+            .dst_chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(
+                new_msg.to_any(),
+                "create solo machine client",
+            ))
+            .map_err(|e| {
+                ForeignClientError::client_create(
+                    self.dst_chain.id(),
+                    "failed sending message to dst chain".to_string(),
+                    e,
+                )
+            })?;
+
+        assert!(!res.is_empty());
+        Ok(res[0].clone())
+    }
+
+    /// Queries the latest state of this client, which must be a solo machine client.
+    pub fn solomachine_client_state(&self) -> Result<SmClientState, ForeignClientError> {
+        let (client_state, _) = self
+            .dst_chain
+            .query_client_state(
+                QueryClientStateRequest {
+                    client_id: self.id.clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| {
+                ForeignClientError::client_query(self.id.clone(), self.dst_chain.id(), e)
+            })?;
+
+        match client_state {
+            AnyClientState::Solomachine(client_state) => Ok(client_state),
+            client_state => Err(ForeignClientError::mismatch_client_type(
+                self.id.clone(),
+                ClientType::Solomachine,
+                client_state.client_type(),
+            )),
+        }
+    }
+
+    /// Lower-level interface for preparing a message to update the solo machine client.
+    ///
+    /// The header is signed by the `current` key of the solo machine, and rotates
+    /// the client to the key and diversifier of `next`, which may be the same.
+    /// It is encoded in the same version as the client state.
+    pub fn build_update_solomachine_client(
+        &self,
+        current: &SoloMachine,
+        next: &SoloMachine,
+    ) -> Result<MsgUpdateClient, ForeignClientError> {
+        let client_state = self.solomachine_client_state()?;
+
+        if client_state.is_frozen {
+            return Err(ForeignClientError::expired_or_frozen(
+                self.id.clone(),
+                self.dst_chain.id(),
+                "the solo machine client is frozen".to_string(),
+            ));
+        }
+
+        let signer = self.dst_chain.get_signer().map_err(|e| {
+            ForeignClientError::client_update(
+                self.dst_chain.id(),
+                "failed while fetching the dst chain signer".to_string(),
+                e,
+            )
+        })?;
+
+        // The timestamp of the header must not be older than the one of the consensus state
+        let timestamp = Timestamp::now()
+            .nanoseconds()
+            .max(client_state.consensus_state.timestamp);
+
+        let header = SmHeader {
+            version: client_state.version(),
+            sequence: client_state.sequence,
+            timestamp,
+            signature: vec![],
+            new_public_key: next.public_key(),
+            new_diversifier: next.diversifier().to_string(),
+        };
+
+        let sign_bytes = header.sign_bytes(&client_state.consensus_state.diversifier);
+
+        let signature = current.sign(sign_bytes).map_err(|e| {
+            ForeignClientError::client_update(
+                self.dst_chain.id(),
+                "failed while signing the solo machine header".to_string(),
+                RelayerError::key_base(e),
+            )
+        })?;
+
+        let header = header.with_signature(signature);
+
+        Ok(MsgUpdateClient::new(self.id.clone(), header.into(), signer))
+    }
+
+    /// Updates the solo machine client, returning the events emitted by the update.
+    pub fn build_update_solomachine_client_and_send(
+        &self,
+        current: &SoloMachine,
+        next: &SoloMachine,
+    ) -> Result<Vec<IbcEventWithHeight>, ForeignClientError> {
+        let new_msg = self.build_update_solomachine_client(current, next)?;
+
+        self.dst_chain
+            .send_messages_and_wait_commit(TrackedMsgs::new_single(
+                new_msg.to_any(),
+                "update solo machine client",
+            ))
+            .map_err(|e| {
+                ForeignClientError::client_update(
+                    self.dst_chain.id(),
+                    "failed sending message to dst chain".to_string(),
+                    e,
+                )
+            })
+    }
+}
@@ -18,18 +18,23 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use tiny_keccak::{Hasher, Keccak};
 
-use crate::config::AddressType;
+use crate::config::{AddressType, ChainConfig};
 
+pub use encrypted::{unlock, Encrypted};
 use errors::Error;
 pub use pub_key::EncodedPubKey;
+pub use remote::{Remote, RemoteKey};
 
+pub mod encrypted;
 pub mod errors;
 mod pub_key;
+pub mod remote;
 
 pub type HDPath = StandardHDPath;
 
 pub const KEYSTORE_DEFAULT_FOLDER: &str = ".hermes/keys/";
 pub const KEYSTORE_DISK_BACKEND: &str = "keyring-test";
+pub const KEYSTORE_ENCRYPTED_BACKEND: &str = "keyring-encrypted";
 pub const KEYSTORE_FILE_EXTENSION: &str = "json";
 
 // /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\ /!\
@@ -52,6 +57,54 @@ pub struct KeyEntry {
     pub address: Vec<u8>,
 }
 
+/// The key with which the transactions of a chain are signed, which is either
+/// held in the keyring, or held by a remote signer exposing only its public key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum KeySigner {
+    Local(KeyEntry),
+    Remote(RemoteKey),
+}
+
+impl KeySigner {
+    pub fn public_key(&self) -> &ExtendedPubKey {
+        match self {
+            KeySigner::Local(key) => &key.public_key,
+            KeySigner::Remote(key) => &key.public_key,
+        }
+    }
+
+    pub fn account(&self) -> &str {
+        match self {
+            KeySigner::Local(key) => &key.account,
+            KeySigner::Remote(key) => &key.account,
+        }
+    }
+
+    pub fn address(&self) -> &[u8] {
+        match self {
+            KeySigner::Local(key) => &key.address,
+            KeySigner::Remote(key) => &key.address,
+        }
+    }
+
+    /// Sign a message. The request to the remote signer is made on
+    /// the blocking thread pool, so as not to stall the async runtime.
+    pub async fn sign(&self, msg: Vec<u8>, address_type: &AddressType) -> Result<Vec<u8>, Error> {
+        match self {
+            KeySigner::Local(key) => sign_message(key, msg, address_type),
+            KeySigner::Remote(key) => {
+                let key = key.clone();
+                let address_type = address_type.clone();
+
+                tokio::task::spawn_blocking(move || key.sign(&msg, &address_type))
+                    .await
+                    .map_err(Error::remote_signer_task)?
+            }
+        }
+    }
+}
+
 /// JSON key seed file
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct KeyFile {
@@ -246,6 +299,8 @@ impl KeyStore for Test {
 pub enum Store {
     Memory,
     Test,
+    Encrypted,
+    Remote,
 }
 
 impl Default for Store {
@@ -258,6 +313,8 @@ impl Default for Store {
 pub enum KeyRing {
     Memory(Memory),
     Test(Test),
+    Encrypted(Encrypted),
+    Remote(Remote),
 }
 
 impl KeyRing {
@@ -266,22 +323,42 @@ impl KeyRing {
             Store::Memory => Ok(Self::Memory(Memory::new(account_prefix.to_string()))),
 
             Store::Test => {
-                let keys_folder = disk_store_path(chain_id.as_str())?;
-
-                // Create keys folder if it does not exist
-                fs::create_dir_all(&keys_folder).map_err(|e| {
-                    Error::key_file_io(
-                        keys_folder.display().to_string(),
-                        "failed to create keys folder".to_string(),
-                        e,
-                    )
-                })?;
+                let keys_folder = disk_store_path(chain_id.as_str(), KEYSTORE_DISK_BACKEND)?;
+                create_keys_folder(&keys_folder)?;
 
                 Ok(Self::Test(Test::new(
                     account_prefix.to_string(),
                     keys_folder,
                 )))
             }
+
+            Store::Encrypted => {
+                // Fail early if the passphrase is not available
+                unlock()?;
+
+                let keys_folder = disk_store_path(chain_id.as_str(), KEYSTORE_ENCRYPTED_BACKEND)?;
+                create_keys_folder(&keys_folder)?;
+
+                Ok(Self::Encrypted(Encrypted::new(
+                    account_prefix.to_string(),
+                    keys_folder,
+                )))
+            }
+
+            // The remote signer configuration is only available from the chain configuration
+            Store::Remote => Err(Error::remote_signer_not_configured()),
+        }
+    }
+
+    /// Open the keyring of the given chain, according to its key store type.
+    pub fn from_chain_config(config: &ChainConfig) -> Result<Self, Error> {
+        match (config.key_store_type, &config.remote_signer) {
+            (Store::Remote, Some(remote_signer)) => Ok(Self::Remote(Remote::new(
+                config.account_prefix.clone(),
+                config.address_type.clone(),
+                remote_signer.clone(),
+            ))),
+            (store, _) => Self::new(store, &config.account_prefix, &config.id),
         }
     }
 
@@ -289,6 +366,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.get_key(key_name),
             KeyRing::Test(d) => d.get_key(key_name),
+            KeyRing::Encrypted(e) => e.get_key(key_name),
+            KeyRing::Remote(_) => Err(Error::remote_key_entry()),
         }
     }
 
@@ -296,6 +375,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.add_key(key_name, key_entry),
             KeyRing::Test(d) => d.add_key(key_name, key_entry),
+            KeyRing::Encrypted(e) => e.add_key(key_name, key_entry),
+            KeyRing::Remote(_) => Err(Error::remote_key_management()),
         }
     }
 
@@ -303,6 +384,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.remove_key(key_name),
             KeyRing::Test(d) => d.remove_key(key_name),
+            KeyRing::Encrypted(e) => e.remove_key(key_name),
+            KeyRing::Remote(_) => Err(Error::remote_key_management()),
         }
     }
 
@@ -310,9 +393,37 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => m.keys(),
             KeyRing::Test(d) => d.keys(),
+            KeyRing::Encrypted(e) => e.keys(),
+            KeyRing::Remote(_) => Err(Error::remote_key_entry()),
+        }
+    }
+
+    /// Get the signer of the given key, which is either the key itself,
+    /// or a reference to the key held by the remote signer.
+    pub fn get_key_signer(&self, key_name: &str) -> Result<KeySigner, Error> {
+        match self {
+            KeyRing::Remote(r) => r.get_key(key_name).map(KeySigner::Remote),
+            _ => self.get_key(key_name).map(KeySigner::Local),
         }
     }
 
+    pub fn key_signers(&self) -> Result<Vec<(String, KeySigner)>, Error> {
+        let signers = match self {
+            KeyRing::Remote(r) => r
+                .keys()?
+                .into_iter()
+                .map(|(name, key)| (name, KeySigner::Remote(key)))
+                .collect(),
+            _ => self
+                .keys()?
+                .into_iter()
+                .map(|(name, key)| (name, KeySigner::Local(key)))
+                .collect(),
+        };
+
+        Ok(signers)
+    }
+
     /// Get key from seed file
     pub fn key_from_seed_file(
         &self,
@@ -368,6 +479,8 @@ impl KeyRing {
         match self {
             KeyRing::Memory(m) => &m.account_prefix,
             KeyRing::Test(d) => &d.account_prefix,
+            KeyRing::Encrypted(e) => &e.account_prefix,
+            KeyRing::Remote(r) => &r.account_prefix,
         }
     }
 }
@@ -464,17 +577,28 @@ fn decode_bech32(input: &str) -> Result<Vec<u8>, Error> {
     Ok(bytes)
 }
 
-fn disk_store_path(folder_name: &str) -> Result<PathBuf, Error> {
+fn disk_store_path(folder_name: &str, backend: &str) -> Result<PathBuf, Error> {
     let home = dirs_next::home_dir().ok_or_else(Error::home_location_unavailable)?;
 
     let folder = Path::new(home.as_path())
         .join(KEYSTORE_DEFAULT_FOLDER)
         .join(folder_name)
-        .join(KEYSTORE_DISK_BACKEND);
+        .join(backend);
 
     Ok(folder)
 }
 
+/// Create the keys folder if it does not exist
+fn create_keys_folder(keys_folder: &Path) -> Result<(), Error> {
+    fs::create_dir_all(keys_folder).map_err(|e| {
+        Error::key_file_io(
+            keys_folder.display().to_string(),
+            "failed to create keys folder".to_string(),
+            e,
+        )
+    })
+}
+
 fn keccak256_hash(bytes: &[u8]) -> Vec<u8> {
     let mut hasher = Keccak::v256();
     hasher.update(bytes);
@@ -0,0 +1,339 @@
+//! A key store keeping the key entries encrypted at rest with a passphrase.
+//!
+//! The encryption key is derived from the passphrase with Argon2id, using a random salt
+//! for every key file, and the key entries are encrypted with XChaCha20-Poly1305.
+//!
+//! The passphrase is read once per process, either from the `HERMES_KEYRING_PASSPHRASE`
+//! environment variable, or from the file descriptor whose number is given by the
+//! `HERMES_KEYRING_PASSPHRASE_FD` environment variable.
+
+use core::fmt::{Debug, Error as FmtError, Formatter};
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs::{self, OpenOptions};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::errors::Error;
+use super::{KeyEntry, KeyStore, KEYSTORE_FILE_EXTENSION};
+
+/// Environment variable holding the passphrase of the encrypted key store.
+pub const PASSPHRASE_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE";
+
+/// Environment variable holding the number of a file descriptor
+/// from which to read the passphrase of the encrypted key store.
+pub const PASSPHRASE_FD_ENV_VAR: &str = "HERMES_KEYRING_PASSPHRASE_FD";
+
+const KDF_ALGORITHM: &str = "argon2id";
+const CIPHER: &str = "xchacha20poly1305";
+const SALT_LEN: usize = 16;
+
+static PASSPHRASE: OnceCell<Passphrase> = OnceCell::new();
+
+/// The passphrase of the encrypted key store, wiped from memory when dropped,
+/// along with the encryption keys derived from it.
+#[derive(Clone)]
+pub struct Passphrase {
+    passphrase: Zeroizing<String>,
+    /// The keys derived so far, by derivation parameters. Since every key file has
+    /// its own salt, this derives the key of a key file once, instead of every time
+    /// the key file is opened.
+    derived_keys: Arc<Mutex<HashMap<KdfParams, Zeroizing<[u8; 32]>>>>,
+}
+
+impl Passphrase {
+    pub fn new(passphrase: String) -> Self {
+        Self {
+            passphrase: Zeroizing::new(passphrase),
+            derived_keys: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Derive the encryption key from the passphrase with the given parameters,
+    /// or return the key derived previously with the same parameters.
+    fn derive_key(&self, kdf: &KdfParams, salt: &[u8]) -> Result<Key, Error> {
+        let mut derived_keys = self.derived_keys.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(key) = derived_keys.get(kdf) {
+            return Ok(Key::clone_from_slice(key.as_slice()));
+        }
+
+        let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32))
+            .map_err(Error::key_derivation)?;
+
+        let mut key = Zeroizing::new([0u8; 32]);
+
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(self.passphrase.as_bytes(), salt, key.as_mut())
+            .map_err(Error::key_derivation)?;
+
+        let derived = Key::clone_from_slice(key.as_slice());
+        derived_keys.insert(kdf.clone(), key);
+
+        Ok(derived)
+    }
+}
+
+impl Debug for Passphrase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "Passphrase(..)")
+    }
+}
+
+/// Read the passphrase of the encrypted key store, if not done already.
+///
+/// This is called when opening an encrypted key store, but should be called
+/// as early as possible by long-running commands, so that a missing or invalid
+/// passphrase is reported before any work is started.
+pub fn unlock() -> Result<&'static Passphrase, Error> {
+    PASSPHRASE.get_or_try_init(read_passphrase)
+}
+
+fn read_passphrase() -> Result<Passphrase, Error> {
+    let passphrase = if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV_VAR) {
+        passphrase
+    } else if let Ok(fd) = std::env::var(PASSPHRASE_FD_ENV_VAR) {
+        let fd: u32 = fd
+            .trim()
+            .parse()
+            .map_err(|_| Error::invalid_passphrase_fd(fd.clone()))?;
+
+        let passphrase = fs::read_to_string(format!("/dev/fd/{}", fd))
+            .map_err(|e| Error::passphrase_fd_io(fd.to_string(), e))?;
+
+        passphrase.trim_end_matches(&['\r', '\n'][..]).to_string()
+    } else {
+        return Err(Error::passphrase_unavailable());
+    };
+
+    if passphrase.is_empty() {
+        return Err(Error::empty_passphrase());
+    }
+
+    Ok(Passphrase::new(passphrase))
+}
+
+/// Parameters of the Argon2id derivation of the
+/// encryption key from the passphrase.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+    salt: String,
+}
+
+/// An encrypted key file, holding a JSON-encoded [`KeyEntry`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct EncryptedKeyFile {
+    kdf: KdfParams,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+impl EncryptedKeyFile {
+    fn seal(key_entry: &KeyEntry, passphrase: &Passphrase) -> Result<Self, Error> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let kdf = KdfParams {
+            algorithm: KDF_ALGORITHM.to_string(),
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+            salt: hex::encode(salt),
+        };
+
+        let cipher = XChaCha20Poly1305::new(&passphrase.derive_key(&kdf, &salt)?);
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+
+        let plaintext = Zeroizing::new(serde_json::to_vec(key_entry).map_err(Error::encode)?);
+
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| Error::key_encryption())?;
+
+        Ok(Self {
+            kdf,
+            cipher: CIPHER.to_string(),
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    fn open(&self, passphrase: &Passphrase, file_path: &str) -> Result<KeyEntry, Error> {
+        if self.kdf.algorithm != KDF_ALGORITHM || self.cipher != CIPHER {
+            return Err(Error::unsupported_key_encryption(
+                file_path.to_string(),
+                format!("{}/{}", self.kdf.algorithm, self.cipher),
+            ));
+        }
+
+        let decode = |field: &str| {
+            hex::decode(field).map_err(|e| Error::key_file_hex(file_path.to_string(), e))
+        };
+
+        let salt = decode(&self.kdf.salt)?;
+        let nonce = decode(&self.nonce)?;
+        let ciphertext = decode(&self.ciphertext)?;
+
+        if nonce.len() != 24 {
+            return Err(Error::key_decryption(file_path.to_string()));
+        }
+
+        let cipher = XChaCha20Poly1305::new(&passphrase.derive_key(&self.kdf, &salt)?);
+
+        let plaintext = cipher
+            .decrypt(XNonce::from_slice(&nonce), ciphertext.as_slice())
+            .map(Zeroizing::new)
+            .map_err(|_| Error::key_decryption(file_path.to_string()))?;
+
+        serde_json::from_slice(&plaintext)
+            .map_err(|e| Error::key_file_decode(file_path.to_string(), e))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Encrypted {
+    pub(super) account_prefix: String,
+    store: PathBuf,
+}
+
+impl Encrypted {
+    pub fn new(account_prefix: String, store: PathBuf) -> Self {
+        Self {
+            account_prefix,
+            store,
+        }
+    }
+
+    fn key_file_path(&self, key_name: &str) -> PathBuf {
+        let mut filename = self.store.join(key_name);
+        filename.set_extension(KEYSTORE_FILE_EXTENSION);
+        filename
+    }
+}
+
+impl KeyStore for Encrypted {
+    fn get_key(&self, key_name: &str) -> Result<KeyEntry, Error> {
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        if !key_file.as_path().exists() {
+            return Err(Error::key_file_not_found(file_path));
+        }
+
+        let contents = fs::read_to_string(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to read file".to_string(), e)
+        })?;
+
+        let encrypted: EncryptedKeyFile = serde_json::from_str(&contents)
+            .map_err(|e| Error::key_file_decode(file_path.clone(), e))?;
+
+        encrypted.open(unlock()?, &file_path)
+    }
+
+    fn add_key(&mut self, key_name: &str, key_entry: KeyEntry) -> Result<(), Error> {
+        let key_file = self.key_file_path(key_name);
+        let file_path = key_file.display().to_string();
+
+        let encrypted = EncryptedKeyFile::seal(&key_entry, unlock()?)?;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        // Only let the owner read the key file
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let file = options.open(&key_file).map_err(|e| {
+            Error::key_file_io(file_path.clone(), "failed to create file".to_string(), e)
+        })?;
+
+        serde_json::to_writer_pretty(file, &encrypted)
+            .map_err(|e| Error::key_file_encode(file_path, e))?;
+
+        Ok(())
+    }
+
+    fn remove_key(&mut self, key_name: &str) -> Result<(), Error> {
+        let key_file = self.key_file_path(key_name);
+
+        fs::remove_file(&key_file)
+            .map_err(|e| Error::remove_io_fail(key_file.display().to_string(), e))?;
+
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<(String, KeyEntry)>, Error> {
+        let dir = fs::read_dir(&self.store).map_err(|e| {
+            Error::key_file_io(
+                self.store.display().to_string(),
+                "failed to list keys".to_string(),
+                e,
+            )
+        })?;
+
+        let ext = OsStr::new(KEYSTORE_FILE_EXTENSION);
+
+        dir.into_iter()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension() == Some(ext))
+            .flat_map(|path| path.file_stem().map(OsStr::to_owned))
+            .flat_map(|stem| stem.to_str().map(ToString::to_string))
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::keyring::{KeyRing, Store};
+
+    fn key_entry() -> KeyEntry {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer-seed.json"
+        );
+        let seed_file_content = fs::read_to_string(path).unwrap();
+
+        let chain_id = "chain_A".parse().unwrap();
+        let keyring = KeyRing::new(Store::Memory, "cosmos", &chain_id).unwrap();
+        let hd_path = "m/44'/118'/0'/0/0".parse().unwrap();
+
+        keyring
+            .key_from_seed_file(&seed_file_content, &hd_path)
+            .unwrap()
+    }
+
+    #[test]
+    fn seal_and_open_key_file() {
+        let key_entry = key_entry();
+        let passphrase = Passphrase::new("correct horse battery staple".to_string());
+
+        let sealed = EncryptedKeyFile::seal(&key_entry, &passphrase).unwrap();
+        assert!(!sealed.ciphertext.contains(&key_entry.account));
+
+        let opened = sealed.open(&passphrase, "test.json").unwrap();
+        assert_eq!(opened, key_entry);
+
+        // The key derived when sealing the key file is reused when opening it
+        assert_eq!(passphrase.derived_keys.lock().unwrap().len(), 1);
+
+        let wrong = Passphrase::new("wrong horse battery staple".to_string());
+        assert!(sealed.open(&wrong, "test.json").is_err());
+    }
+}
@@ -112,5 +112,92 @@ define_error! {
             |e| {
                 format!("invalid HD path: {0}", e.path)
             },
+
+        PassphraseUnavailable
+            |_| {
+                format!("the passphrase of the encrypted keyring is unavailable, please set either the '{}' or the '{}' environment variable",
+                    super::encrypted::PASSPHRASE_ENV_VAR, super::encrypted::PASSPHRASE_FD_ENV_VAR)
+            },
+
+        EmptyPassphrase
+            |_| { "the passphrase of the encrypted keyring is empty" },
+
+        InvalidPassphraseFd
+            { fd: String }
+            |e| {
+                format!("invalid file descriptor '{}' for the passphrase of the encrypted keyring",
+                    e.fd)
+            },
+
+        PassphraseFdIo
+            { fd: String }
+            [ TraceError<IoError> ]
+            |e| {
+                format!("failed to read the passphrase of the encrypted keyring from file descriptor {}",
+                    e.fd)
+            },
+
+        KeyDerivation
+            [ DisplayOnly<argon2::Error> ]
+            |_| { "failed to derive the encryption key from the passphrase" },
+
+        KeyEncryption
+            |_| { "failed to encrypt key" },
+
+        KeyDecryption
+            { file_path: String }
+            |e| {
+                format!("failed to decrypt key file at '{}', the passphrase is wrong or the file is corrupted",
+                    e.file_path)
+            },
+
+        UnsupportedKeyEncryption
+            {
+                file_path: String,
+                scheme: String,
+            }
+            |e| {
+                format!("unsupported encryption scheme '{}' for key file at '{}'",
+                    e.scheme, e.file_path)
+            },
+
+        RemoteSignerNotConfigured
+            |_| { "the key store type is 'Remote' but no remote signer is configured, please set the `remote_signer` option of the chain" },
+
+        RemoteSigner
+            { url: String }
+            [ DisplayOnly<ureq::Error> ]
+            |e| {
+                format!("request to remote signer at '{}' failed",
+                    e.url)
+            },
+
+        RemoteSignerResponse
+            {
+                url: String,
+                reason: String,
+            }
+            |e| {
+                format!("invalid response from remote signer at '{}': {}",
+                    e.url, e.reason)
+            },
+
+        RemoteKeyManagement
+            |_| { "the keys of a remote signer can only be managed on the signer itself" },
+
+        RemoteKeyEntry
+            |_| { "the private keys of a remote signer cannot be retrieved, only their public keys" },
+
+        RemoteSignerTask
+            [ DisplayOnly<tokio::task::JoinError> ]
+            |_| { "the request to the remote signer was aborted" },
+
+        KeyFileHex
+            { file_path: String }
+            [ TraceError<hex::FromHexError> ]
+            |e| {
+                format!("invalid hex encoding in key file at '{}'",
+                    e.file_path)
+            },
     }
 }
@@ -0,0 +1,347 @@
+//! A key store delegating signing to a remote signing service,
+//! so that the private keys never leave the signing host.
+//!
+//! The signing service is reached over HTTP(S) and must expose the following endpoints,
+//! where keys are identified by the key name configured for the chain:
+//!
+//! - `GET <url>/keys` returns the identifiers of the available keys:
+//!   `{ "keys": ["<key id>", ...] }`
+//! - `GET <url>/keys/<key id>` returns the hex-encoded, compressed secp256k1
+//!   public key of a key: `{ "pub_key": "<hex>" }`
+//! - `POST <url>/keys/<key id>/sign` with body `{ "sign_bytes": "<hex>", "algorithm": "<algorithm>" }`
+//!   returns the hex-encoded signature of the given bytes: `{ "signature": "<hex>" }`.
+//!   The algorithm is either `secp256k1`, for a 64-byte compact ECDSA signature over the
+//!   SHA-256 hash of the bytes, or `eth_secp256k1`, for a 64-byte compact recoverable ECDSA
+//!   signature, without its recovery id, over their Keccak-256 hash.
+
+use alloc::collections::btree_map::BTreeMap as HashMap;
+use core::time::Duration;
+use std::sync::{Arc, RwLock};
+
+use bech32::{ToBase32, Variant};
+use bitcoin::network::constants::Network;
+use bitcoin::secp256k1::PublicKey;
+use bitcoin::util::bip32::{ChainCode, ChildNumber, ExtendedPubKey, Fingerprint};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AddressType, RemoteSignerConfig};
+
+use super::errors::Error;
+use super::get_address;
+
+const ALGORITHM_SECP256K1: &str = "secp256k1";
+const ALGORITHM_ETH_SECP256K1: &str = "eth_secp256k1";
+
+/// A key held by a remote signer, along with its public key and address.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteKey {
+    pub public_key: ExtendedPubKey,
+    pub account: String,
+    pub address: Vec<u8>,
+    pub url: String,
+    pub key_id: String,
+    #[serde(with = "humantime_serde")]
+    pub timeout: Duration,
+}
+
+#[derive(Deserialize)]
+struct KeysResponse {
+    keys: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct PubKeyResponse {
+    pub_key: String,
+}
+
+#[derive(Serialize)]
+struct SignRequest<'a> {
+    sign_bytes: String,
+    algorithm: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SignResponse {
+    signature: String,
+}
+
+impl RemoteKey {
+    /// Sign the given bytes with this key, using the signature algorithm
+    /// corresponding to the given address type.
+    ///
+    /// This blocks until the remote signer responds, use [`KeySigner::sign`]
+    /// to sign from async code.
+    ///
+    /// [`KeySigner::sign`]: super::KeySigner::sign
+    pub fn sign(&self, msg: &[u8], address_type: &AddressType) -> Result<Vec<u8>, Error> {
+        let algorithm = match address_type {
+            AddressType::Ethermint { ref pk_type } if pk_type.ends_with(".ethsecp256k1.PubKey") => {
+                ALGORITHM_ETH_SECP256K1
+            }
+            AddressType::Cosmos | AddressType::Ethermint { .. } => ALGORITHM_SECP256K1,
+        };
+
+        let request = SignRequest {
+            sign_bytes: hex::encode(msg),
+            algorithm,
+        };
+
+        let url = format!("{}/keys/{}/sign", self.url, self.key_id);
+        let response: SignResponse = request_json(&url, self.timeout, Some(&request))?;
+
+        hex::decode(&response.signature)
+            .map_err(|e| Error::remote_signer_response(url, e.to_string()))
+    }
+}
+
+/// A key store whose keys are held by a remote signer.
+///
+/// Keys cannot be added to nor removed from this store, as they are managed
+/// by the remote signer, and their private keys cannot be retrieved.
+/// Their public keys are fetched once and then cached.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Remote {
+    pub(super) account_prefix: String,
+    address_type: AddressType,
+    config: RemoteSignerConfig,
+    #[serde(skip)]
+    keys: Arc<RwLock<HashMap<String, RemoteKey>>>,
+}
+
+impl Remote {
+    pub fn new(
+        account_prefix: String,
+        address_type: AddressType,
+        config: RemoteSignerConfig,
+    ) -> Self {
+        Self {
+            account_prefix,
+            address_type,
+            config,
+            keys: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    fn url(&self) -> &str {
+        self.config.url.trim_end_matches('/')
+    }
+
+    fn fetch_key(&self, key_id: &str) -> Result<RemoteKey, Error> {
+        let url = format!("{}/keys/{}", self.url(), key_id);
+        let response: PubKeyResponse = request_json::<(), _>(&url, self.config.timeout, None)?;
+
+        let public_key = hex::decode(&response.pub_key)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| PublicKey::from_slice(&bytes).map_err(|e| e.to_string()))
+            .map_err(|e| Error::remote_signer_response(url, e))?;
+
+        // The remote signer only exposes the public key, hence there is
+        // no derivation information to include in the extended public key.
+        let public_key = ExtendedPubKey {
+            network: Network::Bitcoin,
+            depth: 0,
+            parent_fingerprint: Fingerprint::default(),
+            child_number: ChildNumber::from(0),
+            public_key,
+            chain_code: ChainCode::from(&[0; 32][..]),
+        };
+
+        let address = get_address(public_key, &self.address_type);
+
+        let account = bech32::encode(&self.account_prefix, address.to_base32(), Variant::Bech32)
+            .map_err(Error::bech32)?;
+
+        Ok(RemoteKey {
+            public_key,
+            account,
+            address,
+            url: self.url().to_string(),
+            key_id: key_id.to_string(),
+            timeout: self.config.timeout,
+        })
+    }
+
+    pub fn get_key(&self, key_name: &str) -> Result<RemoteKey, Error> {
+        if let Some(key) = self.keys.read().unwrap().get(key_name) {
+            return Ok(key.clone());
+        }
+
+        let key = self.fetch_key(key_name)?;
+
+        self.keys
+            .write()
+            .unwrap()
+            .insert(key_name.to_string(), key.clone());
+
+        Ok(key)
+    }
+
+    pub fn keys(&self) -> Result<Vec<(String, RemoteKey)>, Error> {
+        let url = format!("{}/keys", self.url());
+        let response: KeysResponse = request_json::<(), _>(&url, self.config.timeout, None)?;
+
+        response
+            .keys
+            .into_iter()
+            .map(|name| self.get_key(&name).map(|key| (name, key)))
+            .collect()
+    }
+}
+
+/// Send a request to the remote signer, as a `POST` request with the given
+/// JSON body if any, or as a `GET` request otherwise, and decode its JSON response.
+fn request_json<B: Serialize, T: DeserializeOwned>(
+    url: &str,
+    timeout: Duration,
+    body: Option<&B>,
+) -> Result<T, Error> {
+    let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+
+    let response = match body {
+        Some(body) => {
+            let body = serde_json::to_string(body).map_err(Error::encode)?;
+
+            agent
+                .post(url)
+                .set("Content-Type", "application/json")
+                .send_string(&body)
+        }
+        None => agent.get(url).call(),
+    }
+    .map_err(|e| Error::remote_signer(url.to_string(), e))?;
+
+    let response = response
+        .into_string()
+        .map_err(|e| Error::remote_signer_response(url.to_string(), e.to_string()))?;
+
+    serde_json::from_str(&response)
+        .map_err(|e| Error::remote_signer_response(url.to_string(), e.to_string()))
+}
+
+/// A stub of a remote signer, for testing.
+#[cfg(test)]
+pub(crate) mod stub {
+    use super::*;
+
+    use crate::keyring::{sign_message, KeyEntry, KeyRing, Store};
+
+    /// Spawns a stub of a remote signer holding the given local keys,
+    /// and returns the URL at which it listens.
+    pub fn spawn_stub_signer(keys: Vec<(&'static str, KeyEntry)>) -> String {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        std::thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let path = request.url().to_string();
+                let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+                let key = |key_id: &str| {
+                    keys.iter()
+                        .find(|(id, _)| *id == key_id)
+                        .map(|(_, key)| key)
+                };
+
+                let body = match segments.as_slice() {
+                    ["keys"] => {
+                        let key_ids: Vec<_> = keys.iter().map(|(id, _)| *id).collect();
+                        serde_json::json!({ "keys": key_ids })
+                    }
+                    ["keys", key_id] if key(key_id).is_some() => {
+                        let pub_key = key(key_id).unwrap().public_key.public_key.serialize();
+                        serde_json::json!({ "pub_key": hex::encode(pub_key) })
+                    }
+                    ["keys", key_id, "sign"] if key(key_id).is_some() => {
+                        let mut content = String::new();
+                        request.as_reader().read_to_string(&mut content).unwrap();
+
+                        let request_body: serde_json::Value =
+                            serde_json::from_str(&content).unwrap();
+                        let sign_bytes =
+                            hex::decode(request_body["sign_bytes"].as_str().unwrap()).unwrap();
+
+                        let signature =
+                            sign_message(key(key_id).unwrap(), sign_bytes, &AddressType::Cosmos)
+                                .unwrap();
+                        serde_json::json!({ "signature": hex::encode(signature) })
+                    }
+                    _ => {
+                        let _ = request.respond(tiny_http::Response::empty(404));
+                        continue;
+                    }
+                };
+
+                let _ = request.respond(tiny_http::Response::from_string(body.to_string()));
+            }
+        });
+
+        url
+    }
+
+    /// The key derived from the mnemonic of the relayer seed file
+    /// of the test fixtures, at the given address index of the HD path.
+    pub fn local_key(index: u32) -> KeyEntry {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer-seed.json"
+        );
+        let seed_file_content = std::fs::read_to_string(path).unwrap();
+        let seed_file: serde_json::Value = serde_json::from_str(&seed_file_content).unwrap();
+
+        let chain_id = "chain_A".parse().unwrap();
+        let keyring = KeyRing::new(Store::Memory, "cosmos", &chain_id).unwrap();
+        let hd_path = format!("m/44'/118'/0'/0/{}", index).parse().unwrap();
+
+        keyring
+            .key_from_mnemonic(
+                seed_file["mnemonic"].as_str().unwrap(),
+                &hd_path,
+                &AddressType::Cosmos,
+            )
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stub::{local_key, spawn_stub_signer};
+    use super::*;
+
+    use crate::keyring::{sign_message, KeySigner};
+
+    #[test]
+    fn sign_with_remote_signer() {
+        let local_key = local_key(0);
+        let url = spawn_stub_signer(vec![("relayer", local_key.clone())]);
+
+        let config = RemoteSignerConfig {
+            url,
+            timeout: Duration::from_secs(5),
+        };
+        let store = Remote::new("cosmos".to_string(), AddressType::Cosmos, config);
+
+        let remote_key = store.get_key("relayer").unwrap();
+        assert_eq!(
+            remote_key.public_key.public_key,
+            local_key.public_key.public_key
+        );
+        assert_eq!(remote_key.account, local_key.account);
+        assert_eq!(remote_key.address, local_key.address);
+
+        let keys = store.keys().unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].0, "relayer");
+
+        let msg = b"sign me".to_vec();
+        let local_signature = sign_message(&local_key, msg.clone(), &AddressType::Cosmos).unwrap();
+
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let remote_signature = rt
+            .block_on(KeySigner::Remote(remote_key).sign(msg, &AddressType::Cosmos))
+            .unwrap();
+        assert_eq!(remote_signature, local_signature);
+
+        assert!(store.get_key("unknown").is_err());
+    }
+}
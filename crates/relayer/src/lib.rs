@@ -40,6 +40,7 @@ pub mod macros;
 pub mod misbehaviour;
 pub mod object;
 pub mod path;
+pub mod recover_client;
 pub mod registry;
 pub mod rest;
 pub mod sdk_error;
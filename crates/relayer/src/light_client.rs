@@ -5,9 +5,13 @@ use core::ops::Deref;
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Header as RawTmHeader;
 use ibc_proto::protobuf::Protobuf as ErasedProtobuf;
+use ibc_relayer_types::clients::ics06_solomachine::header::{
+    Header as SoloMachineHeader, SOLOMACHINE_HEADER_TYPE_URL, SOLOMACHINE_V3_HEADER_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::header::{
     decode_header as tm_decode_header, Header as TendermintHeader, TENDERMINT_HEADER_TYPE_URL,
 };
+use ibc_relayer_types::clients::ics08_wasm::header::{Header as WasmHeader, WASM_HEADER_TYPE_URL};
 use ibc_relayer_types::core::ics02_client::client_type::ClientType;
 use ibc_relayer_types::core::ics02_client::error::Error;
 use ibc_relayer_types::core::ics02_client::events::UpdateClient;
@@ -66,37 +70,46 @@ pub trait LightClient<C: ChainEndpoint>: Send + Sync {
 
 /// Decodes an encoded header into a known `Header` type,
 pub fn decode_header(header_bytes: &[u8]) -> Result<Box<dyn Header>, Error> {
-    // For now, we only have tendermint; however when there is more than one, we
-    // can try decoding into all the known types, and return an error only if
-    // none work
-    let header: TendermintHeader =
+    let header: AnyHeader =
         ErasedProtobuf::<Any>::decode(header_bytes).map_err(Error::invalid_raw_header)?;
 
-    Ok(Box::new(header))
+    match header {
+        AnyHeader::Tendermint(header) => Ok(Box::new(header)),
+        AnyHeader::Solomachine(header) => Ok(Box::new(header)),
+        AnyHeader::Wasm(header) => Ok(Box::new(header)),
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyHeader {
     Tendermint(TendermintHeader),
+    Solomachine(SoloMachineHeader),
+    Wasm(WasmHeader),
 }
 
 impl Header for AnyHeader {
     fn client_type(&self) -> ClientType {
         match self {
             Self::Tendermint(header) => header.client_type(),
+            Self::Solomachine(header) => header.client_type(),
+            Self::Wasm(header) => header.client_type(),
         }
     }
 
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(header) => header.height(),
+            Self::Solomachine(header) => header.height(),
+            Self::Wasm(header) => header.height(),
         }
     }
 
     fn timestamp(&self) -> Timestamp {
         match self {
             Self::Tendermint(header) => header.timestamp(),
+            Self::Solomachine(header) => header.timestamp(),
+            Self::Wasm(header) => header.timestamp(),
         }
     }
 }
@@ -114,6 +127,12 @@ impl TryFrom<Any> for AnyHeader {
                 Ok(AnyHeader::Tendermint(val))
             }
 
+            SOLOMACHINE_HEADER_TYPE_URL | SOLOMACHINE_V3_HEADER_TYPE_URL => {
+                Ok(AnyHeader::Solomachine(raw.try_into()?))
+            }
+
+            WASM_HEADER_TYPE_URL => Ok(AnyHeader::Wasm(raw.try_into()?)),
+
             _ => Err(Error::unknown_header_type(raw.type_url)),
         }
     }
@@ -127,6 +146,8 @@ impl From<AnyHeader> for Any {
                 value: ErasedProtobuf::<RawTmHeader>::encode_vec(&header)
                     .expect("encoding to `Any` from `AnyHeader::Tendermint`"),
             },
+            AnyHeader::Solomachine(header) => header.into(),
+            AnyHeader::Wasm(header) => header.into(),
         }
     }
 }
@@ -136,3 +157,15 @@ impl From<TendermintHeader> for AnyHeader {
         Self::Tendermint(header)
     }
 }
+
+impl From<SoloMachineHeader> for AnyHeader {
+    fn from(header: SoloMachineHeader) -> Self {
+        Self::Solomachine(header)
+    }
+}
+
+impl From<WasmHeader> for AnyHeader {
+    fn from(header: WasmHeader) -> Self {
+        Self::Wasm(header)
+    }
+}
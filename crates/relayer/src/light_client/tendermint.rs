@@ -1,5 +1,12 @@
+pub mod store;
+
+use alloc::sync::Arc;
+use core::time::Duration;
+
 use itertools::Itertools;
+use tokio::runtime::Runtime as TokioRuntime;
 
+use tendermint::{Hash, Time};
 use tendermint_light_client::{
     components::{self, io::AtHeight},
     light_client::LightClient as TmLightClient,
@@ -14,6 +21,7 @@ use tendermint_rpc as rpc;
 
 use ibc_relayer_types::{
     clients::ics07_tendermint::{
+        client_state::ClientState as TmClientState,
         header::{headers_compatible, Header as TmHeader},
         misbehaviour::Misbehaviour as TmMisbehaviour,
     },
@@ -21,23 +29,88 @@ use ibc_relayer_types::{
         ics02_client::{client_type::ClientType, events::UpdateClient, header::downcast_header},
         ics24_host::identifier::ChainId,
     },
-    downcast, Height as ICSHeight,
+    Height as ICSHeight,
 };
-use tracing::trace;
+use tracing::{trace, warn};
 
 use crate::{
     chain::cosmos::CosmosSdkChain, client_state::AnyClientState, config::ChainConfig, error::Error,
     misbehaviour::MisbehaviourEvidence,
 };
 
+use self::store::{stored_bisecting_schedule, LightBlockStore};
 use super::Verified;
 
 pub struct LightClient {
     chain_id: ChainId,
+    primary: Provider,
+    witnesses: Vec<Witness>,
+    /// Whether witness nodes are configured, in which case every header must be
+    /// cross-checked against at least one of them, even if none could be reached
+    require_witness: bool,
+    /// The light blocks verified with the primary node and cross-checked against the witnesses
+    store: LightBlockStore,
+    rpc_timeout: Duration,
+    rt: Arc<TokioRuntime>,
+}
+
+/// A full node from which the light client fetches light blocks.
+#[derive(Clone)]
+struct Provider {
+    address: rpc::Url,
     peer_id: PeerId,
     io: components::io::ProdIo,
 }
 
+/// A witness node, which becomes a provider once its peer id is known.
+///
+/// The peer id is queried from the node the first time it is needed, and again
+/// on every later use for as long as the node cannot be reached.
+struct Witness {
+    address: rpc::Url,
+    provider: Option<Provider>,
+}
+
+impl Provider {
+    fn new(address: rpc::Url, peer_id: PeerId, rpc_timeout: Duration) -> Result<Self, Error> {
+        let rpc_client =
+            rpc::HttpClient::new(address.clone()).map_err(|e| Error::rpc(address.clone(), e))?;
+
+        let io = components::io::ProdIo::new(peer_id, rpc_client, Some(rpc_timeout));
+
+        Ok(Self {
+            address,
+            peer_id,
+            io,
+        })
+    }
+
+    /// Creates a provider for the node at the given address, querying its peer id.
+    fn resolve(rt: &TokioRuntime, address: rpc::Url, rpc_timeout: Duration) -> Result<Self, Error> {
+        use tendermint_rpc::Client;
+
+        let rpc_client =
+            rpc::HttpClient::new(address.clone()).map_err(|e| Error::rpc(address.clone(), e))?;
+
+        let peer_id = rt
+            .block_on(async { tokio::time::timeout(rpc_timeout, rpc_client.status()).await })
+            .map_err(|_| Error::rpc(address.clone(), rpc::Error::timeout(rpc_timeout)))?
+            .map_err(|e| Error::rpc(address.clone(), e))?
+            .node_info
+            .id;
+
+        Self::new(address, peer_id, rpc_timeout)
+    }
+
+    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, Error> {
+        use tendermint_light_client::components::io::Io;
+
+        self.io
+            .fetch_light_block(height)
+            .map_err(|e| Error::light_client_io(self.address.to_string(), e))
+    }
+}
+
 impl super::LightClient<CosmosSdkChain> for LightClient {
     fn header_and_minimal_set(
         &mut self,
@@ -46,7 +119,8 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
         client_state: &AnyClientState,
     ) -> Result<Verified<TmHeader>, Error> {
         let Verified { target, supporting } = self.verify(trusted, target, client_state)?;
-        let (target, supporting) = self.adjust_headers(trusted, target, supporting)?;
+        let (target, supporting) =
+            self.adjust_headers(&self.primary, trusted, target, supporting)?;
         Ok(Verified { target, supporting })
     }
 
@@ -56,39 +130,26 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
         target: ICSHeight,
         client_state: &AnyClientState,
     ) -> Result<Verified<LightBlock>, Error> {
-        trace!(%trusted, %target, "light client verification");
+        let verified = self.verify_with(
+            &self.primary,
+            trusted,
+            target,
+            client_state,
+            Some(&self.store),
+        )?;
 
-        let target_height =
-            TMHeight::try_from(target.revision_height()).map_err(Error::invalid_height)?;
+        self.cross_check(&verified.target)?;
 
-        let client = self.prepare_client(client_state)?;
-        let mut state = self.prepare_state(trusted)?;
+        let trusting_period = tendermint_client_state(client_state)?.trusting_period;
+        self.store_verified(&verified, trusting_period);
 
-        // Verify the target header
-        let target = client
-            .verify_to_target(target_height, &mut state)
-            .map_err(|e| Error::light_client_verification(self.chain_id.to_string(), e))?;
-
-        // Collect the verification trace for the target block
-        let target_trace = state.get_trace(target.height());
-
-        // Compute the minimal supporting set, sorted by ascending height
-        let supporting = target_trace
-            .into_iter()
-            .filter(|lb| lb.height() != target.height())
-            .unique_by(LightBlock::height)
-            .sorted_by_key(LightBlock::height)
-            .collect_vec();
-
-        Ok(Verified { target, supporting })
+        Ok(verified)
     }
 
     fn fetch(&mut self, height: ICSHeight) -> Result<LightBlock, Error> {
         trace!(%height, "fetching header");
 
-        let height = TMHeight::try_from(height.revision_height()).map_err(Error::invalid_height)?;
-
-        self.fetch_light_block(AtHeight::At(height))
+        self.fetch_from(&self.primary, height)
     }
 
     /// Given a client update event that includes the header used in a client update,
@@ -118,7 +179,7 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
                 ))
             })?;
 
-        let latest_chain_block = self.fetch_light_block(AtHeight::Highest)?;
+        let latest_chain_block = self.primary.fetch_light_block(AtHeight::Highest)?;
         let latest_chain_height =
             ICSHeight::new(self.chain_id.version(), latest_chain_block.height().into())
                 .map_err(|_| Error::invalid_height_no_source())?;
@@ -140,53 +201,245 @@ impl super::LightClient<CosmosSdkChain> for LightClient {
             return Ok(None);
         }
 
-        let Verified { target, supporting } =
-            self.verify(trusted_height, target_height, client_state)?;
+        // The stored light blocks are not used here, so that the header of the update
+        // is compared against headers which are verified anew
+        let verified = self.verify_with(
+            &self.primary,
+            trusted_height,
+            target_height,
+            client_state,
+            None,
+        )?;
+
+        if !headers_compatible(&verified.target.signed_header, &update_header.signed_header) {
+            let evidence = self.misbehaviour_evidence(
+                &self.primary,
+                update,
+                update_header,
+                trusted_height,
+                verified,
+            )?;
+
+            return Ok(Some(evidence));
+        }
 
-        if !headers_compatible(&target.signed_header, &update_header.signed_header) {
-            let (witness, supporting) = self.adjust_headers(trusted_height, target, supporting)?;
+        // The header of the update matches the header of the primary node,
+        // which might be compromised, so check it against the witness nodes as well
+        for (address, witness) in self.resolve_witnesses() {
+            let verified = witness.and_then(|witness| {
+                self.verify_with(&witness, trusted_height, target_height, client_state, None)
+                    .map(|verified| (witness, verified))
+            });
+
+            let (witness, verified) = match verified {
+                Ok(verified) => verified,
+                Err(e) => {
+                    warn!(witness = %address, "skipping witness which failed to verify header: {}", e);
+                    continue;
+                }
+            };
+
+            if !headers_compatible(&verified.target.signed_header, &update_header.signed_header) {
+                let evidence = self.misbehaviour_evidence(
+                    &witness,
+                    update,
+                    update_header,
+                    trusted_height,
+                    verified,
+                )?;
 
-            let misbehaviour = TmMisbehaviour {
-                client_id: update.client_id().clone(),
-                header1: update_header.clone(),
-                header2: witness,
+                return Ok(Some(evidence));
             }
-            .into();
-
-            Ok(Some(MisbehaviourEvidence {
-                misbehaviour,
-                supporting_headers: supporting.into_iter().map(Into::into).collect(),
-            }))
-        } else {
-            Ok(None)
         }
+
+        Ok(None)
     }
 }
 
 impl LightClient {
-    pub fn from_config(config: &ChainConfig, peer_id: PeerId) -> Result<Self, Error> {
-        let rpc_client = rpc::HttpClient::new(config.rpc_addr.clone())
-            .map_err(|e| Error::rpc(config.rpc_addr.clone(), e))?;
-
-        let io = components::io::ProdIo::new(peer_id, rpc_client, Some(config.rpc_timeout));
+    /// Creates a light client which fetches light blocks from the node at `rpc_addr`,
+    /// and cross-checks them against the witness nodes at `witness_rpc_addrs`.
+    ///
+    /// If `light_block_store_dir` is set, the verified light blocks are persisted
+    /// in a sub-directory of it named after the chain.
+    pub fn from_config(
+        config: &ChainConfig,
+        peer_id: PeerId,
+        rt: Arc<TokioRuntime>,
+    ) -> Result<Self, Error> {
+        let primary = Provider::new(config.rpc_addr.clone(), peer_id, config.rpc_timeout)?;
+
+        let witnesses = config
+            .witness_rpc_addrs
+            .iter()
+            .map(|address| Witness {
+                address: address.clone(),
+                provider: None,
+            })
+            .collect();
+
+        let store = match &config.light_block_store_dir {
+            Some(dir) => LightBlockStore::open(dir.join(config.id.as_str()))
+                .map_err(|e| Error::light_block_store(config.id.clone(), e))?,
+            None => LightBlockStore::in_memory(),
+        };
 
         Ok(Self {
             chain_id: config.id.clone(),
-            peer_id,
-            io,
+            primary,
+            witnesses,
+            require_witness: !config.witness_rpc_addrs.is_empty(),
+            store,
+            rpc_timeout: config.rpc_timeout,
+            rt,
+        })
+    }
+
+    /// The witness nodes, along with their provider, or the reason why their
+    /// peer id could not be queried, in which case it is queried again next time.
+    fn resolve_witnesses(&mut self) -> Vec<(rpc::Url, Result<Provider, Error>)> {
+        let (rt, rpc_timeout) = (&self.rt, self.rpc_timeout);
+
+        self.witnesses
+            .iter_mut()
+            .map(|witness| {
+                let provider = match &witness.provider {
+                    Some(provider) => Ok(provider.clone()),
+                    None => Provider::resolve(rt, witness.address.clone(), rpc_timeout),
+                };
+
+                if let Ok(provider) = &provider {
+                    witness.provider = Some(provider.clone());
+                }
+
+                (witness.address.clone(), provider)
+            })
+            .collect()
+    }
+
+    /// Verifies the target header with the light blocks of the given provider,
+    /// reusing the light blocks of the given store instead of fetching them, if any.
+    fn verify_with(
+        &self,
+        provider: &Provider,
+        trusted: ICSHeight,
+        target: ICSHeight,
+        client_state: &AnyClientState,
+        store: Option<&LightBlockStore>,
+    ) -> Result<Verified<LightBlock>, Error> {
+        trace!(%trusted, %target, provider = %provider.address, "light client verification");
+
+        let target_height =
+            TMHeight::try_from(target.revision_height()).map_err(Error::invalid_height)?;
+
+        let client = self.prepare_client(provider, client_state)?;
+        let mut state = self.prepare_state(provider, trusted, target_height, store)?;
+
+        // Verify the target header
+        let target = client
+            .verify_to_target(target_height, &mut state)
+            .map_err(|e| Error::light_client_verification(self.chain_id.to_string(), e))?;
+
+        // Collect the verification trace for the target block
+        let target_trace = state.get_trace(target.height());
+
+        // Compute the minimal supporting set, sorted by ascending height
+        let supporting = target_trace
+            .into_iter()
+            .filter(|lb| lb.height() != target.height())
+            .unique_by(LightBlock::height)
+            .sorted_by_key(LightBlock::height)
+            .collect_vec();
+
+        Ok(Verified { target, supporting })
+    }
+
+    /// Compares the given light block, verified with the primary node, against the
+    /// light blocks of the witness nodes at the same height.
+    fn cross_check(&mut self, target: &LightBlock) -> Result<(), Error> {
+        if !self.require_witness {
+            return Ok(());
+        }
+
+        let witness_hashes = self
+            .resolve_witnesses()
+            .into_iter()
+            .map(|(address, witness)| {
+                let hash = witness
+                    .and_then(|witness| witness.fetch_light_block(AtHeight::At(target.height())))
+                    .map(|block| block.signed_header.header.hash());
+
+                (address.to_string(), hash)
+            });
+
+        check_witness_hashes(
+            &self.chain_id,
+            target.height().value(),
+            &self.primary.address.to_string(),
+            target.signed_header.header.hash(),
+            witness_hashes,
+        )
+    }
+
+    /// Adds the given verified light blocks to the store, and prunes the ones
+    /// which are outside of the trusting period. Failures are only logged,
+    /// since they do not affect the verification itself.
+    fn store_verified(&mut self, verified: &Verified<LightBlock>, trusting_period: Duration) {
+        let blocks = verified
+            .supporting
+            .iter()
+            .chain(core::iter::once(&verified.target));
+
+        for block in blocks {
+            if let Err(e) = self.store.insert(block.clone()) {
+                warn!(chain = %self.chain_id, height = %block.height(), "failed to store light block: {}", e);
+            }
+        }
+
+        if let Err(e) = self.store.prune(trusting_period, Time::now()) {
+            warn!(chain = %self.chain_id, "failed to prune light block store: {}", e);
+        }
+    }
+
+    /// Builds the evidence of the misbehaviour made of the header of the given update
+    /// and of the conflicting header verified with the given provider.
+    fn misbehaviour_evidence(
+        &self,
+        provider: &Provider,
+        update: &UpdateClient,
+        update_header: &TmHeader,
+        trusted_height: ICSHeight,
+        verified: Verified<LightBlock>,
+    ) -> Result<MisbehaviourEvidence, Error> {
+        let Verified { target, supporting } = verified;
+
+        let (witness, supporting) =
+            self.adjust_headers(provider, trusted_height, target, supporting)?;
+
+        let misbehaviour = TmMisbehaviour {
+            client_id: update.client_id().clone(),
+            header1: update_header.clone(),
+            header2: witness,
+        }
+        .into();
+
+        Ok(MisbehaviourEvidence {
+            misbehaviour,
+            supporting_headers: supporting.into_iter().map(Into::into).collect(),
         })
     }
 
-    fn prepare_client(&self, client_state: &AnyClientState) -> Result<TmLightClient, Error> {
+    fn prepare_client(
+        &self,
+        provider: &Provider,
+        client_state: &AnyClientState,
+    ) -> Result<TmLightClient, Error> {
         let clock = components::clock::SystemClock;
         let hasher = operations::hasher::ProdHasher;
         let verifier = ProdVerifier::default();
-        let scheduler = components::scheduler::basic_bisecting_schedule;
+        let scheduler = stored_bisecting_schedule;
 
-        let client_state =
-            downcast!(client_state => AnyClientState::Tendermint).ok_or_else(|| {
-                Error::client_type_mismatch(ClientType::Tendermint, client_state.client_type())
-            })?;
+        let client_state = tendermint_client_state(client_state)?;
 
         let params = TmOptions {
             trust_threshold: client_state
@@ -198,44 +451,60 @@ impl LightClient {
         };
 
         Ok(TmLightClient::new(
-            self.peer_id,
+            provider.peer_id,
             params,
             clock,
             scheduler,
             verifier,
             hasher,
-            self.io.clone(),
+            provider.io.clone(),
         ))
     }
 
-    fn prepare_state(&self, trusted: ICSHeight) -> Result<LightClientState, Error> {
+    fn prepare_state(
+        &self,
+        provider: &Provider,
+        trusted: ICSHeight,
+        target_height: TMHeight,
+        store: Option<&LightBlockStore>,
+    ) -> Result<LightClientState, Error> {
         let trusted_height =
             TMHeight::try_from(trusted.revision_height()).map_err(Error::invalid_height)?;
 
-        let trusted_block = self.fetch_light_block(AtHeight::At(trusted_height))?;
+        // Only the light blocks verified by this process are trusted,
+        // the ones loaded from disk must be fetched again.
+        let trusted_block = match store.and_then(|store| store.get_verified(trusted_height)) {
+            Some(trusted_block) => trusted_block.clone(),
+            None => provider.fetch_light_block(AtHeight::At(trusted_height))?,
+        };
 
-        let mut store = MemoryStore::new();
-        store.insert(trusted_block, Status::Trusted);
+        let mut light_store = MemoryStore::new();
+        light_store.insert(trusted_block, Status::Trusted);
 
-        Ok(LightClientState::new(store))
+        // The stored light blocks between the trusted and the target heights are
+        // verified again from the trusted block, but do not need to be fetched.
+        if let Some(store) = store {
+            for block in store.range(trusted_height, target_height) {
+                light_store.insert(block.clone(), Status::Unverified);
+            }
+        }
+
+        Ok(LightClientState::new(light_store))
     }
 
-    fn fetch_light_block(&self, height: AtHeight) -> Result<LightBlock, Error> {
-        use tendermint_light_client::components::io::Io;
+    fn fetch_from(&self, provider: &Provider, height: ICSHeight) -> Result<LightBlock, Error> {
+        let height = TMHeight::try_from(height.revision_height()).map_err(Error::invalid_height)?;
 
-        self.io
-            .fetch_light_block(height)
-            .map_err(|e| Error::light_client_io(self.chain_id.to_string(), e))
+        provider.fetch_light_block(AtHeight::At(height))
     }
 
     fn adjust_headers(
-        &mut self,
+        &self,
+        provider: &Provider,
         trusted_height: ICSHeight,
         target: LightBlock,
         supporting: Vec<LightBlock>,
     ) -> Result<(TmHeader, Vec<TmHeader>), Error> {
-        use super::LightClient;
-
         trace!(
             trusted = %trusted_height, target = %target.height(),
             "adjusting headers with {} supporting headers", supporting.len()
@@ -245,7 +514,9 @@ impl LightClient {
         //
         // NOTE: This is needed to get the next validator set. While there is a next validator set
         //       in the light block at trusted height, the proposer is not known/set in this set.
-        let trusted_validator_set = self.fetch(trusted_height.increment())?.validators;
+        let trusted_validator_set = self
+            .fetch_from(provider, trusted_height.increment())?
+            .validators;
 
         let mut supporting_headers = Vec::with_capacity(supporting.len());
 
@@ -264,7 +535,9 @@ impl LightClient {
             current_trusted_height = header.height();
 
             // Therefore we can now trust the next validator set, see NOTE above.
-            current_trusted_validators = self.fetch(header.height().increment())?.validators;
+            current_trusted_validators = self
+                .fetch_from(provider, header.height().increment())?
+                .validators;
 
             supporting_headers.push(header);
         }
@@ -277,7 +550,7 @@ impl LightClient {
         let (latest_trusted_height, latest_trusted_validator_set) = match supporting_headers.last()
         {
             Some(prev_header) => {
-                let prev_succ = self.fetch(prev_header.height().increment())?;
+                let prev_succ = self.fetch_from(provider, prev_header.height().increment())?;
                 (prev_header.height(), prev_succ.validators)
             }
             None => (trusted_height, trusted_validator_set),
@@ -293,3 +566,124 @@ impl LightClient {
         Ok((target_header, supporting_headers))
     }
 }
+
+/// Fails if the hash of a header of the primary node differs from the hash of
+/// the header of any witness at the same height, or if no witness provided one.
+/// Witnesses which failed to provide their header are skipped.
+fn check_witness_hashes(
+    chain_id: &ChainId,
+    height: u64,
+    primary: &str,
+    primary_hash: Hash,
+    witness_hashes: impl IntoIterator<Item = (String, Result<Hash, Error>)>,
+) -> Result<(), Error> {
+    let mut compared = 0;
+
+    for (witness, hash) in witness_hashes {
+        match hash {
+            Ok(hash) if hash == primary_hash => compared += 1,
+            Ok(_) => {
+                return Err(Error::light_client_fork(
+                    chain_id.clone(),
+                    height,
+                    primary.to_string(),
+                    witness,
+                ))
+            }
+            Err(e) => {
+                warn!(%witness, "skipping witness which failed to provide light block: {}", e);
+            }
+        }
+    }
+
+    if compared == 0 {
+        return Err(Error::light_client_no_witness(chain_id.clone(), height));
+    }
+
+    Ok(())
+}
+
+/// Clients hosted by a Wasm contract wrap the state of a Tendermint client.
+fn tendermint_client_state(client_state: &AnyClientState) -> Result<&TmClientState, Error> {
+    match client_state {
+        AnyClientState::Tendermint(client_state) => Ok(client_state),
+        AnyClientState::Wasm(client_state) => Ok(&client_state.inner),
+        _ => Err(Error::client_type_mismatch(
+            ClientType::Tendermint,
+            client_state.client_type(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::ErrorDetail;
+
+    fn check(witness_hashes: Vec<Result<Hash, Error>>) -> Result<(), Error> {
+        let witness_hashes = witness_hashes
+            .into_iter()
+            .enumerate()
+            .map(|(i, hash)| (format!("http://witness-{i}"), hash));
+
+        check_witness_hashes(
+            &ChainId::new("ibc".to_string(), 0),
+            42,
+            "http://primary",
+            Hash::Sha256([1; 32]),
+            witness_hashes,
+        )
+    }
+
+    #[test]
+    fn witnesses_agree_with_primary() {
+        assert!(check(vec![Ok(Hash::Sha256([1; 32])), Ok(Hash::Sha256([1; 32]))]).is_ok());
+    }
+
+    #[test]
+    fn unreachable_witnesses_are_skipped() {
+        let res = check(vec![
+            Err(Error::invalid_height_no_source()),
+            Ok(Hash::Sha256([1; 32])),
+        ]);
+
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn witness_disagreeing_with_primary_is_a_fork() {
+        let res = check(vec![Ok(Hash::Sha256([1; 32])), Ok(Hash::Sha256([2; 32]))]);
+
+        assert!(matches!(
+            res.unwrap_err().detail(),
+            ErrorDetail::LightClientFork(e) if e.witness == "http://witness-1"
+        ));
+    }
+
+    #[test]
+    fn unreachable_witness_is_not_resolved() {
+        let rt = TokioRuntime::new().unwrap();
+
+        // Nothing listens on port 1, so the witness cannot be reached
+        let unreachable = "http://127.0.0.1:1".parse().unwrap();
+        let res = Provider::resolve(&rt, unreachable, Duration::from_secs(5));
+
+        assert!(matches!(res, Err(e) if matches!(e.detail(), ErrorDetail::Rpc(_))));
+    }
+
+    #[test]
+    fn no_witness_compared_fails() {
+        let res = check(vec![Err(Error::invalid_height_no_source())]);
+        assert!(matches!(
+            res.unwrap_err().detail(),
+            ErrorDetail::LightClientNoWitness(_)
+        ));
+
+        let res = check(vec![]);
+        assert!(matches!(
+            res.unwrap_err().detail(),
+            ErrorDetail::LightClientNoWitness(_)
+        ));
+    }
+}
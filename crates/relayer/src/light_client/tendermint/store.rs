@@ -0,0 +1,454 @@
+//! Store of the light blocks verified by the light client of a chain.
+//!
+//! Light blocks verified by an earlier call of the light client are reused by later
+//! calls instead of being downloaded again. When a directory is configured, the light
+//! blocks are also persisted there, one JSON file per height, so that they survive
+//! restarts of the relayer.
+//!
+//! The light blocks loaded from disk are not trusted as such: they are only used as
+//! candidates for bisection, and are verified again before the light client relies
+//! on them. At most [`MAX_LIGHT_BLOCKS`] light blocks are kept, the lowest ones being
+//! evicted first.
+
+use core::ops::Bound;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use flex_error::{define_error, TraceError};
+use tendermint::Time;
+use tendermint_light_client::components::scheduler::basic_bisecting_schedule;
+use tendermint_light_client::store::LightStore;
+use tendermint_light_client_verifier::types::{Height as TMHeight, LightBlock, Status};
+use tracing::warn;
+
+define_error! {
+    LightBlockStoreError {
+        Io
+            { path: PathBuf }
+            [ TraceError<std::io::Error> ]
+            |e| { format!("I/O error on light block store file {}", e.path.display()) },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode light block" },
+    }
+}
+
+/// The maximum number of light blocks kept by a store.
+pub const MAX_LIGHT_BLOCKS: usize = 100;
+
+#[derive(Debug)]
+struct StoredBlock {
+    block: LightBlock,
+    /// Whether the light block was verified by this process,
+    /// as opposed to having been loaded from disk
+    verified: bool,
+}
+
+#[derive(Debug)]
+pub struct LightBlockStore {
+    /// The directory in which the light blocks are persisted, if any
+    dir: Option<PathBuf>,
+    /// The maximum number of light blocks kept in the store
+    capacity: usize,
+    /// The longest trusting period of the clients the store was pruned for
+    trusting_period: Duration,
+    blocks: BTreeMap<TMHeight, StoredBlock>,
+}
+
+impl Default for LightBlockStore {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            capacity: MAX_LIGHT_BLOCKS,
+            trusting_period: Duration::ZERO,
+            blocks: BTreeMap::new(),
+        }
+    }
+}
+
+impl LightBlockStore {
+    /// A store which only keeps the light blocks in memory.
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of light blocks kept in the store.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Opens the store persisted in the given directory, loading the light blocks
+    /// stored by a previous run, if any. Files which cannot be decoded, or whose
+    /// light block is not consistent, are skipped.
+    ///
+    /// The loaded light blocks are not considered as verified,
+    /// see [`LightBlockStore::get_verified`].
+    pub fn open(dir: PathBuf) -> Result<Self, LightBlockStoreError> {
+        fs::create_dir_all(&dir).map_err(|e| LightBlockStoreError::io(dir.clone(), e))?;
+
+        let entries = fs::read_dir(&dir).map_err(|e| LightBlockStoreError::io(dir.clone(), e))?;
+
+        let mut blocks = BTreeMap::new();
+
+        for entry in entries {
+            let path = entry
+                .map_err(|e| LightBlockStoreError::io(dir.clone(), e))?
+                .path();
+
+            if path.extension().map_or(true, |ext| ext != "json") {
+                continue;
+            }
+
+            let block = fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| {
+                    serde_json::from_str::<LightBlock>(&contents).map_err(|e| e.to_string())
+                })
+                .and_then(|block| check_consistency(&block).map(|_| block));
+
+            match block {
+                Ok(block) => {
+                    let stored = StoredBlock {
+                        block,
+                        verified: false,
+                    };
+
+                    blocks.insert(stored.block.height(), stored);
+                }
+                Err(e) => {
+                    warn!(file = %path.display(), "skipping invalid light block file: {}", e);
+                }
+            }
+        }
+
+        let mut store = Self {
+            dir: Some(dir),
+            blocks,
+            ..Self::default()
+        };
+
+        store.evict()?;
+
+        Ok(store)
+    }
+
+    /// The light block at the given height, if it was verified by this process.
+    /// Such a light block can be used as a trusted state by the light client.
+    pub fn get_verified(&self, height: TMHeight) -> Option<&LightBlock> {
+        self.blocks
+            .get(&height)
+            .filter(|stored| stored.verified)
+            .map(|stored| &stored.block)
+    }
+
+    /// The light blocks above the `trusted` height, up to and including the `target` height.
+    pub fn range(
+        &self,
+        trusted: TMHeight,
+        target: TMHeight,
+    ) -> impl Iterator<Item = &LightBlock> + '_ {
+        // `BTreeMap::range` panics on an empty range
+        let bounds = if trusted < target {
+            Some((Bound::Excluded(trusted), Bound::Included(target)))
+        } else {
+            None
+        };
+
+        bounds
+            .into_iter()
+            .flat_map(move |bounds| self.blocks.range(bounds).map(|(_, stored)| &stored.block))
+    }
+
+    /// Inserts a light block which was verified by the light client, evicting
+    /// the lowest light blocks if the store is over capacity.
+    pub fn insert(&mut self, block: LightBlock) -> Result<(), LightBlockStoreError> {
+        if let Some(dir) = &self.dir {
+            let contents = serde_json::to_string(&block).map_err(LightBlockStoreError::encode)?;
+
+            let file = Self::file(dir, block.height());
+
+            // Write to a temporary file first so that a crash
+            // cannot leave behind a partially written light block.
+            let tmp_file = file.with_extension("json.tmp");
+
+            fs::write(&tmp_file, contents)
+                .map_err(|e| LightBlockStoreError::io(tmp_file.clone(), e))?;
+            fs::rename(&tmp_file, &file).map_err(|e| LightBlockStoreError::io(file.clone(), e))?;
+        }
+
+        let stored = StoredBlock {
+            block,
+            verified: true,
+        };
+
+        self.blocks.insert(stored.block.height(), stored);
+
+        self.evict()
+    }
+
+    /// Removes the light blocks which cannot serve as a trusted state anymore.
+    ///
+    /// The store is shared by all the clients of the chain, whose trusting periods
+    /// may differ, hence only the light blocks which are outside of the longest
+    /// trusting period seen so far, including the given one, are removed.
+    pub fn prune(
+        &mut self,
+        trusting_period: Duration,
+        now: Time,
+    ) -> Result<(), LightBlockStoreError> {
+        self.trusting_period = self.trusting_period.max(trusting_period);
+
+        let expired = self
+            .blocks
+            .iter()
+            .filter(|(_, stored)| match now - self.trusting_period {
+                Ok(start) => stored.block.signed_header.header.time <= start,
+                Err(_) => true,
+            })
+            .map(|(height, _)| *height)
+            .collect::<Vec<_>>();
+
+        for height in expired {
+            self.remove(height)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the lowest light blocks until the store is within its capacity,
+    /// since the highest ones are the closest to the heights verified next.
+    fn evict(&mut self) -> Result<(), LightBlockStoreError> {
+        while self.blocks.len() > self.capacity {
+            let lowest = *self.blocks.keys().next().unwrap();
+            self.remove(lowest)?;
+        }
+
+        Ok(())
+    }
+
+    fn remove(&mut self, height: TMHeight) -> Result<(), LightBlockStoreError> {
+        self.blocks.remove(&height);
+
+        if let Some(dir) = &self.dir {
+            let file = Self::file(dir, height);
+
+            fs::remove_file(&file).map_err(|e| LightBlockStoreError::io(file.clone(), e))?;
+        }
+
+        Ok(())
+    }
+
+    fn file(dir: &Path, height: TMHeight) -> PathBuf {
+        dir.join(format!("{}.json", height))
+    }
+}
+
+/// Checks that the validator sets and the commit of a light block loaded from disk
+/// match its header, so that a corrupted file is not used for bisection.
+fn check_consistency(block: &LightBlock) -> Result<(), String> {
+    let header = &block.signed_header.header;
+
+    if block.validators.hash() != header.validators_hash {
+        return Err("validator set does not match the header".to_string());
+    }
+
+    if block.next_validators.hash() != header.next_validators_hash {
+        return Err("next validator set does not match the header".to_string());
+    }
+
+    if block.signed_header.commit.block_id.hash != header.hash() {
+        return Err("commit does not match the header".to_string());
+    }
+
+    Ok(())
+}
+
+/// Like `basic_bisecting_schedule`, except that the light blocks already present in the
+/// light store, eg. the ones loaded from the [`LightBlockStore`], are tried first,
+/// from the highest one below the current height, before bisecting any further.
+pub fn stored_bisecting_schedule(
+    light_store: &dyn LightStore,
+    current_height: TMHeight,
+    target_height: TMHeight,
+) -> TMHeight {
+    let trusted_height = light_store
+        .highest_trusted_or_verified()
+        .map(|lb| lb.height())
+        .unwrap();
+
+    light_store
+        .all(Status::Unverified)
+        .map(|lb| lb.height())
+        .filter(|height| *height > trusted_height && *height < current_height)
+        .max()
+        .unwrap_or_else(|| basic_bisecting_schedule(light_store, current_height, target_height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+    use tendermint_light_client::store::memory::MemoryStore;
+    use tendermint_testgen::{Generator, LightBlock as TestgenLightBlock};
+
+    fn light_block(height: u64, time: Time) -> LightBlock {
+        let block = TestgenLightBlock::new_default_with_time_and_chain_id(
+            "chain-0".to_string(),
+            time,
+            height,
+        )
+        .generate()
+        .unwrap();
+
+        LightBlock::new(
+            block.signed_header,
+            block.validators,
+            block.next_validators,
+            block.provider,
+        )
+    }
+
+    fn height(height: u64) -> TMHeight {
+        TMHeight::try_from(height).unwrap()
+    }
+
+    #[test]
+    fn persist_reload_and_prune() {
+        let dir = TempDir::new().unwrap();
+
+        let now = Time::now();
+        let old = (now - Duration::from_secs(3600)).unwrap();
+
+        let mut store = LightBlockStore::open(dir.path().to_path_buf()).unwrap();
+        store.insert(light_block(5, old)).unwrap();
+        store.insert(light_block(10, now)).unwrap();
+        store.insert(light_block(20, now)).unwrap();
+
+        let mut store = LightBlockStore::open(dir.path().to_path_buf()).unwrap();
+
+        let heights = |store: &LightBlockStore| {
+            store
+                .range(height(1), height(20))
+                .map(|lb| lb.height().value())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(heights(&store), vec![5, 10, 20]);
+        assert_eq!(
+            store
+                .range(height(5), height(19))
+                .map(|lb| lb.height().value())
+                .collect::<Vec<_>>(),
+            vec![10]
+        );
+        assert_eq!(store.range(height(20), height(10)).count(), 0);
+
+        // Not pruned within the trusting period of another client
+        store.prune(Duration::from_secs(7200), now).unwrap();
+        store.prune(Duration::from_secs(60), now).unwrap();
+        assert_eq!(heights(&store), vec![5, 10, 20]);
+
+        let mut store = LightBlockStore::open(dir.path().to_path_buf()).unwrap();
+        store.prune(Duration::from_secs(60), now).unwrap();
+        assert_eq!(heights(&store), vec![10, 20]);
+
+        let store = LightBlockStore::open(dir.path().to_path_buf()).unwrap();
+        assert_eq!(heights(&store), vec![10, 20]);
+    }
+
+    #[test]
+    fn loaded_blocks_are_not_trusted() {
+        let dir = TempDir::new().unwrap();
+
+        let now = Time::now();
+
+        let mut store = LightBlockStore::open(dir.path().to_path_buf()).unwrap();
+        store.insert(light_block(10, now)).unwrap();
+        assert!(store.get_verified(height(10)).is_some());
+
+        // A light block whose header does not match its commit is skipped on load
+        let mut corrupted = light_block(20, now);
+        corrupted.signed_header.header.app_hash = vec![1, 2, 3].try_into().unwrap();
+        fs::write(
+            dir.path().join("20.json"),
+            serde_json::to_string(&corrupted).unwrap(),
+        )
+        .unwrap();
+
+        let mut store = LightBlockStore::open(dir.path().to_path_buf()).unwrap();
+        assert_eq!(store.range(height(1), height(20)).count(), 1);
+        assert!(store.get_verified(height(10)).is_none());
+
+        // Until it is verified again
+        store.insert(light_block(10, now)).unwrap();
+        assert!(store.get_verified(height(10)).is_some());
+    }
+
+    #[test]
+    fn evict_lowest_blocks_over_capacity() {
+        let dir = TempDir::new().unwrap();
+
+        let now = Time::now();
+
+        let mut store = LightBlockStore::open(dir.path().to_path_buf())
+            .unwrap()
+            .with_capacity(2);
+        for h in [10, 30, 20, 40] {
+            store.insert(light_block(h, now)).unwrap();
+        }
+
+        let heights = |store: &LightBlockStore| {
+            store
+                .range(height(1), height(100))
+                .map(|lb| lb.height().value())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(heights(&store), vec![30, 40]);
+
+        let mut files = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(files, vec!["30.json", "40.json"]);
+    }
+
+    #[test]
+    fn schedule_stored_blocks_first() {
+        let now = Time::now();
+
+        let mut light_store = MemoryStore::new();
+        light_store.insert(light_block(1, now), Status::Trusted);
+        light_store.insert(light_block(40, now), Status::Unverified);
+        light_store.insert(light_block(70, now), Status::Unverified);
+
+        // The stored blocks are tried from the highest one below the current height
+        assert_eq!(
+            stored_bisecting_schedule(&light_store, height(100), height(100)),
+            height(70)
+        );
+        assert_eq!(
+            stored_bisecting_schedule(&light_store, height(70), height(100)),
+            height(40)
+        );
+
+        // Then the schedule falls back to bisection
+        assert_eq!(
+            stored_bisecting_schedule(&light_store, height(40), height(100)),
+            height(21)
+        );
+
+        // And back to the target once the trusted state has moved up
+        light_store.update(&light_block(40, now), Status::Verified);
+        assert_eq!(
+            stored_bisecting_schedule(&light_store, height(40), height(100)),
+            height(100)
+        );
+    }
+}
@@ -11,9 +11,11 @@ use crate::chain::{handle::ChainHandle, requests::IncludeProof};
 use crate::channel::{Channel, ChannelSide};
 use crate::link::error::LinkError;
 
+pub mod activity;
 pub mod cli;
 pub mod error;
 pub mod operational_data;
+pub mod store;
 
 mod packet_events;
 mod pending;
@@ -77,8 +79,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
                 )
             })?;
 
+        // Packets in flight must still be relayed while the channel is being upgraded.
         if !a_channel.state_matches(&ChannelState::Open)
             && !a_channel.state_matches(&ChannelState::Closed)
+            && !a_channel.state.is_upgrading()
         {
             return Err(LinkError::invalid_channel_state(
                 a_channel_id.clone(),
@@ -0,0 +1,136 @@
+//! A stream of the transactions relayed over all the paths, for observers
+//! outside of the relayer, e.g. the clients of the REST API.
+//!
+//! The packet workers publish a [`RelayActivity`] each time they submit
+//! a transaction, and, if transaction confirmation is enabled, each time
+//! such a transaction is confirmed.
+
+use alloc::sync::Arc;
+use std::sync::Mutex;
+
+use crossbeam_channel as channel;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+use crate::event::bus::EventBus;
+use crate::link::operational_data::OperationalData;
+use crate::link::TxHashes;
+
+static ACTIVITY_BUS: Lazy<Mutex<EventBus<Arc<RelayActivity>>>> =
+    Lazy::new(|| Mutex::new(EventBus::new()));
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TxStatus {
+    /// The transactions were submitted to the chain
+    Submitted,
+    /// The transactions were committed to the chain
+    Confirmed,
+}
+
+/// Transactions submitted by the relayer, targeting the given channel end.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RelayActivity {
+    pub status: TxStatus,
+    pub tracking_id: String,
+    /// The chain the transactions are submitted to
+    pub chain_id: ChainId,
+    pub port_id: PortId,
+    pub channel_id: ChannelId,
+    pub counterparty_chain_id: ChainId,
+    pub counterparty_port_id: PortId,
+    pub counterparty_channel_id: ChannelId,
+    /// The sequence numbers of the packets relayed by the transactions
+    pub sequences: Vec<Sequence>,
+    pub tx_hashes: Vec<String>,
+}
+
+impl RelayActivity {
+    /// The sequence numbers of the packets relayed by the messages of the given operational data.
+    pub fn sequences(odata: &OperationalData) -> Vec<Sequence> {
+        odata
+            .batch
+            .iter()
+            .filter_map(|msg| msg.event_with_height.event.packet())
+            .map(|packet| packet.sequence)
+            .collect()
+    }
+
+    pub fn tx_hashes(tx_hashes: &TxHashes) -> Vec<String> {
+        tx_hashes.0.iter().map(|hash| hash.to_string()).collect()
+    }
+
+    /// Whether the transactions were relayed between the given chain and any other chain,
+    /// and, if a channel is given, whether they were relayed over that channel.
+    ///
+    /// If both a chain and a channel are given, the channel must be the end of the
+    /// channel on that chain. Otherwise, the channel may be either end.
+    pub fn matches(&self, chain_id: Option<&ChainId>, channel_id: Option<&ChannelId>) -> bool {
+        let ends = [
+            (&self.chain_id, &self.channel_id),
+            (&self.counterparty_chain_id, &self.counterparty_channel_id),
+        ];
+
+        ends.into_iter().any(|(end_chain_id, end_channel_id)| {
+            chain_id.map_or(true, |chain_id| end_chain_id == chain_id)
+                && channel_id.map_or(true, |channel_id| end_channel_id == channel_id)
+        })
+    }
+}
+
+/// Subscribe to the transactions relayed from now on.
+///
+/// The subscription ends when the returned receiver is dropped.
+pub fn subscribe() -> channel::Receiver<Arc<RelayActivity>> {
+    ACTIVITY_BUS.lock().unwrap().subscribe()
+}
+
+pub(crate) fn publish(activity: RelayActivity) {
+    ACTIVITY_BUS.lock().unwrap().broadcast(Arc::new(activity));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_activity() {
+        let activity = RelayActivity {
+            status: TxStatus::Submitted,
+            tracking_id: "cleared/0b4c9d2f".to_string(),
+            chain_id: ChainId::from_string("ibc-1"),
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(1),
+            counterparty_chain_id: ChainId::from_string("ibc-0"),
+            counterparty_port_id: PortId::transfer(),
+            counterparty_channel_id: ChannelId::new(0),
+            sequences: vec![Sequence::from(1)],
+            tx_hashes: vec![],
+        };
+
+        let ibc_0 = ChainId::from_string("ibc-0");
+        let ibc_1 = ChainId::from_string("ibc-1");
+        let ibc_2 = ChainId::from_string("ibc-2");
+        let channel_0 = ChannelId::new(0);
+        let channel_1 = ChannelId::new(1);
+        let channel_2 = ChannelId::new(2);
+
+        assert!(activity.matches(None, None));
+        assert!(activity.matches(Some(&ibc_0), None));
+        assert!(activity.matches(Some(&ibc_1), None));
+        assert!(!activity.matches(Some(&ibc_2), None));
+
+        // Both ends of the channel match
+        assert!(activity.matches(None, Some(&channel_0)));
+        assert!(activity.matches(None, Some(&channel_1)));
+        assert!(!activity.matches(None, Some(&channel_2)));
+
+        // The channel must be the end on the given chain
+        assert!(activity.matches(Some(&ibc_0), Some(&channel_0)));
+        assert!(activity.matches(Some(&ibc_1), Some(&channel_1)));
+        assert!(!activity.matches(Some(&ibc_0), Some(&channel_1)));
+    }
+}
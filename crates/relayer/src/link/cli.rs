@@ -1,4 +1,5 @@
 use std::convert::TryInto;
+use std::ops::RangeInclusive;
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -166,6 +167,54 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
         )
     }
 
+    /// Implements the `packet-timeout` CLI
+    ///
+    /// Only the packets with a sequence in one of the given ranges are considered,
+    /// or all pending packets if no range is given.
+    pub fn relay_timeout_packet_messages(
+        &self,
+        sequence_ranges: &[RangeInclusive<Sequence>],
+    ) -> Result<Vec<IbcEvent>, LinkError> {
+        let _span = error_span!(
+            "relay_timeout_packet_messages",
+            src_chain = %self.a_to_b.src_chain().id(),
+            src_port = %self.a_to_b.src_port_id(),
+            src_channel = %self.a_to_b.src_channel_id(),
+            dst_chain = %self.a_to_b.dst_chain().id(),
+        )
+        .entered();
+
+        // Find the sequence numbers of unreceived packets
+        let (mut sequences, src_response_height) = unreceived_packets(
+            self.a_to_b.dst_chain(),
+            self.a_to_b.src_chain(),
+            &self.a_to_b.path_id,
+        )
+        .map_err(LinkError::supervisor)?;
+
+        if !sequence_ranges.is_empty() {
+            sequences.retain(|seq| sequence_ranges.iter().any(|range| range.contains(seq)));
+        }
+
+        if sequences.is_empty() {
+            return Ok(vec![]);
+        }
+
+        info!(
+            "{} unreceived packets found: {} ",
+            sequences.len(),
+            PrettySlice(&sequences)
+        );
+
+        self.relay_packet_messages_with(
+            sequences,
+            Qualified::SmallerEqual(src_response_height),
+            query_send_packet_events,
+            TrackingId::new_static("packet-timeout"),
+            RelayPath::timeout_events_to_operational_data,
+        )
+    }
+
     fn relay_packet_messages<QueryFn>(
         &self,
         sequences: Vec<Sequence>,
@@ -180,6 +229,32 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
             &[Sequence],
             Qualified<Height>,
         ) -> Result<Vec<IbcEventWithHeight>, Error>,
+    {
+        self.relay_packet_messages_with(
+            sequences,
+            query_height,
+            query_fn,
+            tracking_id,
+            RelayPath::events_to_operational_data,
+        )
+    }
+
+    fn relay_packet_messages_with<QueryFn, ScheduleFn>(
+        &self,
+        sequences: Vec<Sequence>,
+        query_height: Qualified<Height>,
+        query_fn: QueryFn,
+        tracking_id: TrackingId,
+        schedule_fn: ScheduleFn,
+    ) -> Result<Vec<IbcEvent>, LinkError>
+    where
+        QueryFn: Fn(
+            &ChainA,
+            &PathIdentifiers,
+            &[Sequence],
+            Qualified<Height>,
+        ) -> Result<Vec<IbcEventWithHeight>, Error>,
+        ScheduleFn: Fn(&RelayPath<ChainA, ChainB>, TrackedEvents) -> Result<(), LinkError>,
     {
         let event_chunks = query_packet_events_with(
             &sequences,
@@ -193,7 +268,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> Link<ChainA, ChainB> {
 
         for event_chunk in event_chunks {
             let tracked_events = TrackedEvents::new(event_chunk, tracking_id);
-            self.a_to_b.events_to_operational_data(tracked_events)?;
+            schedule_fn(&self.a_to_b, tracked_events)?;
 
             // In case of zero connection delay, the op. data will already be ready
             let (src_ods, dst_ods) = self.a_to_b.try_fetch_scheduled_operational_data()?;
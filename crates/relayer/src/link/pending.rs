@@ -2,7 +2,7 @@ use core::iter::Iterator;
 use core::time::Duration;
 use std::time::Instant;
 
-use tracing::{debug, error, trace, trace_span};
+use tracing::{debug, error, trace, trace_span, warn};
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 use ibc_relayer_types::events::IbcEvent;
@@ -10,6 +10,9 @@ use ibc_relayer_types::events::IbcEvent;
 use crate::chain::requests::{QueryTxHash, QueryTxRequest};
 use crate::chain::tracking::TrackingId;
 use crate::error::Error as RelayerError;
+use crate::link::activity::{self, RelayActivity, TxStatus};
+use crate::link::operational_data::OperationalDataTarget;
+use crate::link::store::{PathStore, PendingTxRecord};
 use crate::link::{error::LinkError, RelayPath};
 use crate::telemetry;
 use crate::util::queue::Queue;
@@ -48,7 +51,10 @@ pub struct PendingTxs<Chain> {
     pub channel_id: ChannelId,
     pub port_id: PortId,
     pub counterparty_chain_id: ChainId,
+    pub counterparty_channel_id: ChannelId,
+    pub counterparty_port_id: PortId,
     pub pending_queue: Queue<PendingData>,
+    store: Option<PathStore>,
 }
 
 impl<Chain> PendingTxs<Chain> {
@@ -57,13 +63,18 @@ impl<Chain> PendingTxs<Chain> {
         channel_id: ChannelId,
         port_id: PortId,
         counterparty_chain_id: ChainId,
+        counterparty_channel_id: ChannelId,
+        counterparty_port_id: PortId,
     ) -> Self {
         Self {
             chain,
             channel_id,
             port_id,
             counterparty_chain_id,
+            counterparty_channel_id,
+            counterparty_port_id,
             pending_queue: Queue::new(),
+            store: None,
         }
     }
 }
@@ -73,6 +84,59 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
         self.chain.id()
     }
 
+    /// Resume tracking the transactions submitted to this chain which were still
+    /// pending when the given store was last updated, and keep the store up-to-date
+    /// with the pending transactions from now on.
+    ///
+    /// The operational data the restored transactions were built from is not persisted,
+    /// hence these transactions are never resubmitted. The packets they carry are instead
+    /// picked up by packet clearing if the transactions end up not being committed.
+    pub fn restore_from(&mut self, store: PathStore, target: OperationalDataTarget) {
+        let records = store.state().pending;
+
+        for record in records.into_iter().filter(|record| record.target == target) {
+            let tx_hashes = TxHashes(record.tx_hashes);
+
+            debug!(
+                chain = %self.chain_id(),
+                tracking_id = %record.tracking_id,
+                %tx_hashes,
+                "resuming confirmation of transaction submitted before restart",
+            );
+
+            let elapsed = record.submitted_at.elapsed().unwrap_or_default();
+
+            self.pending_queue.push_back(PendingData {
+                original_od: OperationalData::new(
+                    record.proofs_height,
+                    target,
+                    TrackingId::new_static("restored"),
+                    Duration::ZERO,
+                ),
+                tx_hashes,
+                submit_time: Instant::now()
+                    .checked_sub(elapsed)
+                    .unwrap_or_else(Instant::now),
+                error_events: Vec::new(),
+            });
+        }
+
+        self.store = Some(store);
+    }
+
+    /// Remove the given transaction from the store, if any,
+    /// once it does not need to be tracked anymore.
+    fn forget(&self, pending: &PendingData) {
+        if let Some(store) = &self.store {
+            if let Err(e) = store.remove_pending(&pending.tx_hashes.0) {
+                warn!(
+                    "failed to remove {} from the state store: {}",
+                    pending.tx_hashes, e
+                );
+            }
+        }
+    }
+
     /// Insert a new pending transaction to the back of the queue.
     pub fn insert_new_pending_tx(&self, r: AsyncReply, od: OperationalData) {
         let mut tx_hashes = Vec::new();
@@ -123,9 +187,53 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
             error_events,
         };
 
+        if let Some(store) = &self.store {
+            if !u.tx_hashes.0.is_empty() {
+                let record = PendingTxRecord::new(&u.original_od, u.tx_hashes.0.clone());
+
+                if let Err(e) = store.insert_pending(record) {
+                    warn!(
+                        "failed to persist {} to the state store: {}",
+                        u.tx_hashes, e
+                    );
+                }
+            }
+        }
+
         self.pending_queue.push_back(u);
     }
 
+    /// Publish the transactions successfully submitted for the given operational data
+    /// to the observers of the relaying activity, whether or not they are confirmed later on.
+    pub fn publish_submitted(&self, r: &AsyncReply, od: &OperationalData) {
+        let tx_hashes = TxHashes(
+            r.responses
+                .iter()
+                .filter(|response| response.code.is_ok())
+                .map(|response| response.hash)
+                .collect(),
+        );
+
+        if !tx_hashes.0.is_empty() {
+            self.publish_activity(TxStatus::Submitted, od, &tx_hashes);
+        }
+    }
+
+    fn publish_activity(&self, status: TxStatus, od: &OperationalData, tx_hashes: &TxHashes) {
+        activity::publish(RelayActivity {
+            status,
+            tracking_id: od.tracking_id.to_string(),
+            chain_id: self.chain_id(),
+            port_id: self.port_id.clone(),
+            channel_id: self.channel_id.clone(),
+            counterparty_chain_id: self.counterparty_chain_id.clone(),
+            counterparty_port_id: self.counterparty_port_id.clone(),
+            counterparty_channel_id: self.counterparty_channel_id.clone(),
+            sequences: RelayActivity::sequences(od),
+            tx_hashes: RelayActivity::tx_hashes(tx_hashes),
+        });
+    }
+
     fn check_tx_events(&self, tx_hashes: &TxHashes) -> Result<Option<Vec<IbcEvent>>, RelayerError> {
         let mut all_events = Vec::new();
         for hash in &tx_hashes.0 {
@@ -203,6 +311,8 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
 
                                 match new_od.map(f) {
                                     Some(Ok(reply)) => {
+                                        self.forget(&pending);
+                                        self.publish_submitted(&reply, &pending.original_od);
                                         self.insert_new_pending_tx(reply, pending.original_od);
                                         Ok(None)
                                     }
@@ -212,12 +322,14 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                                     }
                                     None => {
                                         // No operational data was regenerated; nothing to resubmit
+                                        self.forget(&pending);
                                         Ok(None)
                                     }
                                 }
                             }
                             None => {
                                 // `clear_interval != 0` such that resubmission has been disabled
+                                self.forget(&pending);
                                 Ok(None)
                             }
                         }
@@ -251,6 +363,14 @@ impl<Chain: ChainHandle> PendingTxs<Chain> {
                         &self.counterparty_chain_id
                     );
 
+                    self.publish_activity(
+                        TxStatus::Confirmed,
+                        &pending.original_od,
+                        &pending.tx_hashes,
+                    );
+
+                    self.forget(&pending);
+
                     // Append the events corresponding to errors from the pending tx.
                     events.extend(pending.error_events);
 
@@ -7,6 +7,8 @@ use ibc_proto::google::protobuf::Any;
 use itertools::Itertools;
 use tracing::{debug, error, info, span, trace, warn, Level};
 
+use ibc_relayer_types::applications::ics29_fee::packet_fee::PacketFee;
+use ibc_relayer_types::clients::ics09_localhost::sentinel_proofs;
 use ibc_relayer_types::core::ics02_client::events::ClientMisbehaviour as ClientMisbehaviourEvent;
 use ibc_relayer_types::core::ics04_channel::channel::{ChannelEnd, Order, State as ChannelState};
 use ibc_relayer_types::core::ics04_channel::events::{SendPacket, WriteAcknowledgement};
@@ -14,9 +16,10 @@ use ibc_relayer_types::core::ics04_channel::msgs::{
     acknowledgement::MsgAcknowledgement, chan_close_confirm::MsgChannelCloseConfirm,
     recv_packet::MsgRecvPacket, timeout::MsgTimeout, timeout_on_close::MsgTimeoutOnClose,
 };
-use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType};
+use ibc_relayer_types::core::ics04_channel::packet::{Packet, PacketMsgType, Sequence};
 use ibc_relayer_types::core::ics24_host::identifier::{ChannelId, ClientId, ConnectionId, PortId};
 use ibc_relayer_types::events::{IbcEvent, WithBlockDataType};
+use ibc_relayer_types::proofs::Proofs;
 use ibc_relayer_types::signer::Signer;
 use ibc_relayer_types::timestamp::Timestamp;
 use ibc_relayer_types::tx_msg::Msg;
@@ -30,16 +33,18 @@ use crate::chain::requests::QueryChannelRequest;
 use crate::chain::requests::QueryClientEventRequest;
 use crate::chain::requests::QueryHeight;
 use crate::chain::requests::QueryHostConsensusStateRequest;
+use crate::chain::requests::QueryIncentivizedPacketsRequest;
 use crate::chain::requests::QueryNextSequenceReceiveRequest;
 use crate::chain::requests::QueryPacketCommitmentRequest;
 use crate::chain::requests::QueryTxRequest;
 use crate::chain::requests::QueryUnreceivedAcksRequest;
 use crate::chain::requests::QueryUnreceivedPacketsRequest;
-use crate::chain::requests::{IncludeProof, Qualified};
+use crate::chain::requests::{IncludeProof, PageRequest, Qualified};
 use crate::chain::tracking::TrackedMsgs;
 use crate::chain::tracking::TrackingId;
 use crate::channel::error::ChannelError;
 use crate::channel::Channel;
+use crate::config::filter::ChannelMinFees;
 use crate::event::monitor::EventBatch;
 use crate::event::IbcEventWithHeight;
 use crate::foreign_client::{ForeignClient, ForeignClientError};
@@ -53,6 +58,7 @@ use crate::link::packet_events::query_write_ack_events;
 use crate::link::pending::PendingTxs;
 use crate::link::relay_sender::{AsyncReply, SubmitReply};
 use crate::link::relay_summary::RelaySummary;
+use crate::link::store::PathStore;
 use crate::link::{pending, relay_sender};
 use crate::path::PathIdentifiers;
 use crate::telemetry;
@@ -62,6 +68,26 @@ use crate::util::queue::Queue;
 
 const MAX_RETRIES: usize = 5;
 
+/// The ICS-29 fees escrowed for the packets sent over the source channel,
+/// along with the minimum fees these packets must be incentivized with
+/// in order to be relayed.
+struct PacketIncentives {
+    min_fees: ChannelMinFees,
+    fees: HashMap<Sequence, Vec<PacketFee>>,
+}
+
+impl PacketIncentives {
+    fn is_satisfied_by(&self, packet: &Packet) -> bool {
+        let fees = self
+            .fees
+            .get(&packet.sequence)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        self.min_fees.is_satisfied_by(fees)
+    }
+}
+
 /// Whether or not to resubmit packets when pending transactions
 /// fail to process within the given timeout duration.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -106,6 +132,9 @@ pub struct RelayPath<ChainA: ChainHandle, ChainB: ChainHandle> {
     // transactions if [`confirm_txes`] is true.
     pending_txs_src: PendingTxs<ChainA>,
     pending_txs_dst: PendingTxs<ChainB>,
+
+    // Persisted state of this path, if persistence is enabled.
+    store: Option<PathStore>,
 }
 
 impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
@@ -148,11 +177,83 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             dst_operational_data: Queue::new(),
 
             confirm_txes: with_tx_confirmation,
-            pending_txs_src: PendingTxs::new(src_chain, src_channel_id, src_port_id, dst_chain_id),
-            pending_txs_dst: PendingTxs::new(dst_chain, dst_channel_id, dst_port_id, src_chain_id),
+            pending_txs_src: PendingTxs::new(
+                src_chain,
+                src_channel_id.clone(),
+                src_port_id.clone(),
+                dst_chain_id,
+                dst_channel_id.clone(),
+                dst_port_id.clone(),
+            ),
+            pending_txs_dst: PendingTxs::new(
+                dst_chain,
+                dst_channel_id,
+                dst_port_id,
+                src_chain_id,
+                src_channel_id,
+                src_port_id,
+            ),
+
+            store: None,
         })
     }
 
+    /// Persist the state of this path to the given store from now on.
+    ///
+    /// The confirmation of the transactions which were still pending when the store
+    /// was last updated is resumed, and the packets they carry are not relayed again
+    /// by packet clearing until these transactions are either confirmed or dropped.
+    pub fn restore_from(&mut self, store: PathStore) {
+        let pending = store.state().pending.len();
+
+        if self.confirm_txes {
+            self.pending_txs_src
+                .restore_from(store.clone(), OperationalDataTarget::Source);
+            self.pending_txs_dst
+                .restore_from(store.clone(), OperationalDataTarget::Destination);
+        } else if let Err(e) = store.clear_pending() {
+            // Without transaction confirmation, pending transactions are not tracked
+            warn!(
+                "failed to clear the pending transactions from the state store: {}",
+                e
+            );
+        }
+
+        info!(
+            file = %store.file().display(),
+            pending = %if self.confirm_txes { pending } else { 0 },
+            last_cleared_height = ?store.last_cleared_height(),
+            "restored relaying state",
+        );
+
+        self.store = Some(store);
+    }
+
+    /// Whether the pending packets were cleared less than `clear_interval` blocks
+    /// ago on the source chain, according to the persisted state of this path.
+    pub fn cleared_recently(&self, clear_interval: u64) -> bool {
+        let last_cleared_height = match self.store.as_ref().and_then(|s| s.last_cleared_height()) {
+            Some(height) => height,
+            None => return false,
+        };
+
+        // Packets are only cleared on start when the clear interval is 0
+        if clear_interval == 0 {
+            return false;
+        }
+
+        match self.src_latest_height() {
+            Ok(latest_height) => {
+                latest_height.revision_number() == last_cleared_height.revision_number()
+                    && latest_height
+                        .revision_height()
+                        .saturating_sub(last_cleared_height.revision_height())
+                        < clear_interval
+            }
+            Err(_) => false,
+        }
+    }
+
     pub fn src_chain(&self) -> &ChainA {
         self.channel.src_chain()
     }
@@ -312,6 +413,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     pub fn build_update_client_on_dst(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+        // The localhost client is updated by the chain itself at every block
+        if self.channel.is_localhost() {
+            return Ok(vec![]);
+        }
+
         let client = self.restore_dst_client();
         client
             .wait_and_build_update_client(height)
@@ -319,6 +425,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     pub fn build_update_client_on_src(&self, height: Height) -> Result<Vec<Any>, LinkError> {
+        if self.channel.is_localhost() {
+            return Ok(vec![]);
+        }
+
         let client = self.restore_src_client();
         client
             .wait_and_build_update_client(height)
@@ -330,10 +440,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         event: &IbcEventWithHeight,
     ) -> Result<Any, LinkError> {
         let src_channel_id = self.src_channel_id();
-        let proofs = self
-            .src_chain()
-            .build_channel_proofs(self.src_port_id(), src_channel_id, event.height)
-            .map_err(|e| LinkError::channel(ChannelError::channel_proof(e)))?;
+        let proofs = if self.channel.is_localhost() {
+            sentinel_proofs(event.height.increment())
+        } else {
+            self.src_chain()
+                .build_channel_proofs(self.src_port_id(), src_channel_id, event.height)
+                .map_err(|e| LinkError::channel(ChannelError::channel_proof(e)))?
+        };
 
         // Build the domain type message
         let new_msg = MsgChannelCloseConfirm {
@@ -431,6 +544,17 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
 
         debug!(height = ?clear_height, "done relaying pending packets at clear height");
 
+        if let Some(store) = &self.store {
+            let cleared_height = match height {
+                Some(height) => height,
+                None => self.src_latest_height()?,
+            };
+
+            if let Err(e) = store.set_last_cleared_height(cleared_height) {
+                warn!("failed to persist the packet clearing height: {}", e);
+            }
+        }
+
         Ok(())
     }
 
@@ -444,6 +568,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         )
         .entered();
 
+        telemetry!({
+            self.trace_relayed_packets(&batch.events, batch.tracking_id);
+            self.record_packet_latencies(&batch.events);
+        });
+
         // Collect relevant events from the incoming batch & adjust their height.
         let events = self.filter_relaying_events(batch.events, batch.tracking_id);
 
@@ -463,6 +592,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         &self,
         events: TrackedEvents,
     ) -> Result<(), LinkError> {
+        telemetry!(self.trace_packets(
+            ibc_telemetry::PacketStage::EventSeen,
+            events.tracking_id(),
+            events.events().iter(),
+        ));
+
         // Obtain the operational data for the source chain (mostly timeout packets) and for the
         // destination chain (e.g., receive packet messages).
         let (src_opt, dst_opt) = self.generate_operational_data(events)?;
@@ -471,12 +606,31 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             self.schedule_operational_data(src_od)?;
         }
         if let Some(dst_od) = dst_opt {
+            telemetry!(self.trace_packets(
+                ibc_telemetry::PacketStage::OperationalDataBuilt,
+                dst_od.tracking_id,
+                dst_od.batch.iter().map(|msg| &msg.event_with_height),
+            ));
+
             self.schedule_operational_data(dst_od)?;
         }
 
         Ok(())
     }
 
+    /// Schedules the timeout messages of the packets sent in the given events
+    /// which have expired on the destination chain, leaving out all other messages.
+    pub(crate) fn timeout_events_to_operational_data(
+        &self,
+        events: TrackedEvents,
+    ) -> Result<(), LinkError> {
+        if let Some(src_od) = self.generate_timeout_operational_data(events)? {
+            self.schedule_operational_data(src_od)?;
+        }
+
+        Ok(())
+    }
+
     /// Generates operational data out of a set of events.
     /// Handles building operational data targeting both the destination and source chains.
     ///
@@ -505,10 +659,12 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let dst_latest_info = self
             .dst_chain()
             .query_application_status()
-            .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
 
         let dst_latest_height = dst_latest_info.height;
 
+        let incentives = self.query_packet_incentives(input)?;
+
         // Operational data targeting the source chain (e.g., Timeout packets)
         let mut src_od = OperationalData::new(
             dst_latest_height,
@@ -556,12 +712,22 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                     if self.send_packet_event_handled(event)? {
                         debug!(?event, "SendPacket event has already been handled");
 
+                        (None, None)
+                    } else if self.ordered_channel() && !src_od.batch.is_empty() {
+                        // The timeout of an earlier packet closes the ordered channel, after
+                        // which the following packets can only be timed out on close.
+                        debug!(
+                            packet = %event.packet,
+                            "skipping packet which follows a timed out packet on an ordered channel"
+                        );
+
                         (None, None)
                     } else {
                         self.build_recv_or_timeout_from_send_packet_event(
                             event,
                             &dst_latest_info,
                             event_with_height.height,
+                            incentives.as_ref(),
                         )?
                     }
                 }
@@ -619,6 +785,66 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok((src_od, dst_od))
     }
 
+    /// Generates operational data targeting the source chain out of a set of
+    /// `SendPacket` events, with a timeout message (`MsgTimeoutOnClose` or `MsgTimeout`)
+    /// for each packet which expired on the destination chain.
+    fn generate_timeout_operational_data(
+        &self,
+        events: TrackedEvents,
+    ) -> Result<Option<OperationalData>, LinkError> {
+        let _span = span!(
+            Level::ERROR,
+            "generate_timeout_operational_data",
+            tracking_id = %events.tracking_id(),
+        )
+        .entered();
+
+        let input = events.events();
+        if input.is_empty() {
+            return Ok(None);
+        }
+
+        let dst_latest_info = self
+            .dst_chain()
+            .query_application_status()
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+        let mut src_od = OperationalData::new(
+            dst_latest_info.height,
+            OperationalDataTarget::Source,
+            events.tracking_id(),
+            self.channel.connection_delay,
+        );
+
+        for event_with_height in input {
+            let event = match &event_with_height.event {
+                IbcEvent::SendPacket(event) => event,
+                _ => continue,
+            };
+
+            if self.send_packet_event_handled(event)? {
+                debug!(?event, "SendPacket event has already been handled");
+                continue;
+            }
+
+            // For Ordered channels a single timeout message should be sent as this closes the channel.
+            if self.ordered_channel() && !src_od.batch.is_empty() {
+                break;
+            }
+
+            if let Some(msg) = self.build_timeout_from_send_packet_event(event, &dst_latest_info)? {
+                trace!(%msg.type_url, event = %event_with_height, "collected event");
+
+                src_od.batch.push(TransitMessage {
+                    event_with_height: event_with_height.clone(),
+                    msg,
+                });
+            }
+        }
+
+        Ok(Some(src_od).filter(|od| !od.batch.is_empty()))
+    }
+
     /// Relays an [`OperationalData`] using a specific
     /// sender, which implements [`relay_sender::Submit`].
     pub(crate) fn relay_from_operational_data<S: relay_sender::Submit>(
@@ -651,6 +877,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
                             port_id,
                             &counterparty,
                         );
+
+                        if odata.target == OperationalDataTarget::Destination {
+                            self.trace_packets(
+                                ibc_telemetry::PacketStage::TxSubmitted,
+                                odata.tracking_id,
+                                odata.batch.iter().map(|msg| &msg.event_with_height),
+                            );
+                        }
                     });
 
                     return Ok(reply);
@@ -782,6 +1016,13 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     }
 
     fn enqueue_pending_tx(&self, reply: AsyncReply, odata: OperationalData) {
+        match odata.target {
+            OperationalDataTarget::Source => self.pending_txs_src.publish_submitted(&reply, &odata),
+            OperationalDataTarget::Destination => {
+                self.pending_txs_dst.publish_submitted(&reply, &odata)
+            }
+        }
+
         if !self.confirm_txes {
             return;
         }
@@ -941,6 +1182,11 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         src_chain_height: Height,
         tracking_id: TrackingId,
     ) -> Result<Height, LinkError> {
+        // The localhost client always tracks the latest height of the chain hosting it
+        if self.channel.is_localhost() {
+            return Ok(src_chain_height);
+        }
+
         self.do_update_client_dst(src_chain_height, tracking_id, MAX_RETRIES)
     }
 
@@ -1008,6 +1254,10 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         dst_chain_height: Height,
         tracking_id: TrackingId,
     ) -> Result<Height, LinkError> {
+        if self.channel.is_localhost() {
+            return Ok(dst_chain_height);
+        }
+
         self.do_update_client_src(dst_chain_height, tracking_id, MAX_RETRIES)
     }
 
@@ -1092,6 +1342,25 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             unreceived_packets(self.dst_chain(), self.src_chain(), &self.path_id)
                 .map_err(LinkError::supervisor)?;
 
+        // On ordered channels, packets can only be received in order.
+        let sequences = if self.ordered_channel() {
+            self.ordered_sequences_to_relay(sequences)?
+        } else {
+            sequences
+        };
+
+        // Skip the packets carried by transactions still awaiting confirmation.
+        let sequences = match &self.store {
+            Some(store) => {
+                let (in_flight, _) = store.in_flight_sequences();
+                sequences
+                    .into_iter()
+                    .filter(|seq| !in_flight.contains(seq))
+                    .collect()
+            }
+            None => sequences,
+        };
+
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
         // Skip: no relevant events found.
@@ -1129,6 +1398,69 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Selects, out of the given unreceived packets, the ones which can be relayed
+    /// over an ordered channel, in order, and reports the sequence number of the packet
+    /// at the head of the line, which blocks all the others until it is received or
+    /// timed out.
+    ///
+    /// No packets are selected if the packet expected next by the destination chain
+    /// has no commitment on the source chain, since the channel cannot make progress.
+    fn ordered_sequences_to_relay(
+        &self,
+        sequences: Vec<Sequence>,
+    ) -> Result<Vec<Sequence>, LinkError> {
+        if sequences.is_empty() {
+            return Ok(sequences);
+        }
+
+        let (next_sequence, _) = self
+            .dst_chain()
+            .query_next_sequence_receive(
+                QueryNextSequenceReceiveRequest {
+                    port_id: self.dst_port_id().clone(),
+                    channel_id: self.dst_channel_id().clone(),
+                    height: QueryHeight::Latest,
+                },
+                IncludeProof::No,
+            )
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
+
+        let (sequences, has_gap) = in_order_from(next_sequence, sequences);
+
+        if sequences.is_empty() {
+            return Ok(sequences);
+        }
+
+        telemetry!(
+            backlog_head_of_line,
+            next_sequence.into(),
+            &self.src_chain().id(),
+            self.src_channel_id(),
+            self.src_port_id(),
+            &self.dst_chain().id(),
+        );
+
+        if has_gap {
+            warn!(
+                src_chain = %self.src_chain().id(),
+                dst_chain = %self.dst_chain().id(),
+                %next_sequence,
+                sequences = %sequences.iter().copied().collated().format(", "),
+                "ordered channel is blocked: the packet expected next by the destination chain \
+                has no commitment on the source chain, the following packets cannot be relayed",
+            );
+
+            return Ok(vec![]);
+        }
+
+        debug!(
+            head_of_line = %next_sequence,
+            "relaying packets over ordered channel from the head of the line",
+        );
+
+        Ok(sequences)
+    }
+
     /// Schedules the relaying of [`MsgAcknowledgement`] messages.
     ///
     /// The `opt_query_height` parameter allows to optionally use a specific height on the source
@@ -1150,6 +1482,18 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             unreceived_acknowledgements(self.dst_chain(), self.src_chain(), &self.path_id)
                 .map_err(LinkError::supervisor)?;
 
+        // Skip the acknowledgements carried by transactions still awaiting confirmation.
+        let sequences = match &self.store {
+            Some(store) => {
+                let (_, in_flight) = store.in_flight_sequences();
+                sequences
+                    .into_iter()
+                    .filter(|seq| !in_flight.contains(seq))
+                    .collect()
+            }
+            None => sequences,
+        };
+
         let query_height = opt_query_height.unwrap_or(src_response_height);
 
         // Skip: no relevant events found.
@@ -1180,17 +1524,38 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         Ok(())
     }
 
+    /// Builds the proofs of a packet message on the given chain at the given height.
+    ///
+    /// The localhost client reads the packet commitments, receipts and acknowledgements
+    /// from the store of the chain, so channels on top of the localhost connection
+    /// only carry the sentinel proof.
+    fn build_packet_proofs<Chain: ChainHandle>(
+        &self,
+        chain: &Chain,
+        packet_type: PacketMsgType,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+        sequence: Sequence,
+        height: Height,
+    ) -> Result<Proofs, LinkError> {
+        if self.channel.is_localhost() {
+            return Ok(sentinel_proofs(height.increment()));
+        }
+
+        chain
+            .build_packet_proofs(packet_type, port_id, channel_id, sequence, height)
+            .map_err(|e| LinkError::packet_proofs_constructor(chain.id(), e))
+    }
+
     fn build_recv_packet(&self, packet: &Packet, height: Height) -> Result<Option<Any>, LinkError> {
-        let proofs = self
-            .src_chain()
-            .build_packet_proofs(
-                PacketMsgType::Recv,
-                &packet.source_port,
-                &packet.source_channel,
-                packet.sequence,
-                height,
-            )
-            .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
+        let proofs = self.build_packet_proofs(
+            self.src_chain(),
+            PacketMsgType::Recv,
+            &packet.source_port,
+            &packet.source_channel,
+            packet.sequence,
+            height,
+        )?;
 
         let msg = MsgRecvPacket::new(packet.clone(), proofs.clone(), self.dst_signer()?);
 
@@ -1206,16 +1571,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
     ) -> Result<Option<Any>, LinkError> {
         let packet = event.packet.clone();
 
-        let proofs = self
-            .src_chain()
-            .build_packet_proofs(
-                PacketMsgType::Ack,
-                &packet.destination_port,
-                &packet.destination_channel,
-                packet.sequence,
-                height,
-            )
-            .map_err(|e| LinkError::packet_proofs_constructor(self.src_chain().id(), e))?;
+        let proofs = self.build_packet_proofs(
+            self.src_chain(),
+            PacketMsgType::Ack,
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+            height,
+        )?;
 
         let msg = MsgAcknowledgement::new(
             packet,
@@ -1256,16 +1619,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
             (PacketMsgType::TimeoutUnordered, packet.sequence)
         };
 
-        let proofs = self
-            .dst_chain()
-            .build_packet_proofs(
-                packet_type,
-                &packet.destination_port,
-                &packet.destination_channel,
-                next_sequence_received,
-                height,
-            )
-            .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
+        let proofs = self.build_packet_proofs(
+            self.dst_chain(),
+            packet_type,
+            &packet.destination_port,
+            &packet.destination_channel,
+            next_sequence_received,
+            height,
+        )?;
 
         let msg = MsgTimeout::new(
             packet.clone(),
@@ -1284,16 +1645,14 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         packet: &Packet,
         height: Height,
     ) -> Result<Option<Any>, LinkError> {
-        let proofs = self
-            .dst_chain()
-            .build_packet_proofs(
-                PacketMsgType::TimeoutOnClose,
-                &packet.destination_port,
-                &packet.destination_channel,
-                packet.sequence,
-                height,
-            )
-            .map_err(|e| LinkError::packet_proofs_constructor(self.dst_chain().id(), e))?;
+        let proofs = self.build_packet_proofs(
+            self.dst_chain(),
+            PacketMsgType::TimeoutOnClose,
+            &packet.destination_port,
+            &packet.destination_channel,
+            packet.sequence,
+            height,
+        )?;
 
         let msg = MsgTimeoutOnClose::new(
             packet.clone(),
@@ -1330,15 +1689,72 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         event: &SendPacket,
         dst_info: &ChainStatus,
         height: Height,
+        incentives: Option<&PacketIncentives>,
     ) -> Result<(Option<Any>, Option<Any>), LinkError> {
         let timeout = self.build_timeout_from_send_packet_event(event, dst_info)?;
         if timeout.is_some() {
             Ok((None, timeout))
+        } else if let Some(incentives) =
+            incentives.filter(|incentives| !incentives.is_satisfied_by(&event.packet))
+        {
+            // The packet will be reconsidered when clearing packets, by which time
+            // its fees might have been increased.
+            debug!(
+                packet = %event.packet,
+                min_fees = %incentives.min_fees,
+                "skipping packet which is not sufficiently incentivized",
+            );
+
+            Ok((None, None))
         } else {
             Ok((self.build_recv_packet(&event.packet, height)?, None))
         }
     }
 
+    /// Queries the fees escrowed for the packets sent over the source channel,
+    /// if there are any packets to relay in the given events and the source
+    /// chain requires a minimum fee to relay packets over this channel.
+    fn query_packet_incentives(
+        &self,
+        events: &[IbcEventWithHeight],
+    ) -> Result<Option<PacketIncentives>, LinkError> {
+        if !events
+            .iter()
+            .any(|ev| matches!(ev.event, IbcEvent::SendPacket(_)))
+        {
+            return Ok(None);
+        }
+
+        let min_fees = self
+            .src_chain()
+            .config()
+            .map_err(LinkError::relayer)?
+            .fee_filter
+            .min_fees(self.src_port_id(), self.src_channel_id())
+            .cloned();
+
+        let min_fees = match min_fees {
+            Some(min_fees) => min_fees,
+            None => return Ok(None),
+        };
+
+        let packets = self
+            .src_chain()
+            .query_incentivized_packets(QueryIncentivizedPacketsRequest {
+                port_id: self.src_port_id().clone(),
+                channel_id: self.src_channel_id().clone(),
+                pagination: Some(PageRequest::all()),
+            })
+            .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
+
+        let fees = packets
+            .into_iter()
+            .map(|packet| (packet.packet_id.sequence, packet.packet_fees))
+            .collect();
+
+        Ok(Some(PacketIncentives { min_fees, fees }))
+    }
+
     /// Drives the relaying of elapsed operational data items meant for
     /// a specified target chain forward.
     ///
@@ -1530,7 +1946,7 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         let dst_status = self
             .dst_chain()
             .query_application_status()
-            .map_err(|e| LinkError::query(self.src_chain().id(), e))?;
+            .map_err(|e| LinkError::query(self.dst_chain().id(), e))?;
 
         let dst_current_height = dst_status.height;
 
@@ -1817,6 +2233,138 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 
+    /// Records that the packets sent by the source chain, among the given events,
+    /// reached the given stage of their lifecycle.
+    #[cfg(feature = "telemetry")]
+    fn trace_packets<'a>(
+        &self,
+        stage: ibc_telemetry::PacketStage,
+        tracking_id: TrackingId,
+        events_with_heights: impl Iterator<Item = &'a IbcEventWithHeight>,
+    ) {
+        for event_with_height in events_with_heights {
+            if let IbcEvent::SendPacket(send_packet_ev) = &event_with_height.event {
+                let packet = &send_packet_ev.packet;
+
+                ibc_telemetry::global().packet_stage(
+                    stage,
+                    tracking_id,
+                    packet.sequence.into(),
+                    &self.src_chain().id(),
+                    &packet.source_channel,
+                    &packet.source_port,
+                );
+            }
+        }
+    }
+
+    /// Records that the packets received, resp. acknowledged, among the given events
+    /// emitted by the source chain, were relayed. The packets received by the source
+    /// chain were sent by the destination chain, and are thus on the reverse path.
+    #[cfg(feature = "telemetry")]
+    fn trace_relayed_packets(&self, events: &[IbcEventWithHeight], tracking_id: TrackingId) {
+        use ibc_telemetry::PacketStage;
+
+        for event_with_height in events {
+            let (stage, packet, src_chain_id) = match &event_with_height.event {
+                IbcEvent::ReceivePacket(recv_packet_ev)
+                    if recv_packet_ev.dst_channel_id() == self.src_channel_id()
+                        && recv_packet_ev.dst_port_id() == self.src_port_id() =>
+                {
+                    (
+                        PacketStage::TxConfirmed,
+                        &recv_packet_ev.packet,
+                        self.dst_chain().id(),
+                    )
+                }
+                IbcEvent::AcknowledgePacket(ack_packet_ev)
+                    if ack_packet_ev.src_channel_id() == self.src_channel_id()
+                        && ack_packet_ev.src_port_id() == self.src_port_id() =>
+                {
+                    (
+                        PacketStage::AckRelayed,
+                        &ack_packet_ev.packet,
+                        self.src_chain().id(),
+                    )
+                }
+                _ => continue,
+            };
+
+            ibc_telemetry::global().packet_stage(
+                stage,
+                tracking_id,
+                packet.sequence.into(),
+                &src_chain_id,
+                &packet.source_channel,
+                &packet.source_port,
+            );
+        }
+    }
+
+    /// Records the timestamps of the blocks in which the packets sent over this path, resp.
+    /// over the reverse path, among the given events emitted by the source chain, were sent,
+    /// resp. received, or acknowledged, from which the latencies of the packets are computed.
+    #[cfg(feature = "telemetry")]
+    fn record_packet_latencies(&self, events: &[IbcEventWithHeight]) {
+        let src_chain_id = self.src_chain().id();
+
+        for event_with_height in events {
+            let timestamp = || block_timestamp(self.src_chain(), event_with_height.height);
+
+            match &event_with_height.event {
+                IbcEvent::SendPacket(send_packet_ev)
+                    if send_packet_ev.src_channel_id() == self.src_channel_id()
+                        && send_packet_ev.src_port_id() == self.src_port_id() =>
+                {
+                    let packet = &send_packet_ev.packet;
+
+                    if let Some(send_time) = timestamp() {
+                        ibc_telemetry::global().packet_sent(
+                            packet.sequence.into(),
+                            &src_chain_id,
+                            &packet.source_channel,
+                            &packet.source_port,
+                            send_time,
+                        );
+                    }
+                }
+                IbcEvent::ReceivePacket(recv_packet_ev)
+                    if recv_packet_ev.dst_channel_id() == self.src_channel_id()
+                        && recv_packet_ev.dst_port_id() == self.src_port_id() =>
+                {
+                    let packet = &recv_packet_ev.packet;
+
+                    if let Some(recv_time) = timestamp() {
+                        ibc_telemetry::global().packet_received(
+                            packet.sequence.into(),
+                            &self.dst_chain().id(),
+                            &packet.source_channel,
+                            &packet.source_port,
+                            recv_time,
+                        );
+                    }
+                }
+                IbcEvent::AcknowledgePacket(ack_packet_ev)
+                    if ack_packet_ev.src_channel_id() == self.src_channel_id()
+                        && ack_packet_ev.src_port_id() == self.src_port_id() =>
+                {
+                    let packet = &ack_packet_ev.packet;
+
+                    if let Some(ack_time) = timestamp() {
+                        ibc_telemetry::global().packet_acknowledged(
+                            packet.sequence.into(),
+                            &src_chain_id,
+                            &packet.source_channel,
+                            &packet.source_port,
+                            ack_time,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
     #[cfg(feature = "telemetry")]
     fn record_cleared_acknowledgments<'a>(
         &self,
@@ -1836,3 +2384,58 @@ impl<ChainA: ChainHandle, ChainB: ChainHandle> RelayPath<ChainA, ChainB> {
         }
     }
 }
+
+/// The timestamp of the block at the given height of the given chain, which
+/// is only queried if it is not already known by the telemetry state.
+#[cfg(feature = "telemetry")]
+fn block_timestamp(chain: &impl ChainHandle, height: Height) -> Option<Timestamp> {
+    ibc_telemetry::global().block_timestamp(&chain.id(), height.revision_height(), || {
+        chain
+            .query_host_consensus_state(QueryHostConsensusStateRequest {
+                height: QueryHeight::Specific(height),
+            })
+            .map(|consensus_state| consensus_state.timestamp())
+            .map_err(
+                |e| warn!(chain = %chain.id(), %height, "failed to query block timestamp: {}", e),
+            )
+            .ok()
+    })
+}
+
+/// Sorts the sequences of the packets which can still be received over an ordered channel,
+/// given the next sequence number expected by the destination chain, dropping the others.
+/// Also returns whether there is a gap, i.e. whether the packet expected next is missing.
+fn in_order_from(next_sequence: Sequence, mut sequences: Vec<Sequence>) -> (Vec<Sequence>, bool) {
+    sequences.retain(|seq| *seq >= next_sequence);
+    sequences.sort_unstable();
+
+    let has_gap = sequences
+        .first()
+        .map_or(false, |first| *first != next_sequence);
+
+    (sequences, has_gap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seqs(sequences: &[u64]) -> Vec<Sequence> {
+        sequences.iter().copied().map(Sequence::from).collect()
+    }
+
+    #[test]
+    fn ordered_channel_head_of_line() {
+        assert_eq!(
+            in_order_from(5.into(), seqs(&[7, 5, 6])),
+            (seqs(&[5, 6, 7]), false)
+        );
+
+        assert_eq!(
+            in_order_from(5.into(), seqs(&[3, 4, 6, 7])),
+            (seqs(&[6, 7]), true)
+        );
+
+        assert_eq!(in_order_from(5.into(), seqs(&[3, 4])), (vec![], false));
+    }
+}
@@ -0,0 +1,290 @@
+//! On-disk persistence of the state of a relaying path, so that
+//! a restarted relayer can pick up where it left off.
+//!
+//! The state of each path is stored as a JSON file under the state
+//! directory configured in the `[state]` section of the configuration.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use flex_error::{define_error, TraceError};
+use serde::{Deserialize, Serialize};
+use tendermint_rpc::abci::transaction;
+
+use ibc_relayer_types::core::ics04_channel::packet::Sequence;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+use ibc_relayer_types::events::IbcEvent;
+use ibc_relayer_types::Height;
+
+use crate::config::StateConfig;
+use crate::link::operational_data::{OperationalData, OperationalDataTarget};
+
+/// The default folder, relative to the home directory, in which the state is stored.
+pub const STATE_DEFAULT_FOLDER: &str = ".hermes/state/";
+
+define_error! {
+    StoreError {
+        Io
+            { path: PathBuf }
+            [ TraceError<std::io::Error> ]
+            |e| { format!("I/O error on relayer state file {}", e.path.display()) },
+
+        Decode
+            { path: PathBuf }
+            [ TraceError<serde_json::Error> ]
+            |e| { format!("invalid relayer state file {}", e.path.display()) },
+
+        Encode
+            [ TraceError<serde_json::Error> ]
+            |_| { "failed to encode relayer state" },
+
+        HomeLocationUnavailable
+            |_| { "home location is unavailable" },
+    }
+}
+
+/// The directory in which the state is stored.
+pub fn state_dir(config: &StateConfig) -> Result<PathBuf, StoreError> {
+    match &config.dir {
+        Some(dir) => Ok(dir.clone()),
+        None => Ok(dirs_next::home_dir()
+            .ok_or_else(StoreError::home_location_unavailable)?
+            .join(STATE_DEFAULT_FOLDER)),
+    }
+}
+
+/// The persisted state of a relaying path.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PathState {
+    /// The transactions submitted by the relayer that were not yet confirmed.
+    #[serde(default)]
+    pub pending: Vec<PendingTxRecord>,
+    /// The height of the source chain at which pending packets were last cleared.
+    #[serde(default)]
+    pub last_cleared_height: Option<Height>,
+}
+
+/// A record of a submitted transaction awaiting confirmation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingTxRecord {
+    /// The tracking identifier of the operational data the transaction was built from.
+    pub tracking_id: String,
+    /// The chain the transaction was submitted to.
+    pub target: OperationalDataTarget,
+    /// The height at which the proofs for the messages in the transaction were queried.
+    pub proofs_height: Height,
+    pub tx_hashes: Vec<transaction::Hash>,
+    pub submitted_at: SystemTime,
+    /// Sequence numbers of the packets sent by the source chain which
+    /// the transaction either receives or times out.
+    #[serde(default)]
+    pub packets: Vec<Sequence>,
+    /// Sequence numbers of the packets sent by the destination chain
+    /// which the transaction acknowledges.
+    #[serde(default)]
+    pub acks: Vec<Sequence>,
+}
+
+impl PendingTxRecord {
+    pub fn new(odata: &OperationalData, tx_hashes: Vec<transaction::Hash>) -> Self {
+        let mut packets = Vec::new();
+        let mut acks = Vec::new();
+
+        for msg in &odata.batch {
+            match &msg.event_with_height.event {
+                IbcEvent::SendPacket(ev) => packets.push(ev.packet.sequence),
+                IbcEvent::WriteAcknowledgement(ev) => acks.push(ev.packet.sequence),
+                _ => {}
+            }
+        }
+
+        Self {
+            tracking_id: odata.tracking_id.to_string(),
+            target: odata.target,
+            proofs_height: odata.proofs_height,
+            tx_hashes,
+            submitted_at: SystemTime::now(),
+            packets,
+            acks,
+        }
+    }
+}
+
+/// A handle to the persisted state of a relaying path.
+///
+/// Every update is written through to disk.
+#[derive(Clone, Debug)]
+pub struct PathStore {
+    file: PathBuf,
+    state: Arc<Mutex<PathState>>,
+}
+
+impl PathStore {
+    /// Open the store for the path starting at the given port and channel of the
+    /// source chain, loading the state persisted by a previous run, if any.
+    pub fn open(
+        config: &StateConfig,
+        chain_id: &ChainId,
+        port_id: &PortId,
+        channel_id: &ChannelId,
+    ) -> Result<Self, StoreError> {
+        let file = state_dir(config)?
+            .join(chain_id.as_str())
+            .join(format!("{}_{}.json", port_id, channel_id));
+
+        Self::open_file(file)
+    }
+
+    fn open_file(file: PathBuf) -> Result<Self, StoreError> {
+        let state = if file.exists() {
+            let contents =
+                fs::read_to_string(&file).map_err(|e| StoreError::io(file.clone(), e))?;
+
+            serde_json::from_str(&contents).map_err(|e| StoreError::decode(file.clone(), e))?
+        } else {
+            PathState::default()
+        };
+
+        Ok(Self {
+            file,
+            state: Arc::new(Mutex::new(state)),
+        })
+    }
+
+    /// The path of the file backing this store.
+    pub fn file(&self) -> &Path {
+        &self.file
+    }
+
+    /// A snapshot of the current state.
+    pub fn state(&self) -> PathState {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// Record a newly submitted transaction.
+    pub fn insert_pending(&self, record: PendingTxRecord) -> Result<(), StoreError> {
+        self.update(|state| state.pending.push(record))
+    }
+
+    /// Forget about the transaction with the given hashes, once it has either
+    /// been confirmed or given up on.
+    pub fn remove_pending(&self, tx_hashes: &[transaction::Hash]) -> Result<(), StoreError> {
+        self.update(|state| state.pending.retain(|record| record.tx_hashes != tx_hashes))
+    }
+
+    /// Forget about all the transactions awaiting confirmation.
+    pub fn clear_pending(&self) -> Result<(), StoreError> {
+        self.update(|state| state.pending.clear())
+    }
+
+    /// The sequence numbers of the packets sent by the source chain and of
+    /// the packets sent by the destination chain, respectively, which are
+    /// being relayed by a transaction awaiting confirmation.
+    pub fn in_flight_sequences(&self) -> (BTreeSet<Sequence>, BTreeSet<Sequence>) {
+        let state = self.state.lock().unwrap();
+
+        let packets = state
+            .pending
+            .iter()
+            .flat_map(|record| record.packets.iter().copied())
+            .collect();
+
+        let acks = state
+            .pending
+            .iter()
+            .flat_map(|record| record.acks.iter().copied())
+            .collect();
+
+        (packets, acks)
+    }
+
+    pub fn last_cleared_height(&self) -> Option<Height> {
+        self.state.lock().unwrap().last_cleared_height
+    }
+
+    pub fn set_last_cleared_height(&self, height: Height) -> Result<(), StoreError> {
+        self.update(|state| state.last_cleared_height = Some(height))
+    }
+
+    fn update(&self, f: impl FnOnce(&mut PathState)) -> Result<(), StoreError> {
+        let mut state = self.state.lock().unwrap();
+        f(&mut state);
+
+        let contents = serde_json::to_string_pretty(&*state).map_err(StoreError::encode)?;
+
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir).map_err(|e| StoreError::io(dir.to_path_buf(), e))?;
+        }
+
+        // Write to a temporary file first so that a crash
+        // cannot leave behind a partially written state.
+        let tmp_file = self.file.with_extension("json.tmp");
+
+        fs::write(&tmp_file, contents).map_err(|e| StoreError::io(tmp_file.clone(), e))?;
+        fs::rename(&tmp_file, &self.file).map_err(|e| StoreError::io(self.file.clone(), e))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    use tempfile::TempDir;
+
+    fn record(hash: &str, packets: Vec<u64>, acks: Vec<u64>) -> PendingTxRecord {
+        PendingTxRecord {
+            tracking_id: "test".to_string(),
+            target: OperationalDataTarget::Destination,
+            proofs_height: Height::new(0, 10).unwrap(),
+            tx_hashes: vec![transaction::Hash::from_str(hash).unwrap()],
+            submitted_at: SystemTime::now(),
+            packets: packets.into_iter().map(Sequence::from).collect(),
+            acks: acks.into_iter().map(Sequence::from).collect(),
+        }
+    }
+
+    #[test]
+    fn persist_and_reload_path_state() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("transfer_channel-0.json");
+
+        let hash_a = "A".repeat(64);
+        let hash_b = "B".repeat(64);
+
+        let store = PathStore::open_file(file.clone()).unwrap();
+        store
+            .insert_pending(record(&hash_a, vec![1, 2], vec![]))
+            .unwrap();
+        store
+            .insert_pending(record(&hash_b, vec![3], vec![7]))
+            .unwrap();
+        store
+            .set_last_cleared_height(Height::new(0, 42).unwrap())
+            .unwrap();
+
+        let reloaded = PathStore::open_file(file.clone()).unwrap();
+        assert_eq!(reloaded.state().pending.len(), 2);
+        assert_eq!(
+            reloaded.last_cleared_height(),
+            Some(Height::new(0, 42).unwrap())
+        );
+
+        let (packets, acks) = reloaded.in_flight_sequences();
+        assert_eq!(packets, [1, 2, 3].into_iter().map(Sequence::from).collect());
+        assert_eq!(acks, [7].into_iter().map(Sequence::from).collect());
+
+        let hash_a = transaction::Hash::from_str(&hash_a).unwrap();
+        reloaded.remove_pending(&[hash_a]).unwrap();
+
+        let reloaded = PathStore::open_file(file).unwrap();
+        let (packets, _) = reloaded.in_flight_sequences();
+        assert_eq!(packets, [3].into_iter().map(Sequence::from).collect());
+    }
+}
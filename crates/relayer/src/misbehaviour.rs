@@ -1,4 +1,7 @@
 use ibc_proto::{google::protobuf::Any, protobuf::Protobuf};
+use ibc_relayer_types::clients::ics06_solomachine::misbehaviour::{
+    Misbehaviour as SmMisbehaviour, SOLOMACHINE_MISBEHAVIOUR_TYPE_URL,
+};
 use ibc_relayer_types::clients::ics07_tendermint::misbehaviour::{
     Misbehaviour as TmMisbehaviour, TENDERMINT_MISBEHAVIOR_TYPE_URL,
 };
@@ -21,10 +24,42 @@ pub struct MisbehaviourEvidence {
     pub supporting_headers: Vec<AnyHeader>,
 }
 
+impl core::fmt::Display for MisbehaviourEvidence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.misbehaviour)
+    }
+}
+
+/// Evidence that a consensus state of a client conflicts with the chain it tracks.
+///
+/// It consists of the header of the chain at the height of the conflicting consensus
+/// state, verified from a consensus state of the client which matches the chain, and of
+/// the headers supporting that verification. Updating the client with these headers
+/// freezes it, since the client already holds a different consensus state at that height.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConflictingHeaderEvidence {
+    pub client_id: ClientId,
+    pub conflicting_height: Height,
+    pub trusted_height: Height,
+    pub header: AnyHeader,
+    pub supporting_headers: Vec<AnyHeader>,
+}
+
+impl core::fmt::Display for ConflictingHeaderEvidence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "ConflictingHeader {{ client_id: {}, conflicting_height: {}, trusted_height: {} }}",
+            self.client_id, self.conflicting_height, self.trusted_height
+        )
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum AnyMisbehaviour {
     Tendermint(TmMisbehaviour),
+    Solomachine(SmMisbehaviour),
 
     #[cfg(test)]
     Mock(MockMisbehaviour),
@@ -34,6 +69,7 @@ impl Misbehaviour for AnyMisbehaviour {
     fn client_id(&self) -> &ClientId {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.client_id(),
+            Self::Solomachine(misbehaviour) => misbehaviour.client_id(),
 
             #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.client_id(),
@@ -43,6 +79,7 @@ impl Misbehaviour for AnyMisbehaviour {
     fn height(&self) -> Height {
         match self {
             Self::Tendermint(misbehaviour) => misbehaviour.height(),
+            Self::Solomachine(misbehaviour) => misbehaviour.height(),
 
             #[cfg(test)]
             Self::Mock(misbehaviour) => misbehaviour.height(),
@@ -61,6 +98,10 @@ impl TryFrom<Any> for AnyMisbehaviour {
                 TmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
             )),
 
+            SOLOMACHINE_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Solomachine(
+                SmMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
+            )),
+
             #[cfg(test)]
             MOCK_MISBEHAVIOUR_TYPE_URL => Ok(AnyMisbehaviour::Mock(
                 MockMisbehaviour::decode_vec(&raw.value).map_err(Error::decode_raw_misbehaviour)?,
@@ -81,6 +122,13 @@ impl From<AnyMisbehaviour> for Any {
                     .expect("encoding to `Any` from `AnyMisbehavior::Tendermint`"),
             },
 
+            AnyMisbehaviour::Solomachine(misbehaviour) => Any {
+                type_url: SOLOMACHINE_MISBEHAVIOUR_TYPE_URL.to_string(),
+                value: misbehaviour
+                    .encode_vec()
+                    .expect("encoding to `Any` from `AnyMisbehavior::Solomachine`"),
+            },
+
             #[cfg(test)]
             AnyMisbehaviour::Mock(misbehaviour) => Any {
                 type_url: MOCK_MISBEHAVIOUR_TYPE_URL.to_string(),
@@ -96,6 +144,7 @@ impl core::fmt::Display for AnyMisbehaviour {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             AnyMisbehaviour::Tendermint(tm) => write!(f, "{}", tm),
+            AnyMisbehaviour::Solomachine(sm) => write!(f, "{}", sm),
 
             #[cfg(test)]
             AnyMisbehaviour::Mock(mock) => write!(f, "{:?}", mock),
@@ -109,6 +158,12 @@ impl From<TmMisbehaviour> for AnyMisbehaviour {
     }
 }
 
+impl From<SmMisbehaviour> for AnyMisbehaviour {
+    fn from(misbehaviour: SmMisbehaviour) -> Self {
+        Self::Solomachine(misbehaviour)
+    }
+}
+
 #[cfg(test)]
 impl From<MockMisbehaviour> for AnyMisbehaviour {
     fn from(misbehaviour: MockMisbehaviour) -> Self {
@@ -5,7 +5,8 @@ use ibc_relayer_types::core::{
     ics02_client::{client_state::ClientState, events::UpdateClient},
     ics03_connection::events::Attributes as ConnectionAttributes,
     ics04_channel::events::{
-        Attributes, CloseInit, SendPacket, TimeoutPacket, WriteAcknowledgement,
+        AcknowledgePacket, Attributes, CloseInit, ReceivePacket, SendPacket, TimeoutPacket,
+        UpgradeAttributes, WriteAcknowledgement,
     },
     ics24_host::identifier::{ChainId, ChannelId, ClientId, ConnectionId, PortId},
 };
@@ -429,6 +430,24 @@ impl Object {
         .into())
     }
 
+    /// Build the Channel object associated with the given channel upgrade event.
+    pub fn channel_from_chan_upgrade_events(
+        attributes: &UpgradeAttributes,
+        src_chain: &impl ChainHandle,
+    ) -> Result<Self, ObjectError> {
+        let dst_chain_id =
+            counterparty_chain_from_channel(src_chain, &attributes.channel_id, &attributes.port_id)
+                .map_err(ObjectError::supervisor)?;
+
+        Ok(Channel {
+            dst_chain_id,
+            src_chain_id: src_chain.id(),
+            src_channel_id: attributes.channel_id.clone(),
+            src_port_id: attributes.port_id.clone(),
+        }
+        .into())
+    }
+
     /// Build the object associated with the given [`SendPacket`] event.
     pub fn for_send_packet(
         e: &SendPacket,
@@ -471,6 +490,43 @@ impl Object {
         .into())
     }
 
+    /// Build the object associated with the given [`ReceivePacket`] event,
+    /// ie. the path from the chain which received the packet back to its sender.
+    pub fn for_recv_packet(
+        e: &ReceivePacket,
+        src_chain: &impl ChainHandle,
+    ) -> Result<Self, ObjectError> {
+        let dst_chain_id =
+            counterparty_chain_from_channel(src_chain, e.dst_channel_id(), e.dst_port_id())
+                .map_err(ObjectError::supervisor)?;
+
+        Ok(Packet {
+            dst_chain_id,
+            src_chain_id: src_chain.id(),
+            src_channel_id: e.dst_channel_id().clone(),
+            src_port_id: e.dst_port_id().clone(),
+        }
+        .into())
+    }
+
+    /// Build the object associated with the given [`AcknowledgePacket`] event.
+    pub fn for_ack_packet(
+        e: &AcknowledgePacket,
+        src_chain: &impl ChainHandle,
+    ) -> Result<Self, ObjectError> {
+        let dst_chain_id =
+            counterparty_chain_from_channel(src_chain, e.src_channel_id(), e.src_port_id())
+                .map_err(ObjectError::supervisor)?;
+
+        Ok(Packet {
+            dst_chain_id,
+            src_chain_id: src_chain.id(),
+            src_channel_id: e.src_channel_id().clone(),
+            src_port_id: e.src_port_id().clone(),
+        }
+        .into())
+    }
+
     /// Build the object associated with the given [`TimeoutPacket`] event.
     pub fn for_timeout_packet(
         e: &TimeoutPacket,
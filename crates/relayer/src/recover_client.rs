@@ -0,0 +1,191 @@
+//! Governance proposals for recovering expired or frozen clients.
+//!
+//! A client which has expired or has been frozen cannot be updated anymore,
+//! and the channels built on top of it are stuck. Rather than opening new channels,
+//! governance can recover the client by substituting the state of a fresh client
+//! for it, so that the channels keep their packet history.
+
+use bech32::{ToBase32, Variant};
+use flex_error::{define_error, TraceError};
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use tendermint_rpc::abci::transaction::Hash as TxHash;
+
+use ibc_proto::cosmos::base::v1beta1::Coin;
+use ibc_proto::cosmos::gov::v1beta1::MsgSubmitProposal as LegacyMsgSubmitProposal;
+use ibc_proto::google::protobuf::Any;
+use ibc_proto::ibc::core::client::v1::ClientUpdateProposal;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ClientId};
+
+use crate::chain::handle::ChainHandle;
+use crate::chain::tracking::TrackedMsgs;
+use crate::error::Error;
+
+define_error! {
+    RecoverClientError {
+        Key
+            [ Error ]
+            |_| { "key error" },
+
+        Config
+            [ Error ]
+            |_| { "failed to get the chain configuration" },
+
+        Authority
+            { account_prefix: String }
+            [ TraceError<bech32::Error> ]
+            |e| {
+                format!("failed to encode the address of the governance module with the account prefix '{}'", e.account_prefix)
+            },
+
+        Submit
+            { chain_id: ChainId }
+            [ Error ]
+            |e| {
+                format!("failed while submitting the recover client proposal to chain {0}", e.chain_id)
+            },
+    }
+}
+
+const MSG_RECOVER_CLIENT_TYPE_URL: &str = "/ibc.core.client.v1.MsgRecoverClient";
+const MSG_SUBMIT_PROPOSAL_TYPE_URL: &str = "/cosmos.gov.v1.MsgSubmitProposal";
+const LEGACY_MSG_SUBMIT_PROPOSAL_TYPE_URL: &str = "/cosmos.gov.v1beta1.MsgSubmitProposal";
+const CLIENT_UPDATE_PROPOSAL_TYPE_URL: &str = "/ibc.core.client.v1.ClientUpdateProposal";
+
+#[derive(Clone, Debug)]
+pub struct RecoverClientOptions {
+    /// The expired or frozen client to recover
+    pub subject_client_id: ClientId,
+    /// The client whose state is substituted for the state of the subject client
+    pub substitute_client_id: ClientId,
+    pub amount: u64,
+    pub denom: String,
+    pub title: String,
+    pub summary: String,
+    /// Submit a `ClientUpdateProposal` with a `v1beta1` governance message,
+    /// for chains which predate `MsgRecoverClient` (ibc-go < v8)
+    pub legacy: bool,
+}
+
+/// Submits a governance proposal to the given chain for substituting the
+/// client `opts.substitute_client_id` for the client `opts.subject_client_id`.
+pub fn build_and_send_recover_client_proposal(
+    chain: impl ChainHandle,
+    opts: &RecoverClientOptions,
+) -> Result<TxHash, RecoverClientError> {
+    let proposer = chain.get_signer().map_err(RecoverClientError::key)?;
+
+    let deposit = Coin {
+        denom: opts.denom.clone(),
+        amount: opts.amount.to_string(),
+    };
+
+    let any_msg = if opts.legacy {
+        let proposal = ClientUpdateProposal {
+            title: opts.title.clone(),
+            description: opts.summary.clone(),
+            subject_client_id: opts.subject_client_id.to_string(),
+            substitute_client_id: opts.substitute_client_id.to_string(),
+        };
+
+        let msg = LegacyMsgSubmitProposal {
+            content: Some(Any {
+                type_url: CLIENT_UPDATE_PROPOSAL_TYPE_URL.to_string(),
+                value: proposal.encode_to_vec(),
+            }),
+            initial_deposit: vec![deposit],
+            proposer: proposer.to_string(),
+        };
+
+        Any {
+            type_url: LEGACY_MSG_SUBMIT_PROPOSAL_TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    } else {
+        let account_prefix = chain
+            .config()
+            .map_err(RecoverClientError::config)?
+            .account_prefix;
+
+        let recover = MsgRecoverClient {
+            subject_client_id: opts.subject_client_id.to_string(),
+            substitute_client_id: opts.substitute_client_id.to_string(),
+            signer: gov_authority(&account_prefix)?,
+        };
+
+        let msg = MsgSubmitProposal {
+            messages: vec![Any {
+                type_url: MSG_RECOVER_CLIENT_TYPE_URL.to_string(),
+                value: recover.encode_to_vec(),
+            }],
+            initial_deposit: vec![deposit],
+            proposer: proposer.to_string(),
+            metadata: String::new(),
+            title: opts.title.clone(),
+            summary: opts.summary.clone(),
+        };
+
+        Any {
+            type_url: MSG_SUBMIT_PROPOSAL_TYPE_URL.to_string(),
+            value: msg.encode_to_vec(),
+        }
+    };
+
+    // As for upgrade proposals, no IBC event confirms the submission of the proposal.
+    let responses = chain
+        .send_messages_and_wait_check_tx(TrackedMsgs::new_single(any_msg, "recover client"))
+        .map_err(|e| RecoverClientError::submit(chain.id(), e))?;
+
+    Ok(responses[0].hash)
+}
+
+/// Returns the address of the governance module account, which is
+/// the only signer accepted for `MsgRecoverClient`.
+fn gov_authority(account_prefix: &str) -> Result<String, RecoverClientError> {
+    let address = &Sha256::digest(b"gov")[..20];
+
+    bech32::encode(account_prefix, address.to_base32(), Variant::Bech32)
+        .map_err(|e| RecoverClientError::authority(account_prefix.to_string(), e))
+}
+
+/// `MsgRecoverClient` as defined by ibc-go v8, which `ibc-proto` does not provide yet.
+#[derive(Clone, PartialEq, Eq, Message)]
+struct MsgRecoverClient {
+    #[prost(string, tag = "1")]
+    subject_client_id: String,
+    #[prost(string, tag = "2")]
+    substitute_client_id: String,
+    #[prost(string, tag = "3")]
+    signer: String,
+}
+
+/// `MsgSubmitProposal` of the `v1` governance module, which `ibc-proto` does not export yet.
+#[derive(Clone, PartialEq, Message)]
+struct MsgSubmitProposal {
+    #[prost(message, repeated, tag = "1")]
+    messages: Vec<Any>,
+    #[prost(message, repeated, tag = "2")]
+    initial_deposit: Vec<Coin>,
+    #[prost(string, tag = "3")]
+    proposer: String,
+    #[prost(string, tag = "4")]
+    metadata: String,
+    #[prost(string, tag = "5")]
+    title: String,
+    #[prost(string, tag = "6")]
+    summary: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gov_authority;
+
+    #[test]
+    fn gov_authority_address() {
+        assert_eq!(
+            gov_authority("cosmos").unwrap(),
+            "cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn"
+        );
+    }
+}
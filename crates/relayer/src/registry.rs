@@ -51,6 +51,12 @@ impl<Chain: ChainHandle> Registry<Chain> {
         self.handles.values()
     }
 
+    /// Get the [`ChainHandle`] associated with the given [`ChainId`],
+    /// if its runtime has already been spawned.
+    pub fn get(&self, chain_id: &ChainId) -> Option<Chain> {
+        self.handles.get(chain_id).cloned()
+    }
+
     /// Get the [`ChainHandle`] associated with the given [`ChainId`].
     ///
     /// If there is no handle yet, this will first spawn the runtime and then
@@ -81,6 +87,14 @@ impl<Chain: ChainHandle> Registry<Chain> {
         }
     }
 
+    /// Replace the configuration used to spawn new chain runtimes.
+    ///
+    /// Runtimes which have already been spawned are left untouched,
+    /// and must be shut down to pick up the new configuration.
+    pub fn update_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     /// Shutdown the runtime associated with the given chain identifier.
     pub fn shutdown(&mut self, chain_id: &ChainId) {
         if let Some(handle) = self.handles.remove(chain_id) {
@@ -100,6 +114,10 @@ impl<Chain: ChainHandle> SharedRegistry<Chain> {
         }
     }
 
+    pub fn get(&self, chain_id: &ChainId) -> Option<Chain> {
+        self.read().get(chain_id)
+    }
+
     pub fn get_or_spawn(&self, chain_id: &ChainId) -> Result<Chain, SpawnError> {
         self.registry.write().unwrap().get_or_spawn(chain_id)
     }
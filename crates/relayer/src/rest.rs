@@ -1,11 +1,15 @@
 use crossbeam_channel::TryRecvError;
 use tracing::{error, trace};
 
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
 use crate::{
     config::Config,
+    link::activity,
+    object::Object,
     rest::request::ReplySender,
-    rest::request::{Request, VersionInfo},
-    supervisor::dump_state::SupervisorState,
+    rest::request::{PendingPacketsSummary, Request, VersionInfo},
+    supervisor::{cmd::ConfigUpdate, dump_state::SupervisorState},
 };
 
 pub mod request;
@@ -26,11 +30,23 @@ pub type Receiver = crossbeam_channel::Receiver<Request>;
 
 // TODO: Unify this enum with `SupervisorCmd`
 //  We won't unify yet as it is possible we will never implement
-//  REST API `/chain` adding endpoint; instead of `/chain` we might
-//  implement `/reload` for supporting a broader range of functionality
-//  e.g., adjusting chain config, removing chains, etc.
+//  REST API `/chain` adding endpoint; instead, chains can be added,
+//  adjusted or removed by reloading the configuration through `/config/reload`.
 pub enum Command {
     DumpState(ReplySender<SupervisorState>),
+    ReloadConfig(ReplySender<Vec<ConfigUpdate>>),
+    PendingPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<PendingPacketsSummary>,
+    },
+    ClearPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<Object>,
+    },
 }
 
 /// Process incoming REST requests.
@@ -77,11 +93,57 @@ pub fn process_incoming_requests(config: &Config, channel: &Receiver) -> Option<
                     .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
             }
 
+            Request::SubscribeActivity { reply_to } => {
+                trace!("SubscribeActivity");
+
+                reply_to
+                    .send(Ok(activity::subscribe()))
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            }
+
             Request::State { reply_to } => {
                 trace!("State");
 
                 return Some(Command::DumpState(reply_to));
             }
+
+            Request::ReloadConfig { reply_to } => {
+                trace!("ReloadConfig");
+
+                return Some(Command::ReloadConfig(reply_to));
+            }
+
+            Request::PendingPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } => {
+                trace!("PendingPackets {}/{}/{}", chain_id, port_id, channel_id);
+
+                return Some(Command::PendingPackets {
+                    chain_id,
+                    port_id,
+                    channel_id,
+                    reply_to,
+                });
+            }
+
+            Request::ClearPackets {
+                chain_id,
+                port_id,
+                channel_id,
+                reply_to,
+            } => {
+                trace!("ClearPackets {}/{}/{}", chain_id, port_id, channel_id);
+
+                return Some(Command::ClearPackets {
+                    chain_id,
+                    port_id,
+                    channel_id,
+                    reply_to,
+                });
+            }
         },
         Err(e) => {
             if !matches!(e, TryRecvError::Empty) {
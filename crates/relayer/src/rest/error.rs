@@ -1,7 +1,10 @@
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use thiserror::Error;
 
-use ibc_relayer_types::core::ics24_host::{error::ValidationErrorDetail, identifier::ChainId};
+use ibc_relayer_types::core::ics24_host::{
+    error::ValidationErrorDetail,
+    identifier::{ChainId, ChannelId, PortId},
+};
 
 #[derive(Error, Debug)]
 pub enum RestApiError {
@@ -17,12 +20,33 @@ pub enum RestApiError {
     #[error("could not find configuration for chain: {0}")]
     ChainConfigNotFound(ChainId),
 
+    #[error("no chain runtime is running for chain: {0}")]
+    ChainNotFound(ChainId),
+
     #[error("failed to parse the string {0} into a valid chain identifier: {1}")]
     InvalidChainId(String, ValidationErrorDetail),
 
+    #[error("failed to parse the string {0} into a valid port identifier: {1}")]
+    InvalidPortId(String, ValidationErrorDetail),
+
+    #[error("failed to parse the string {0} into a valid channel identifier: {1}")]
+    InvalidChannelId(String, ValidationErrorDetail),
+
     #[error("failed while parsing the request body into a chain configuration: {0}")]
     InvalidChainConfig(String),
 
+    #[error("failed to reload the configuration: {0}")]
+    ConfigReload(String),
+
+    #[error("failed to query the pending packets: {0}")]
+    PendingPackets(String),
+
+    #[error("no packet worker relays the packets sent over channel {2} on port {1} of chain {0}")]
+    PacketWorkerNotFound(ChainId, PortId, ChannelId),
+
+    #[error("failed to open a WebSocket connection: {0}")]
+    WebSocket(String),
+
     #[error("not implemented")]
     Unimplemented,
 }
@@ -34,8 +58,15 @@ impl RestApiError {
             RestApiError::ChannelRecv(_) => "ChannelRecv",
             RestApiError::Serialization(_) => "Serialization",
             RestApiError::ChainConfigNotFound(_) => "ChainConfigNotFound",
+            RestApiError::ChainNotFound(_) => "ChainNotFound",
             RestApiError::InvalidChainId(_, _) => "InvalidChainId",
+            RestApiError::InvalidPortId(_, _) => "InvalidPortId",
+            RestApiError::InvalidChannelId(_, _) => "InvalidChannelId",
             RestApiError::InvalidChainConfig(_) => "InvalidChainConfig",
+            RestApiError::ConfigReload(_) => "ConfigReload",
+            RestApiError::PendingPackets(_) => "PendingPackets",
+            RestApiError::PacketWorkerNotFound(_, _, _) => "PacketWorkerNotFound",
+            RestApiError::WebSocket(_) => "WebSocket",
             RestApiError::Unimplemented => "Unimplemented",
         }
     }
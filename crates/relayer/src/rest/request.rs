@@ -1,8 +1,17 @@
+use alloc::sync::Arc;
+
 use serde::Serialize;
 
-use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
 
-use crate::{config::ChainConfig, rest::RestApiError, supervisor::dump_state::SupervisorState};
+use crate::{
+    chain::counterparty::PendingPackets,
+    config::ChainConfig,
+    link::activity::RelayActivity,
+    object::Object,
+    rest::RestApiError,
+    supervisor::{cmd::ConfigUpdate, dump_state::SupervisorState},
+};
 
 pub type ReplySender<T> = crossbeam_channel::Sender<Result<T, RestApiError>>;
 pub type ReplyReceiver<T> = crossbeam_channel::Receiver<Result<T, RestApiError>>;
@@ -17,6 +26,15 @@ pub struct VersionInfo {
     pub version: String,
 }
 
+/// The packets pending on both ends of a channel.
+#[derive(Clone, Debug, Serialize)]
+pub struct PendingPacketsSummary {
+    /// The packets sent from the chain which was queried
+    pub src: PendingPackets,
+    /// The packets sent from its counterparty chain
+    pub dst: PendingPackets,
+}
+
 /// REST API request variants
 #[derive(Clone, Debug)]
 pub enum Request {
@@ -36,4 +54,26 @@ pub enum Request {
         chain_id: ChainId,
         reply_to: ReplySender<ChainConfig>,
     },
+
+    ReloadConfig {
+        reply_to: ReplySender<Vec<ConfigUpdate>>,
+    },
+
+    PendingPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<PendingPacketsSummary>,
+    },
+
+    ClearPackets {
+        chain_id: ChainId,
+        port_id: PortId,
+        channel_id: ChannelId,
+        reply_to: ReplySender<Object>,
+    },
+
+    SubscribeActivity {
+        reply_to: ReplySender<crossbeam_channel::Receiver<Arc<RelayActivity>>>,
+    },
 }
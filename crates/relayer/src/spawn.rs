@@ -2,6 +2,7 @@ use alloc::sync::Arc;
 
 use flex_error::define_error;
 use tokio::runtime::Runtime as TokioRuntime;
+use tracing::warn;
 
 use ibc_relayer_types::core::ics24_host::identifier::ChainId;
 
@@ -9,6 +10,7 @@ use crate::{
     chain::{cosmos::CosmosSdkChain, handle::ChainHandle, runtime::ChainRuntime, ChainType},
     config::Config,
     error::Error as RelayerError,
+    link::store::state_dir,
 };
 
 define_error! {
@@ -52,8 +54,18 @@ pub fn spawn_chain_runtime<Handle: ChainHandle>(
         .cloned()
         .ok_or_else(|| SpawnError::missing_chain_config(chain_id.clone()))?;
 
+    let state_dir = if config.state.enabled {
+        state_dir(&config.state)
+            .map_err(|e| warn!(chain = %chain_id, "relaying state will not be persisted: {}", e))
+            .ok()
+    } else {
+        None
+    };
+
     let handle = match chain_config.r#type {
-        ChainType::CosmosSdk => ChainRuntime::<CosmosSdkChain>::spawn::<Handle>(chain_config, rt),
+        ChainType::CosmosSdk => {
+            ChainRuntime::<CosmosSdkChain>::spawn::<Handle>(chain_config, state_dir, rt)
+        }
     }
     .map_err(SpawnError::relayer)?;
 
@@ -1,8 +1,10 @@
 use alloc::collections::btree_map::BTreeMap as HashMap;
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use core::convert::Infallible;
 use core::ops::Deref;
 use core::time::Duration;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
@@ -10,25 +12,33 @@ use itertools::Itertools;
 use tracing::{debug, error, error_span, info, instrument, trace, warn};
 
 use ibc_relayer_types::{
-    core::ics24_host::identifier::{ChainId, ChannelId, PortId},
+    core::{
+        ics02_client::client_state::ClientState,
+        ics24_host::identifier::{ChainId, ChannelId, PortId},
+    },
     events::IbcEvent,
     Height,
 };
 
 use crate::{
-    chain::{endpoint::HealthCheck, handle::ChainHandle, tracking::TrackingId},
-    config::Config,
+    chain::{
+        counterparty::{channel_connection_client, channel_on_destination, pending_packet_summary},
+        endpoint::HealthCheck,
+        handle::ChainHandle,
+        tracking::TrackingId,
+    },
+    config::{self, ChainConfig, Config},
     event::{
         monitor::{self, Error as EventError, ErrorDetail as EventErrorDetail, EventBatch},
         IbcEventWithHeight,
     },
     object::Object,
     registry::{Registry, SharedRegistry},
-    rest,
+    rest::{self, request::PendingPacketsSummary, RestApiError},
     supervisor::scan::ScanMode,
     telemetry,
     util::{
-        lock::LockExt,
+        lock::{LockExt, RwArc},
         task::{spawn_background_task, Next, TaskError, TaskHandle},
     },
     worker::WorkerMap,
@@ -47,7 +57,7 @@ pub mod scan;
 pub mod spawn;
 
 pub mod cmd;
-use cmd::SupervisorCmd;
+use cmd::{same_config, ConfigUpdate, SupervisorCmd};
 
 use self::{scan::ChainScanner, spawn::SpawnContext};
 
@@ -75,6 +85,14 @@ pub struct SupervisorOptions {
     /// even when an allow list is configured for a chain and the full scan could
     /// be omitted.
     pub force_full_scan: bool,
+
+    /// Path to the configuration file, from which the configuration
+    /// is reloaded when requested through the REST API.
+    pub config_path: Option<PathBuf>,
+
+    /// Validates the configuration reloaded through the REST API,
+    /// which is rejected if it is invalid.
+    pub validate_config: fn(&Config) -> Result<(), String>,
 }
 
 /**
@@ -131,6 +149,48 @@ impl SupervisorHandle {
 
         Ok(state)
     }
+
+    /// Ask the supervisor to apply the given configuration, and
+    /// return the list of updates that were applied.
+    pub fn update_config(&self, config: Config) -> Result<Vec<ConfigUpdate>, Error> {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+
+        self.sender
+            .send(SupervisorCmd::UpdateConfig(Box::new(config), tx))
+            .map_err(|_| Error::handle_send())?;
+
+        let updates = rx.recv().map_err(|_| Error::handle_recv())?;
+
+        Ok(updates)
+    }
+}
+
+/// The state shared between the background tasks of the supervisor.
+///
+/// The configuration is shared so that it can be updated at runtime,
+/// when the supervisor receives a [`SupervisorCmd::UpdateConfig`] command.
+struct SupervisorContext<Chain: ChainHandle> {
+    config: RwArc<Config>,
+    registry: SharedRegistry<Chain>,
+    client_state_filter: RwArc<FilterPolicy>,
+    workers: RwArc<WorkerMap>,
+    /// The tasks processing the event batches of each chain.
+    ///
+    /// Note that these tasks must not hold a reference to this map, so that
+    /// they get shut down once the supervisor tasks owning it are dropped.
+    batch_tasks: RwArc<HashMap<ChainId, TaskHandle>>,
+}
+
+impl<Chain: ChainHandle> Clone for SupervisorContext<Chain> {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            registry: self.registry.clone(),
+            client_state_filter: self.client_state_filter.clone(),
+            workers: self.workers.clone(),
+            batch_tasks: self.batch_tasks.clone(),
+        }
+    }
 }
 
 pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
@@ -166,21 +226,23 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 
     let subscriptions = init_subscriptions(&config, &mut registry.write())?;
 
-    let batch_tasks = spawn_batch_workers(
-        &config,
-        registry.clone(),
+    let ctx = SupervisorContext {
+        config: Arc::new(RwLock::new(config)),
+        registry,
         client_state_filter,
-        workers.clone(),
-        subscriptions,
-    );
+        workers,
+        batch_tasks: Arc::new(RwLock::new(HashMap::new())),
+    };
+
+    spawn_batch_workers(&ctx, subscriptions);
 
-    let cmd_task = spawn_cmd_worker(registry.clone(), workers.clone(), cmd_rx);
+    let cmd_task = spawn_cmd_worker(ctx.clone(), cmd_rx);
 
     let mut tasks = vec![cmd_task];
-    tasks.extend(batch_tasks);
 
     if let Some(rest_rx) = rest_rx {
-        let rest_task = spawn_rest_worker(config, registry, workers, rest_rx);
+        let rest_task =
+            spawn_rest_worker(ctx, options.config_path, options.validate_config, rest_rx);
         tasks.push(rest_task);
     }
 
@@ -188,48 +250,51 @@ pub fn spawn_supervisor_tasks<Chain: ChainHandle>(
 }
 
 fn spawn_batch_workers<Chain: ChainHandle>(
-    config: &Config,
-    registry: SharedRegistry<Chain>,
-    client_state_filter: Arc<RwLock<FilterPolicy>>,
-    workers: Arc<RwLock<WorkerMap>>,
+    ctx: &SupervisorContext<Chain>,
     subscriptions: Vec<(Chain, Subscription)>,
-) -> Vec<TaskHandle> {
-    let mut handles = Vec::with_capacity(subscriptions.len());
+) {
+    let mut batch_tasks = ctx.batch_tasks.acquire_write();
 
     for (chain, subscription) in subscriptions {
-        let config = config.clone();
-        let registry = registry.clone();
-        let client_state_filter = client_state_filter.clone();
-        let workers = workers.clone();
-
-        let handle = spawn_background_task(
-            error_span!("worker.batch", chain = %chain.id()),
-            Some(Duration::from_millis(5)),
-            move || -> Result<Next, TaskError<Infallible>> {
-                if let Ok(batch) = subscription.try_recv() {
-                    handle_batch(
-                        &config,
-                        &mut registry.write(),
-                        &mut client_state_filter.acquire_write(),
-                        &mut workers.acquire_write(),
-                        chain.clone(),
-                        batch,
-                    );
-                }
+        let chain_id = chain.id();
+        let handle = spawn_batch_worker(ctx, chain, subscription);
 
-                Ok(Next::Continue)
-            },
-        );
-
-        handles.push(handle);
+        batch_tasks.insert(chain_id, handle);
     }
+}
+
+fn spawn_batch_worker<Chain: ChainHandle>(
+    ctx: &SupervisorContext<Chain>,
+    chain: Chain,
+    subscription: Subscription,
+) -> TaskHandle {
+    let config = ctx.config.clone();
+    let registry = ctx.registry.clone();
+    let client_state_filter = ctx.client_state_filter.clone();
+    let workers = ctx.workers.clone();
+
+    spawn_background_task(
+        error_span!("worker.batch", chain = %chain.id()),
+        Some(Duration::from_millis(5)),
+        move || -> Result<Next, TaskError<Infallible>> {
+            if let Ok(batch) = subscription.try_recv() {
+                handle_batch(
+                    &config.acquire_read(),
+                    &mut registry.write(),
+                    &mut client_state_filter.acquire_write(),
+                    &mut workers.acquire_write(),
+                    chain.clone(),
+                    batch,
+                );
+            }
 
-    handles
+            Ok(Next::Continue)
+        },
+    )
 }
 
-pub fn spawn_cmd_worker<Chain: ChainHandle>(
-    registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+fn spawn_cmd_worker<Chain: ChainHandle>(
+    ctx: SupervisorContext<Chain>,
     cmd_rx: Receiver<SupervisorCmd>,
 ) -> TaskHandle {
     spawn_background_task(
@@ -238,8 +303,12 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
         move || -> Result<Next, TaskError<Infallible>> {
             if let Ok(cmd) = cmd_rx.try_recv() {
                 match cmd {
+                    SupervisorCmd::UpdateConfig(config, reply_to) => {
+                        let updates = update_config(&ctx, *config);
+                        let _ = reply_to.try_send(updates);
+                    }
                     SupervisorCmd::DumpState(reply_to) => {
-                        dump_state(&registry.read(), &workers.acquire_read(), reply_to);
+                        dump_state(&ctx.registry.read(), &ctx.workers.acquire_read(), reply_to);
                     }
                 }
             }
@@ -249,17 +318,17 @@ pub fn spawn_cmd_worker<Chain: ChainHandle>(
     )
 }
 
-pub fn spawn_rest_worker<Chain: ChainHandle>(
-    config: Config,
-    registry: SharedRegistry<Chain>,
-    workers: Arc<RwLock<WorkerMap>>,
+fn spawn_rest_worker<Chain: ChainHandle>(
+    ctx: SupervisorContext<Chain>,
+    config_path: Option<PathBuf>,
+    validate_config: fn(&Config) -> Result<(), String>,
     rest_rx: rest::Receiver,
 ) -> TaskHandle {
     spawn_background_task(
         error_span!("rest"),
         Some(Duration::from_millis(500)),
         move || -> Result<Next, TaskError<Infallible>> {
-            handle_rest_requests(&config, &registry.read(), &workers.acquire_read(), &rest_rx);
+            handle_rest_requests(&ctx, config_path.as_deref(), validate_config, &rest_rx);
 
             Ok(Next::Continue)
         },
@@ -401,6 +470,10 @@ pub fn collect_events(
 
     let mode = config.mode;
 
+    // The `ReceivePacket` and `AcknowledgePacket` events are only
+    // needed to trace the lifecycle of the packets, if enabled
+    let trace_packets = cfg!(feature = "telemetry") && config.telemetry.traces.enabled;
+
     for event_with_height in &batch.events {
         match &event_with_height.event {
             IbcEvent::NewBlock(_) => {
@@ -482,6 +555,28 @@ pub fn collect_events(
                     || Object::client_from_chan_open_events(&attributes, src_chain).ok(),
                 );
             }
+            IbcEvent::UpgradeInitChannel(..)
+            | IbcEvent::UpgradeTryChannel(..)
+            | IbcEvent::UpgradeAckChannel(..)
+            | IbcEvent::UpgradeConfirmChannel(..)
+            | IbcEvent::UpgradeOpenChannel(..)
+            | IbcEvent::UpgradeErrorChannel(..)
+            | IbcEvent::FlushCompleteChannel(..) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.channels.enabled,
+                    || {
+                        event_with_height
+                            .event
+                            .clone()
+                            .channel_upgrade_attributes()
+                            .and_then(|attr| {
+                                Object::channel_from_chan_upgrade_events(&attr, src_chain).ok()
+                            })
+                    },
+                );
+            }
             IbcEvent::SendPacket(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -506,6 +601,32 @@ pub fn collect_events(
                     || Object::for_write_ack(packet, src_chain).ok(),
                 );
             }
+            IbcEvent::ReceivePacket(ref packet) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.packets.enabled && trace_packets,
+                    || {
+                        // Only used to follow the progress of the packets relayed by an
+                        // existing worker, hence collected only if the worker exists
+                        Object::for_recv_packet(packet, src_chain)
+                            .ok()
+                            .filter(|object| workers.contains(object))
+                    },
+                );
+            }
+            IbcEvent::AcknowledgePacket(ref packet) => {
+                collect_event(
+                    &mut collected,
+                    event_with_height.clone(),
+                    mode.packets.enabled && trace_packets,
+                    || {
+                        Object::for_ack_packet(packet, src_chain)
+                            .ok()
+                            .filter(|object| workers.contains(object))
+                    },
+                );
+            }
             IbcEvent::CloseInitChannel(ref packet) => {
                 collect_event(
                     &mut collected,
@@ -635,29 +756,312 @@ fn state<Chain: ChainHandle>(registry: &Registry<Chain>, workers: &WorkerMap) ->
 }
 
 fn handle_rest_requests<Chain: ChainHandle>(
-    config: &Config,
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    ctx: &SupervisorContext<Chain>,
+    config_path: Option<&Path>,
+    validate_config: fn(&Config) -> Result<(), String>,
     rest_rx: &rest::Receiver,
 ) {
-    if let Some(cmd) = rest::process_incoming_requests(config, rest_rx) {
-        handle_rest_cmd(registry, workers, cmd);
+    let cmd = rest::process_incoming_requests(&ctx.config.acquire_read(), rest_rx);
+
+    if let Some(cmd) = cmd {
+        handle_rest_cmd(ctx, config_path, validate_config, cmd);
     }
 }
 
 #[instrument(name = "supervisor.handle_rest_cmd", level = "error", skip_all)]
 fn handle_rest_cmd<Chain: ChainHandle>(
-    registry: &Registry<Chain>,
-    workers: &WorkerMap,
+    ctx: &SupervisorContext<Chain>,
+    config_path: Option<&Path>,
+    validate_config: fn(&Config) -> Result<(), String>,
     m: rest::Command,
 ) {
     match m {
         rest::Command::DumpState(reply) => {
-            let state = state(registry, workers);
+            let state = state(&ctx.registry.read(), &ctx.workers.acquire_read());
             reply
                 .send(Ok(state))
                 .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
         }
+        rest::Command::ReloadConfig(reply) => {
+            let result = match config_path {
+                Some(path) => config::load(path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|config| validate_config(&config).map(|()| config))
+                    .map(|config| update_config(ctx, config))
+                    .map_err(RestApiError::ConfigReload),
+                None => Err(RestApiError::ConfigReload(
+                    "the path to the configuration file is unknown".to_string(),
+                )),
+            };
+
+            reply
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+        rest::Command::PendingPackets {
+            chain_id,
+            port_id,
+            channel_id,
+            reply_to,
+        } => {
+            let registry = ctx.registry.clone();
+
+            // Querying both chains may take a while, do not hold up the supervisor meanwhile.
+            std::thread::spawn(move || {
+                let result = pending_packets(&registry, &chain_id, &port_id, &channel_id);
+
+                reply_to
+                    .send(result)
+                    .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+            });
+        }
+        rest::Command::ClearPackets {
+            chain_id,
+            port_id,
+            channel_id,
+            reply_to,
+        } => {
+            let workers = ctx.workers.acquire_read();
+
+            let worker = workers.handles().find(|worker| {
+                matches!(
+                    worker.object(),
+                    Object::Packet(packet)
+                        if packet.src_chain_id == chain_id
+                            && packet.src_port_id == port_id
+                            && packet.src_channel_id == channel_id
+                )
+            });
+
+            let result = match worker {
+                Some(worker) => {
+                    info!(object = %worker.object().short_name(), "clearing pending packets on request");
+
+                    worker.clear_pending_packets();
+                    Ok(worker.object().clone())
+                }
+                None => Err(RestApiError::PacketWorkerNotFound(
+                    chain_id, port_id, channel_id,
+                )),
+            };
+
+            reply_to
+                .send(result)
+                .unwrap_or_else(|e| error!("error replying to a REST request {}", e));
+        }
+    }
+}
+
+/// Query the packets pending on both ends of the given channel.
+fn pending_packets<Chain: ChainHandle>(
+    registry: &SharedRegistry<Chain>,
+    chain_id: &ChainId,
+    port_id: &PortId,
+    channel_id: &ChannelId,
+) -> Result<PendingPacketsSummary, RestApiError> {
+    let query_error = |e: &dyn core::fmt::Display| RestApiError::PendingPackets(e.to_string());
+
+    // Only query the chains whose runtime is already running, so that a
+    // request cannot make the supervisor spawn runtimes for arbitrary chains.
+    let chain = registry
+        .get(chain_id)
+        .ok_or_else(|| RestApiError::ChainNotFound(chain_id.clone()))?;
+
+    let chan_conn_cli =
+        channel_connection_client(&chain, port_id, channel_id).map_err(|e| query_error(&e))?;
+
+    let counterparty_chain_id = chan_conn_cli.client.client_state.chain_id();
+    let counterparty_chain = registry
+        .get(&counterparty_chain_id)
+        .ok_or(RestApiError::ChainNotFound(counterparty_chain_id))?;
+
+    let src = pending_packet_summary(&chain, &counterparty_chain, &chan_conn_cli.channel)
+        .map_err(|e| query_error(&e))?;
+
+    let counterparty_channel = channel_on_destination(
+        &chan_conn_cli.channel,
+        &chan_conn_cli.connection,
+        &counterparty_chain,
+    )
+    .map_err(|e| query_error(&e))?
+    .ok_or_else(|| {
+        RestApiError::PendingPackets(format!(
+            "the counterparty of channel {} on port {} of chain {} cannot be found",
+            channel_id, port_id, chain_id
+        ))
+    })?;
+
+    let dst = pending_packet_summary(&counterparty_chain, &chain, &counterparty_channel)
+        .map_err(|e| query_error(&e))?;
+
+    Ok(PendingPacketsSummary { src, dst })
+}
+
+/// Apply the changes between the current configuration and the given one.
+///
+/// Only the chain runtimes, event subscriptions and workers affected by the
+/// changes to the `chains` section are shut down and (re)spawned, so that the
+/// untouched paths keep on relaying without interruption. Changes to any
+/// other section of the configuration require a restart to take effect.
+///
+/// Returns the list of updates which were applied.
+#[instrument(name = "supervisor.update_config", level = "error", skip_all)]
+fn update_config<Chain: ChainHandle>(
+    ctx: &SupervisorContext<Chain>,
+    new_config: Config,
+) -> Vec<ConfigUpdate> {
+    // Holding on to the batch tasks for the whole duration
+    // of the update prevents concurrent updates.
+    let mut batch_tasks = ctx.batch_tasks.acquire_write();
+
+    let updates = {
+        let config = ctx.config.acquire_read();
+        warn_unsupported_changes(&config, &new_config);
+        ConfigUpdate::diff(&config, &new_config)
+    };
+
+    if updates.is_empty() {
+        info!("no changes to the chains configuration, nothing to update");
+        return updates;
+    }
+
+    // Stop processing the events of the affected chains first. This must be done
+    // before acquiring the other locks, as the batch workers may be waiting on them.
+    for update in &updates {
+        if let Some(task) = batch_tasks.remove(update.chain_id()) {
+            task.shutdown_and_wait();
+        }
+    }
+
+    let mut config = ctx.config.acquire_write();
+    let mut registry = ctx.registry.write();
+    let mut client_state_filter = ctx.client_state_filter.acquire_write();
+    let mut workers = ctx.workers.acquire_write();
+
+    config.chains = new_config.chains;
+    registry.update_config(config.clone());
+
+    // The chains whose workers are relaying to or from one of the updated chains,
+    // and which therefore need to be re-spawned once the update is complete.
+    let mut counterparties = BTreeSet::new();
+
+    for update in &updates {
+        let chain_id = update.chain_id();
+
+        info!(chain = %chain_id, "applying configuration update: {}", update);
+
+        if let ConfigUpdate::Remove(_) | ConfigUpdate::Update(_) = update {
+            for object in workers.objects_for_chain(chain_id) {
+                counterparties.insert(object.src_chain_id().clone());
+                counterparties.insert(object.dst_chain_id().clone());
+
+                workers.shutdown_worker(&object);
+            }
+
+            registry.shutdown(chain_id);
+        }
+    }
+
+    for update in &updates {
+        if let ConfigUpdate::Add(chain_config) | ConfigUpdate::Update(chain_config) = update {
+            counterparties.remove(&chain_config.id);
+
+            spawn_chain(
+                &config,
+                &mut registry,
+                &mut client_state_filter,
+                &mut workers,
+                &mut batch_tasks,
+                ctx,
+                chain_config,
+            );
+        }
+    }
+
+    // Re-spawn the workers for the paths between the
+    // remaining chains and the updated ones.
+    for chain_id in counterparties {
+        if let Some(chain_config) = config.find_chain(&chain_id) {
+            let scan = chain_scanner(
+                &config,
+                &mut registry,
+                &mut client_state_filter,
+                ScanMode::Auto,
+            )
+            .scan_chain(chain_config);
+
+            match scan {
+                Ok(scan) => spawn_context(&config, &mut registry, &mut workers)
+                    .spawn_workers_for_chain(scan),
+                Err(e) => error!(chain = %chain_id, "failed to re-spawn workers: {}", e),
+            }
+        }
+    }
+
+    info!("applied {} configuration update(s)", updates.len());
+
+    updates
+}
+
+/// Scan the chain with the given configuration, spawn its workers,
+/// and start processing the events emitted by the chain.
+fn spawn_chain<Chain: ChainHandle>(
+    config: &Config,
+    registry: &mut Registry<Chain>,
+    client_state_filter: &mut FilterPolicy,
+    workers: &mut WorkerMap,
+    batch_tasks: &mut HashMap<ChainId, TaskHandle>,
+    ctx: &SupervisorContext<Chain>,
+    chain_config: &ChainConfig,
+) {
+    let _span = error_span!("spawn_chain", chain = %chain_config.id).entered();
+
+    let scan = chain_scanner(config, registry, client_state_filter, ScanMode::Auto)
+        .scan_chain(chain_config);
+
+    match scan {
+        Ok(scan) => spawn_context(config, registry, workers).spawn_workers_for_chain(scan),
+        Err(e) => {
+            error!("failed to scan chain, skipping workers: {}", e);
+        }
+    }
+
+    let subscription = registry
+        .get_or_spawn(&chain_config.id)
+        .map_err(Error::spawn)
+        .and_then(|chain| {
+            let subscription = chain.subscribe().map_err(Error::relayer)?;
+            Ok((chain, subscription))
+        });
+
+    match subscription {
+        Ok((chain, subscription)) => {
+            let handle = spawn_batch_worker(ctx, chain, subscription);
+            batch_tasks.insert(chain_config.id.clone(), handle);
+        }
+        Err(e) => error!("failed to subscribe to events: {}", e),
+    }
+}
+
+/// Warn about the changes to the configuration which cannot
+/// be applied without restarting the relayer.
+fn warn_unsupported_changes(prev: &Config, next: &Config) {
+    let sections = [
+        ("global", same_config(&prev.global, &next.global)),
+        ("mode", same_config(&prev.mode, &next.mode)),
+        ("rest", same_config(&prev.rest, &next.rest)),
+        ("telemetry", same_config(&prev.telemetry, &next.telemetry)),
+        ("state", same_config(&prev.state, &next.state)),
+    ];
+
+    for (section, unchanged) in sections {
+        if !unchanged {
+            warn!(
+                "changes to the `{}` section of the configuration are ignored, \
+                 restart Hermes to apply them",
+                section
+            );
+        }
     }
 }
 
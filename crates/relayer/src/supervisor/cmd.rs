@@ -1,8 +1,131 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
 use crossbeam_channel::Sender;
+use serde::Serialize;
+
+use ibc_relayer_types::core::ics24_host::identifier::ChainId;
+
+use crate::config::{ChainConfig, Config};
+use crate::util::diff::{gdiff, Change};
 
 use super::dump_state::SupervisorState;
 
 #[derive(Clone, Debug)]
 pub enum SupervisorCmd {
+    UpdateConfig(Box<Config>, Sender<Vec<ConfigUpdate>>),
     DumpState(Sender<SupervisorState>),
 }
+
+/// A change to the configuration of a single chain,
+/// computed by diffing two versions of the [`Config`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "chain")]
+pub enum ConfigUpdate {
+    /// A new chain was added to the configuration.
+    Add(ChainConfig),
+    /// The chain with this identifier was removed from the configuration.
+    Remove(ChainId),
+    /// The configuration of an existing chain was modified.
+    Update(ChainConfig),
+}
+
+impl ConfigUpdate {
+    /// Computes the list of chain-level updates needed to go from the
+    /// `prev` configuration to the `next` one.
+    pub fn diff(prev: &Config, next: &Config) -> Vec<Self> {
+        let prev_chains = prev.chains_map();
+        let next_chains = next.chains_map();
+
+        gdiff(&prev_chains, &next_chains, |a, b| same_config(*a, *b))
+            .into_iter()
+            .map(|change| match change {
+                Change::Added(id) => Self::Add(next_chains[*id].clone()),
+                Change::Updated(id) => Self::Update(next_chains[*id].clone()),
+                Change::Removed(id) => Self::Remove((*id).clone()),
+            })
+            .collect()
+    }
+
+    /// The identifier of the chain affected by this update.
+    pub fn chain_id(&self) -> &ChainId {
+        match self {
+            Self::Add(config) => &config.id,
+            Self::Remove(id) => id,
+            Self::Update(config) => &config.id,
+        }
+    }
+}
+
+impl Display for ConfigUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::Add(config) => write!(f, "add chain '{}'", config.id),
+            Self::Remove(id) => write!(f, "remove chain '{}'", id),
+            Self::Update(config) => write!(f, "update chain '{}'", config.id),
+        }
+    }
+}
+
+/// Whether two configuration sections are identical.
+///
+/// Some of the configuration types (eg. the wildcard-based packet filters)
+/// do not implement `Eq`, so we compare their serialized representations instead.
+pub(crate) fn same_config<T: Serialize>(a: &T, b: &T) -> bool {
+    match (serde_json::to_value(a), serde_json::to_value(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::{load, PacketFilter};
+
+    fn example_config() -> Config {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+
+        load(path).expect("could not parse config")
+    }
+
+    #[test]
+    fn diff_identical_configs() {
+        let config = example_config();
+
+        assert!(ConfigUpdate::diff(&config, &config.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_chain_changes() {
+        let prev = example_config();
+        let mut next = prev.clone();
+
+        let removed = next.chains.remove(0);
+
+        let mut added = next.chains[0].clone();
+        added.id = ChainId::from_string("chain_C");
+        next.chains.push(added);
+
+        next.chains[0].packet_filter = PacketFilter::AllowAll;
+        next.chains[0].gas_price.price += 1.0;
+
+        let mut updates = ConfigUpdate::diff(&prev, &next)
+            .iter()
+            .map(|update| update.to_string())
+            .collect::<Vec<_>>();
+        updates.sort();
+
+        assert_eq!(
+            updates,
+            vec![
+                "add chain 'chain_C'".to_string(),
+                format!("remove chain '{}'", removed.id),
+                format!("update chain '{}'", next.chains[0].id),
+            ]
+        );
+    }
+}
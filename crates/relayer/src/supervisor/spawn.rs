@@ -1,7 +1,8 @@
 use tracing::{error, info};
 
 use ibc_relayer_types::core::{
-    ics02_client::client_state::ClientState, ics03_connection::connection::IdentifiedConnectionEnd,
+    ics02_client::client_state::ClientState, ics02_client::client_type::ClientType,
+    ics03_connection::connection::IdentifiedConnectionEnd,
     ics04_channel::channel::State as ChannelState, ics24_host::identifier::ChainId,
 };
 
@@ -244,11 +245,15 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
             chan_state_dst
         );
 
+        // A channel which is being upgraded is still used to flush the packets in flight.
+        let is_usable = |state: ChannelState| state.is_open() || state.is_upgrading();
+
         if (mode.clients.enabled || mode.packets.enabled)
-            && chan_state_src.is_open()
-            && chan_state_dst.is_open()
+            && is_usable(chan_state_src)
+            && is_usable(chan_state_dst)
         {
-            if mode.clients.enabled {
+            // The localhost client is updated by the chain itself, and needs no client worker
+            if mode.clients.enabled && client.client_state.client_type() != ClientType::Localhost {
                 // Spawn the client worker
                 let client_object = Object::Client(Client {
                     dst_client_id: client.client_id.clone(),
@@ -302,6 +307,37 @@ impl<'a, Chain: ChainHandle> SpawnContext<'a, Chain> {
                 }
             }
 
+            // An upgrade proposed on the source end while the relayer was not running
+            // is pending until the destination end accepts it.
+            let upgrade_pending = channel_scan.counterparty.as_ref().map_or(false, |c| {
+                channel_scan.channel.channel_end.upgrade_sequence()
+                    > c.channel_end.upgrade_sequence()
+            });
+
+            // Spawn the channel worker which will resume the upgrade handshake, and open
+            // the upgraded channel once the packets in flight have been flushed on both ends
+            if mode.channels.enabled
+                && (upgrade_pending
+                    || chan_state_src.is_upgrading()
+                    || chan_state_dst.is_upgrading())
+            {
+                let channel_object = Object::Channel(Channel {
+                    dst_chain_id: counterparty_chain.id(),
+                    src_chain_id: chain.id(),
+                    src_channel_id: channel_scan.channel.channel_id.clone(),
+                    src_port_id: channel_scan.channel.port_id.clone(),
+                });
+
+                self.workers
+                    .spawn(
+                        chain.clone(),
+                        counterparty_chain.clone(),
+                        &channel_object,
+                        self.config,
+                    )
+                    .then(|| info!("spawned channel worker: {}", channel_object.short_name()));
+            }
+
             Ok(mode.clients.enabled)
         } else if mode.channels.enabled
             && !chan_state_dst.is_open()
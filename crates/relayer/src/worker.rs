@@ -6,6 +6,7 @@ use std::sync::Mutex;
 use tracing::error;
 
 use crate::foreign_client::ForeignClient;
+use crate::link::store::PathStore;
 use crate::link::{Link, LinkParameters, Resubmit};
 use crate::{
     chain::handle::{ChainHandle, ChainHandlePair},
@@ -76,7 +77,11 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
 
             let cmd_tx = if config.mode.clients.misbehaviour {
                 let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
-                let misbehavior_task = client::detect_misbehavior_task(cmd_rx, client);
+                let misbehavior_task = client::detect_misbehavior_task(
+                    cmd_rx,
+                    client,
+                    config.mode.clients.misbehaviour_scan_interval,
+                );
                 if let Some(task) = misbehavior_task {
                     task_handles.push(task);
                     misbehaviour = true;
@@ -123,10 +128,28 @@ pub fn spawn_worker_tasks<ChainA: ChainHandle, ChainB: ChainHandle>(
             );
 
             match link_res {
-                Ok(link) => {
+                Ok(mut link) => {
+                    if config.state.enabled {
+                        match PathStore::open(
+                            &config.state,
+                            &path.src_chain_id,
+                            &path.src_port_id,
+                            &path.src_channel_id,
+                        ) {
+                            Ok(store) => link.a_to_b.restore_from(store),
+                            Err(e) => error!(
+                                "failed to open the relaying state store, the state of this path will not be persisted: {}",
+                                e
+                            ),
+                        }
+                    }
+
+                    // Skip clearing on start if a previous run of the relayer
+                    // already cleared packets recently, unless the channel is ordered.
                     let channel_ordering = link.a_to_b.channel().ordering;
-                    let should_clear_on_start =
-                        packets_config.clear_on_start || channel_ordering == Order::Ordered;
+                    let should_clear_on_start = (packets_config.clear_on_start
+                        && !link.a_to_b.cleared_recently(packets_config.clear_interval))
+                        || channel_ordering == Order::Ordered;
 
                     let (cmd_tx, cmd_rx) = crossbeam_channel::unbounded();
                     let link = Arc::new(Mutex::new(link));
@@ -34,17 +34,47 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
 
                         complete_handshake_on_new_block = false;
                         if let Some(event_with_height) = last_event {
-                            let mut handshake_channel = RelayChannel::restore_from_event(
-                                chains.a.clone(),
-                                chains.b.clone(),
-                                event_with_height.event.clone(),
+                            let is_upgrade_event = event_with_height
+                                .event
+                                .clone()
+                                .channel_upgrade_attributes()
+                                .is_some();
+
+                            // Upgrade events do not carry the connection of the channel,
+                            // so the channel is restored from the chain state instead.
+                            let restored = if is_upgrade_event {
+                                RelayChannel::restore_from_state(
+                                    chains.a.clone(),
+                                    chains.b.clone(),
+                                    channel.clone(),
+                                    event_with_height.height,
+                                )
+                                .map(|(handshake_channel, _)| handshake_channel)
+                            } else {
+                                RelayChannel::restore_from_event(
+                                    chains.a.clone(),
+                                    chains.b.clone(),
+                                    event_with_height.event.clone(),
+                                )
+                            };
+
+                            let mut handshake_channel =
+                                restored.map_err(|e| TaskError::Fatal(RunError::channel(e)))?;
+
+                            let next = retry_with_index(
+                                retry_strategy::worker_default_strategy(),
+                                |index| {
+                                    handshake_channel.step_event(&event_with_height.event, index)
+                                },
                             )
-                            .map_err(|e| TaskError::Fatal(RunError::channel(e)))?;
+                            .map_err(|e| TaskError::Fatal(RunError::retry(e)))?;
 
-                            retry_with_index(retry_strategy::worker_default_strategy(), |index| {
-                                handshake_channel.step_event(&event_with_height.event, index)
-                            })
-                            .map_err(|e| TaskError::Fatal(RunError::retry(e)))
+                            // The channel ends of an upgrade in progress are checked again
+                            // on the next blocks, until both ends are flushed and opened.
+                            complete_handshake_on_new_block =
+                                is_upgrade_event && matches!(next, Next::Continue);
+
+                            Ok(next)
                         } else {
                             Ok(Next::Continue)
                         }
@@ -68,11 +98,22 @@ pub fn spawn_channel_worker<ChainA: ChainHandle, ChainB: ChainHandle>(
                         )
                         .map_err(|e| TaskError::Fatal(RunError::channel(e)))?;
 
-                        complete_handshake_on_new_block = false;
-                        retry_with_index(retry_strategy::worker_default_strategy(), |index| {
-                            handshake_channel.step_state(state, index)
-                        })
-                        .map_err(|e| TaskError::Fatal(RunError::retry(e)))
+                        let next =
+                            retry_with_index(retry_strategy::worker_default_strategy(), |index| {
+                                handshake_channel.step_state(state, index)
+                            })
+                            .map_err(|e| TaskError::Fatal(RunError::retry(e)))?;
+
+                        // Keep checking while either end of the channel is being upgraded,
+                        // as the packets in flight may be flushed without an event reaching
+                        // this worker, and the upgrade of the counterparty may time out.
+                        complete_handshake_on_new_block = matches!(next, Next::Continue)
+                            && (state.is_upgrading()
+                                || handshake_channel
+                                    .counterparty_state()
+                                    .map_or(false, |state| state.is_upgrading()));
+
+                        Ok(next)
                     }
 
                     // nothing to do
@@ -80,6 +80,7 @@ pub fn spawn_refresh_client<ChainA: ChainHandle, ChainB: ChainHandle>(
 pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
     receiver: Receiver<WorkerCmd>,
     client: ForeignClient<ChainB, ChainA>,
+    scan_interval: Option<Duration>,
 ) -> Option<TaskHandle> {
     if client.is_expired_or_frozen() {
         warn!(
@@ -90,6 +91,7 @@ pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
     }
 
     let mut first_check_done = false;
+    let mut next_scan: Option<Instant> = None;
 
     let handle = spawn_background_task(
         span!(
@@ -104,8 +106,27 @@ pub fn detect_misbehavior_task<ChainA: ChainHandle, ChainB: ChainHandle>(
             if !first_check_done {
                 first_check_done = true;
                 debug!("doing first check");
-                let misbehavior_result = client.detect_misbehaviour_and_submit_evidence(None);
-                trace!("detect misbehavior result: {:?}", misbehavior_result);
+
+                if scan_interval.is_none() {
+                    let misbehavior_result = client.detect_misbehaviour_and_submit_evidence(None);
+                    trace!("detect misbehavior result: {:?}", misbehavior_result);
+                }
+            }
+
+            if let Some(interval) = scan_interval {
+                if next_scan.map_or(true, |next| Instant::now() >= next) {
+                    debug!("scanning all consensus states for misbehavior");
+                    let misbehavior_result = client.scan_misbehaviour_and_submit_evidence();
+                    trace!("scan misbehavior result: {:?}", misbehavior_result);
+
+                    match misbehavior_result {
+                        MisbehaviourResults::EvidenceSubmitted(_) => return Ok(Next::Abort),
+                        MisbehaviourResults::CannotExecute => return Ok(Next::Abort),
+                        _ => {}
+                    }
+
+                    next_scan = Some(Instant::now() + interval);
+                }
             }
 
             if let Ok(cmd) = receiver.try_recv() {
@@ -4,6 +4,9 @@ use tracing::{error_span, trace, warn};
 
 use crate::{
     chain::handle::ChainHandle,
+    config::ChainConfig,
+    error::Error,
+    keyring::KeyRing,
     telemetry,
     util::task::{spawn_background_task, Next, TaskError, TaskHandle},
 };
@@ -11,41 +14,112 @@ use crate::{
 pub fn spawn_wallet_worker<Chain: ChainHandle>(chain: Chain) -> TaskHandle {
     let span = error_span!("wallet", chain = %chain.id());
 
+    // The names and accounts of the keys in use by the relayer, fetched on the first run
+    let mut accounts = Vec::new();
+
     spawn_background_task(span, Some(Duration::from_secs(5)), move || {
-        let key = chain.get_key().map_err(|e| {
-            TaskError::Fatal(format!("failed to get key in use by the relayer: {e}"))
-        })?;
-
-        let balance = chain.query_balance(None, None).map_err(|e| {
-            TaskError::Ignore(format!("failed to query balance for the account: {e}"))
-        })?;
-
-        match balance.amount.parse::<f64>() {
-            Ok(amount) => {
-                telemetry!(
-                    wallet_balance,
-                    &chain.id(),
-                    &key.account,
-                    amount,
-                    &balance.denom,
-                );
-                trace!(%amount, denom = %balance.denom, account = %key.account, "wallet balance");
-            }
-            Err(e) => {
-                warn!(
-                    %balance.amount, denom = %balance.denom, account = %key.account,
-                    "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
-                );
+        if accounts.is_empty() {
+            accounts = fetch_accounts(&chain).map_err(|e| {
+                TaskError::Fatal(format!("failed to get keys in use by the relayer: {e}"))
+            })?;
+        }
+
+        for (key_name, account) in &accounts {
+            let balance = match chain.query_balance(Some(key_name.clone()), None) {
+                Ok(balance) => balance,
+                Err(e) => {
+                    warn!(%account, "failed to query balance for the account: {}", e);
+                    continue;
+                }
+            };
+
+            match balance.amount.parse::<f64>() {
+                Ok(amount) => {
+                    telemetry!(wallet_balance, &chain.id(), account, amount, &balance.denom,);
+                    trace!(%amount, denom = %balance.denom, %account, "wallet balance");
+                }
+                Err(e) => {
+                    warn!(
+                        %balance.amount, denom = %balance.denom, %account,
+                        "unable to parse the wallet balance into a f64, the balance will therefore not be reported to telemetry. Reason: {}", e
+                    );
+                }
             }
         }
         Ok(Next::Continue)
     })
 }
 
+/// Fetch the account of every key used by the relayer to submit transactions to the chain.
+fn fetch_accounts(chain: &impl ChainHandle) -> Result<Vec<(String, String)>, Error> {
+    let config = chain.config()?;
+    let keyring = KeyRing::from_chain_config(&config).map_err(Error::key_base)?;
+
+    key_accounts(&config, &keyring)
+}
+
+/// The account of every key of the given chain configuration, the primary key first.
+/// The keys may be held by a remote signer, which only exposes their public keys.
+fn key_accounts(config: &ChainConfig, keyring: &KeyRing) -> Result<Vec<(String, String)>, Error> {
+    config
+        .all_key_names()
+        .into_iter()
+        .map(|key_name| {
+            let key = keyring
+                .get_key_signer(key_name)
+                .map_err(|e| Error::key_not_found(key_name.to_string(), e))?;
+
+            Ok((key_name.to_string(), key.account().to_string()))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    use core::time::Duration;
+
     use ibc_relayer_types::bigint::U256;
 
+    use crate::config::{self, RemoteSignerConfig};
+    use crate::keyring::remote::stub::{local_key, spawn_stub_signer};
+    use crate::keyring::Store;
+
+    #[test]
+    fn key_accounts_of_remote_keyring() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/config/fixtures/relayer_conf_example.toml"
+        );
+        let mut config = config::load(path).unwrap().chains.remove(0);
+
+        let (primary, pooled) = (local_key(0), local_key(1));
+
+        config.key_name = "relayer".to_string();
+        config.key_names = vec!["relayer-2".to_string()];
+        config.key_store_type = Store::Remote;
+        config.remote_signer = Some(RemoteSignerConfig {
+            url: spawn_stub_signer(vec![
+                ("relayer", primary.clone()),
+                ("relayer-2", pooled.clone()),
+            ]),
+            timeout: Duration::from_secs(5),
+        });
+
+        let keyring = KeyRing::from_chain_config(&config).unwrap();
+        assert!(matches!(keyring, KeyRing::Remote(_)));
+
+        let accounts = key_accounts(&config, &keyring).unwrap();
+        assert_eq!(
+            accounts,
+            vec![
+                ("relayer".to_string(), primary.account),
+                ("relayer-2".to_string(), pooled.account)
+            ]
+        );
+    }
+
     // Test to confirm that any u256 fits in f64
     #[test]
     fn compare_f64_max_to_u256_max() {
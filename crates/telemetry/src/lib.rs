@@ -1,5 +1,7 @@
 extern crate alloc;
 
+pub mod otlp;
+pub mod packet_trace;
 mod path_identifier;
 pub mod server;
 pub mod state;
@@ -12,6 +14,7 @@ use std::{
     thread::JoinHandle,
 };
 
+pub use crate::packet_trace::PacketStage;
 pub use crate::state::TelemetryState;
 
 pub fn new_state() -> Arc<TelemetryState> {
@@ -43,3 +46,20 @@ where
         Err(e) => Err(e),
     }
 }
+
+/// The maximum number of spans awaiting export, beyond which new spans are dropped
+const MAX_PENDING_SPANS: usize = 10_000;
+
+/// Spawns the exporter of the packet traces recorded by the given state to
+/// the OpenTelemetry collector listening for OTLP over HTTP at `endpoint`.
+pub fn spawn_trace_exporter(
+    endpoint: String,
+    service_name: String,
+    state: Arc<TelemetryState>,
+) -> JoinHandle<()> {
+    let (sender, receiver) = crossbeam_channel::bounded(MAX_PENDING_SPANS);
+
+    state.init_packet_tracer(packet_trace::PacketTracer::new(sender));
+
+    std::thread::spawn(move || otlp::run(receiver, &endpoint, &service_name))
+}
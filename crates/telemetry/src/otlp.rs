@@ -0,0 +1,88 @@
+//! Export of trace spans to an OpenTelemetry collector, eg. Jaeger or Tempo,
+//! with the OTLP protocol over HTTP.
+//!
+//! The spans are recorded by Hermes as [`SpanData`], with the identifiers and timestamps
+//! chosen by the [`PacketTracer`](crate::packet_trace::PacketTracer), and are handed over
+//! as is to the OTLP span exporter.
+
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+
+use crossbeam_channel::{Receiver, RecvTimeoutError};
+use opentelemetry::sdk::export::trace::{SpanData, SpanExporter};
+use opentelemetry::sdk::Resource;
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use tracing::{error, trace, warn};
+
+/// The maximum number of spans exported in a single request
+const MAX_BATCH_SIZE: usize = 512;
+
+/// How long spans are collected before being exported
+const EXPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Exports the spans received on the given channel to the collector listening at
+/// `endpoint`, until the channel is closed. Spans are exported in batches, and
+/// a batch which cannot be exported is dropped.
+pub fn run(spans: Receiver<SpanData>, endpoint: &str, service_name: &str) {
+    let url = format!("{}/v1/traces", endpoint.trim_end_matches('/'));
+
+    let exporter = opentelemetry_otlp::new_exporter()
+        .http()
+        .with_endpoint(&url)
+        .with_timeout(EXPORT_TIMEOUT)
+        .build_span_exporter();
+
+    let mut exporter = match exporter {
+        Ok(exporter) => exporter,
+        Err(e) => {
+            error!("failed to set up the export of traces to {}: {}", url, e);
+            return;
+        }
+    };
+
+    let resource: Cow<'static, Resource> = Cow::Owned(Resource::new(vec![KeyValue::new(
+        "service.name",
+        service_name.to_string(),
+    )]));
+
+    while let Ok(span) = spans.recv() {
+        let mut batch = vec![span];
+        let deadline = Instant::now() + EXPORT_INTERVAL;
+        let mut disconnected = false;
+
+        while batch.len() < MAX_BATCH_SIZE {
+            match spans.recv_deadline(deadline) {
+                Ok(span) => batch.push(span),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => {
+                    disconnected = true;
+                    break;
+                }
+            }
+        }
+
+        for span in batch.iter_mut() {
+            span.resource = resource.clone();
+        }
+
+        export(&mut exporter, &url, batch);
+
+        if disconnected {
+            break;
+        }
+    }
+
+    exporter.shutdown();
+}
+
+fn export(exporter: &mut impl SpanExporter, url: &str, spans: Vec<SpanData>) {
+    let count = spans.len();
+
+    match futures::executor::block_on(exporter.export(spans)) {
+        Ok(()) => trace!("exported {} spans to {}", count, url),
+        Err(e) => warn!("failed to export {} spans to {}: {}", count, url, e),
+    }
+}
@@ -0,0 +1,332 @@
+//! Traces of the lifecycle of the packets relayed by Hermes.
+//!
+//! All the spans recorded for a packet belong to the same trace, whose identifier
+//! is derived from the source chain, port, channel and sequence of the packet.
+//! Each stage reached by the packet is recorded as a span running from the end
+//! of the previous stage, which is linked to it, and every stage span is a child
+//! of a root span covering the whole lifecycle, which is recorded once the
+//! acknowledgement of the packet has been relayed.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crossbeam_channel::{Sender, TrySendError};
+use opentelemetry::sdk::export::trace::SpanData;
+use opentelemetry::sdk::trace::{EvictedHashMap, EvictedQueue};
+use opentelemetry::sdk::{InstrumentationLibrary, Resource};
+use opentelemetry::trace::{
+    Link, SpanContext, SpanId, SpanKind, Status, TraceFlags, TraceId, TraceState,
+};
+use opentelemetry::KeyValue;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, PortId};
+
+/// The stages of the lifecycle of a packet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PacketStage {
+    /// The `SendPacket` event was seen by the relayer
+    EventSeen,
+    /// The operational data with the `MsgRecvPacket` was built
+    OperationalDataBuilt,
+    /// The transaction with the `MsgRecvPacket` was submitted to the destination chain
+    TxSubmitted,
+    /// The transaction with the `MsgRecvPacket` was confirmed on the destination chain
+    TxConfirmed,
+    /// The transaction with the `MsgAcknowledgement` was confirmed on the source chain
+    AckRelayed,
+}
+
+impl Display for PacketStage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        match self {
+            Self::EventSeen => write!(f, "event_seen"),
+            Self::OperationalDataBuilt => write!(f, "operational_data_built"),
+            Self::TxSubmitted => write!(f, "tx_submitted"),
+            Self::TxConfirmed => write!(f, "tx_confirmed"),
+            Self::AckRelayed => write!(f, "ack_relayed"),
+        }
+    }
+}
+
+/// The progress of a packet through its lifecycle.
+#[derive(Clone, Debug)]
+struct PacketProgress {
+    start: SystemTime,
+    last_span_id: SpanId,
+    last_end: SystemTime,
+}
+
+pub struct PacketTracer {
+    spans: Sender<SpanData>,
+    packets: moka::sync::Cache<String, PacketProgress>,
+    /// Makes the identifiers of the spans recorded within the same nanosecond distinct
+    counter: AtomicU64,
+}
+
+impl PacketTracer {
+    pub fn new(spans: Sender<SpanData>) -> Self {
+        Self {
+            spans,
+            packets: moka::sync::Cache::builder()
+                .time_to_idle(Duration::from_secs(60 * 60)) // Forget about packets which have not progressed for 1 hour
+                .build(),
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Records that the packet with the given sequence, sent over the
+    /// given channel of the given chain, reached the given stage.
+    pub fn record(
+        &self,
+        stage: PacketStage,
+        tracking_id: String,
+        sequence: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) {
+        let key = format!("{}/{}/{}/{}", chain_id, port_id, channel_id, sequence);
+
+        let packet_hash = Sha256::digest(key.as_bytes());
+        let trace_id = TraceId::from_bytes(packet_hash[..16].try_into().unwrap());
+        let root_span_id = SpanId::from_bytes(packet_hash[16..24].try_into().unwrap());
+
+        let now = SystemTime::now();
+
+        let span_id = {
+            let counter = self.counter.fetch_add(1, Ordering::Relaxed);
+            let seed = format!(
+                "{}/{}/{}/{}/{}",
+                key,
+                stage,
+                tracking_id,
+                unix_nanos(now),
+                counter
+            );
+
+            SpanId::from_bytes(Sha256::digest(seed.as_bytes())[..8].try_into().unwrap())
+        };
+
+        let progress = self.packets.get(&key);
+
+        let attributes = vec![
+            KeyValue::new("tracking_id", tracking_id),
+            KeyValue::new("packet.sequence", sequence as i64),
+            KeyValue::new("packet.src_chain", chain_id.to_string()),
+            KeyValue::new("packet.src_channel", channel_id.to_string()),
+            KeyValue::new("packet.src_port", port_id.to_string()),
+        ];
+
+        let links = progress
+            .iter()
+            .map(|progress| Link::new(span_context(trace_id, progress.last_span_id), Vec::new()))
+            .collect();
+
+        let start = progress.as_ref().map_or(now, |progress| progress.last_end);
+
+        self.send(span(
+            span_context(trace_id, span_id),
+            root_span_id,
+            stage.to_string(),
+            (start, now),
+            attributes.clone(),
+            links,
+        ));
+
+        let lifecycle_start = progress.map_or(start, |progress| progress.start);
+
+        if stage == PacketStage::AckRelayed {
+            self.packets.invalidate(&key);
+
+            self.send(span(
+                span_context(trace_id, root_span_id),
+                SpanId::INVALID,
+                format!("packet {}", sequence),
+                (lifecycle_start, now),
+                attributes,
+                Vec::new(),
+            ));
+        } else {
+            self.packets.insert(
+                key,
+                PacketProgress {
+                    start: lifecycle_start,
+                    last_span_id: span_id,
+                    last_end: now,
+                },
+            );
+        }
+    }
+
+    /// Hands the span over to the exporter without blocking,
+    /// dropping it if the exporter is lagging behind.
+    fn send(&self, span: SpanData) {
+        if let Err(TrySendError::Full(span)) = self.spans.try_send(span) {
+            warn!(span = %span.name, "dropping span since the trace exporter is lagging behind");
+        }
+    }
+}
+
+fn span_context(trace_id: TraceId, span_id: SpanId) -> SpanContext {
+    SpanContext::new(
+        trace_id,
+        span_id,
+        TraceFlags::SAMPLED,
+        false,
+        TraceState::default(),
+    )
+}
+
+/// The span running over the given time range, which is a child of
+/// the given parent span, unless the parent span is invalid.
+fn span(
+    span_context: SpanContext,
+    parent_span_id: SpanId,
+    name: String,
+    (start, end): (SystemTime, SystemTime),
+    attributes: Vec<KeyValue>,
+    links: Vec<Link>,
+) -> SpanData {
+    let mut attribute_map = EvictedHashMap::new(attributes.len() as u32, attributes.len());
+    for attribute in attributes {
+        attribute_map.insert(attribute);
+    }
+
+    let mut link_queue = EvictedQueue::new(links.len() as u32);
+    link_queue.extend(links);
+
+    SpanData {
+        span_context,
+        parent_span_id,
+        span_kind: SpanKind::Internal,
+        name: Cow::Owned(name),
+        start_time: start,
+        end_time: end,
+        attributes: attribute_map,
+        events: EvictedQueue::new(0),
+        links: link_queue,
+        status: Status::Unset,
+        resource: Cow::Owned(Resource::empty()),
+        instrumentation_lib: InstrumentationLibrary::new(
+            "hermes",
+            Some(env!("CARGO_PKG_VERSION")),
+            None,
+        ),
+    }
+}
+
+fn unix_nanos(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crossbeam_channel::{unbounded, Receiver};
+
+    fn tracer() -> (PacketTracer, Receiver<SpanData>) {
+        let (sender, receiver) = unbounded();
+        (PacketTracer::new(sender), receiver)
+    }
+
+    fn record(tracer: &PacketTracer, stage: PacketStage, chain: &str, sequence: u64) {
+        tracer.record(
+            stage,
+            "tracking-id".to_string(),
+            sequence,
+            &ChainId::from_string(chain),
+            &ChannelId::new(0),
+            &PortId::transfer(),
+        );
+    }
+
+    #[test]
+    fn trace_ids_are_deterministic() {
+        let (tracer_a, spans_a) = tracer();
+        let (tracer_b, spans_b) = tracer();
+
+        record(&tracer_a, PacketStage::EventSeen, "ibc-0", 1);
+        record(&tracer_b, PacketStage::TxConfirmed, "ibc-0", 1);
+
+        let span_a = spans_a.try_recv().unwrap();
+        let span_b = spans_b.try_recv().unwrap();
+
+        // The spans of the same packet belong to the same trace and root span,
+        // even when recorded by different tracers, eg. after a restart
+        assert_ne!(span_a.span_context.trace_id(), TraceId::INVALID);
+        assert_eq!(
+            span_a.span_context.trace_id(),
+            span_b.span_context.trace_id()
+        );
+        assert_eq!(span_a.parent_span_id, span_b.parent_span_id);
+        assert_ne!(span_a.span_context.span_id(), span_b.span_context.span_id());
+
+        record(&tracer_a, PacketStage::EventSeen, "ibc-0", 2);
+        record(&tracer_a, PacketStage::EventSeen, "ibc-1", 1);
+
+        let other_sequence = spans_a.try_recv().unwrap();
+        let other_chain = spans_a.try_recv().unwrap();
+
+        assert_ne!(
+            span_a.span_context.trace_id(),
+            other_sequence.span_context.trace_id()
+        );
+        assert_ne!(
+            span_a.span_context.trace_id(),
+            other_chain.span_context.trace_id()
+        );
+    }
+
+    #[test]
+    fn stages_are_linked_and_closed_by_root_span() {
+        let (tracer, spans) = tracer();
+
+        record(&tracer, PacketStage::EventSeen, "ibc-0", 1);
+        record(&tracer, PacketStage::TxConfirmed, "ibc-0", 1);
+        record(&tracer, PacketStage::AckRelayed, "ibc-0", 1);
+
+        let spans = spans.try_iter().collect::<Vec<_>>();
+        let names = spans
+            .iter()
+            .map(|span| span.name.as_ref())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            vec!["event_seen", "tx_confirmed", "ack_relayed", "packet 1"]
+        );
+
+        let (event_seen, tx_confirmed, ack_relayed, root) =
+            (&spans[0], &spans[1], &spans[2], &spans[3]);
+
+        let linked_span_id = |span: &SpanData| {
+            span.links
+                .iter()
+                .map(|link| link.span_context.span_id())
+                .next()
+        };
+
+        assert!(event_seen.links.is_empty());
+        assert_eq!(
+            linked_span_id(tx_confirmed),
+            Some(event_seen.span_context.span_id())
+        );
+        assert_eq!(
+            linked_span_id(ack_relayed),
+            Some(tx_confirmed.span_context.span_id())
+        );
+        assert_eq!(tx_confirmed.start_time, event_seen.end_time);
+
+        assert_eq!(root.parent_span_id, SpanId::INVALID);
+        assert_eq!(root.span_context.span_id(), event_seen.parent_span_id);
+        assert_eq!(root.start_time, event_seen.start_time);
+        assert_eq!(root.end_time, ack_relayed.end_time);
+    }
+}
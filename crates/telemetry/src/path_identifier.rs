@@ -1,7 +1,9 @@
+use core::fmt::{Display, Error as FmtError, Formatter};
+
 /// Structure used by the telemetry in order to define a UID
 /// to track the SendPacket and WriteAcknowledgement and Timeouts for a given
 /// chain, channel and port.
-#[derive(Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PathIdentifier {
     chain_id: String,
     channel_id: String,
@@ -17,3 +19,9 @@ impl PathIdentifier {
         }
     }
 }
+
+impl Display for PathIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}/{}/{}", self.chain_id, self.port_id, self.channel_id)
+    }
+}
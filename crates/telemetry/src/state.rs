@@ -1,19 +1,23 @@
 use core::fmt::{Display, Error as FmtError, Formatter};
+use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
 use dashmap::DashMap;
+use once_cell::sync::OnceCell;
 use opentelemetry::{
     global,
-    metrics::{Counter, ObservableGauge, UpDownCounter},
+    metrics::{Counter, Histogram, ObservableGauge, UpDownCounter},
     Context, KeyValue,
 };
 use opentelemetry_prometheus::PrometheusExporter;
 use prometheus::proto::MetricFamily;
 
 use ibc_relayer_types::core::ics24_host::identifier::{ChainId, ChannelId, ClientId, PortId};
+use ibc_relayer_types::timestamp::Timestamp;
 
 use tendermint::Time;
 
+use crate::packet_trace::{PacketStage, PacketTracer};
 use crate::path_identifier::PathIdentifier;
 
 const EMPTY_BACKLOG_SYMBOL: u64 = 0;
@@ -79,6 +83,18 @@ impl Display for WorkerType {
     }
 }
 
+/// The upper bounds of the buckets of the packet latency histograms, in milliseconds.
+///
+/// The histograms have no bucket other than `+Inf` until the buckets
+/// from the telemetry configuration are set with [`TelemetryState::set_latency_buckets`].
+#[derive(Clone, Debug, Default)]
+pub struct LatencyBuckets {
+    /// The buckets of the `packet_recv_latency` histogram
+    pub recv: Vec<u64>,
+    /// The buckets of the `packet_ack_latency` histogram
+    pub ack: Vec<u64>,
+}
+
 pub struct TelemetryState {
     exporter: PrometheusExporter,
 
@@ -115,9 +131,16 @@ pub struct TelemetryState {
     /// Number of messages submitted to a specific chain
     total_messages_submitted: Counter<u64>,
 
+    /// Total fees paid by Hermes for the transactions it submitted, per chain and denomination
+    fees_paid: Counter<u64>,
+
     /// The balance of each wallet Hermes uses per chain
     wallet_balance: ObservableGauge<f64>,
 
+    /// The gas price used by Hermes for the transactions it submits to chains with
+    /// a dynamic gas price, as discovered from the chain's fee market
+    dynamic_gas_price: ObservableGauge<f64>,
+
     /// Indicates the latency for all transactions submitted to a specific chain,
     /// i.e. the difference between the moment when Hermes received a batch of events
     /// until the corresponding transaction(s) were submitted. Milliseconds.
@@ -128,6 +151,31 @@ pub struct TelemetryState {
     /// until the corresponding transaction(s) were confirmed. Milliseconds.
     tx_latency_confirmed: ObservableGauge<u64>,
 
+    /// The latency of the packets sent over a path, i.e. the difference between the
+    /// timestamps of the block in which a packet was sent and of the block in which
+    /// it was received on the counterparty chain. Milliseconds.
+    packet_recv_latency: Histogram<u64>,
+
+    /// The latency of the acknowledgements of the packets sent over a path, i.e. the
+    /// difference between the timestamps of the block in which a packet was received
+    /// and of the block in which its acknowledgement was relayed. Milliseconds.
+    packet_ack_latency: Histogram<u64>,
+
+    /// The buckets of the packet latency histograms, shared with the aggregator selector.
+    latency_buckets: Arc<RwLock<LatencyBuckets>>,
+
+    /// The timestamps of the blocks in which packets were sent, received or acknowledged,
+    /// so that the timestamp of a block is only queried once.
+    block_timestamps: moka::sync::Cache<(ChainId, u64), Timestamp>,
+
+    /// The timestamps of the blocks in which the packets sent over a path were sent,
+    /// used for computing the `packet_recv_latency` metric.
+    packets_sent: moka::sync::Cache<(PathIdentifier, u64), Timestamp>,
+
+    /// The timestamps of the blocks in which the packets sent over a path were received,
+    /// used for computing the `packet_ack_latency` metric.
+    packets_received: moka::sync::Cache<(PathIdentifier, u64), Timestamp>,
+
     /// Records the time at which we started processing an event batch.
     /// Used for computing the `tx_latency` metric.
     in_flight_events: moka::sync::Cache<String, Instant>,
@@ -168,6 +216,10 @@ pub struct TelemetryState {
     /// that the relayer observed, and for which there was no associated Acknowledgement or
     /// Timeout event.
     backlogs: DashMap<PathIdentifier, DashMap<u64, u64>>,
+
+    /// Records the traces of the lifecycle of the relayed packets,
+    /// if their export was enabled with [`crate::spawn_trace_exporter`].
+    packet_tracer: OnceCell<PacketTracer>,
 }
 
 impl TelemetryState {
@@ -460,6 +512,28 @@ impl TelemetryState {
         self.wallet_balance.observe(&cx, amount, labels);
     }
 
+    pub fn fees_paid(&self, chain_id: &ChainId, denom: &str, amount: u64) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.fees_paid.add(&cx, amount, labels);
+    }
+
+    pub fn dynamic_gas_price(&self, chain_id: &ChainId, price: f64, denom: &str) {
+        let cx = Context::current();
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("denom", denom.to_string()),
+        ];
+
+        self.dynamic_gas_price.observe(&cx, price, labels);
+    }
+
     pub fn received_event_batch(&self, tracking_id: impl ToString) {
         self.in_flight_events
             .insert(tracking_id.to_string(), Instant::now());
@@ -525,6 +599,122 @@ impl TelemetryState {
         }
     }
 
+    /// Set the buckets of the packet latency histograms. The buckets only apply to the
+    /// histograms of the paths for which no latency was recorded yet, hence they must be
+    /// set before packets are relayed.
+    pub fn set_latency_buckets(&self, buckets: LatencyBuckets) {
+        *self.latency_buckets.write().unwrap() = buckets;
+    }
+
+    /// The timestamp of the block at the given height of the given chain. The timestamp is
+    /// obtained with `query` unless it is already known, in which case it is not queried again.
+    pub fn block_timestamp(
+        &self,
+        chain_id: &ChainId,
+        height: u64,
+        query: impl FnOnce() -> Option<Timestamp>,
+    ) -> Option<Timestamp> {
+        let key = (chain_id.clone(), height);
+
+        if let Some(timestamp) = self.block_timestamps.get(&key) {
+            return Some(timestamp);
+        }
+
+        let timestamp = query()?;
+        self.block_timestamps.insert(key, timestamp);
+
+        Some(timestamp)
+    }
+
+    /// Record that the packet with the given sequence number, sent over the given channel and
+    /// port of the given chain, was sent in a block with the given timestamp.
+    pub fn packet_sent(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        send_time: Timestamp,
+    ) {
+        let path_uid = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        self.packets_sent.insert((path_uid, seq_nr), send_time);
+    }
+
+    /// Record that the packet with the given sequence number, sent over the given channel and
+    /// port of the given chain, was received on the counterparty chain in a block with the given
+    /// timestamp, along with its latency since it was sent, if the relayer observed it.
+    pub fn packet_received(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        recv_time: Timestamp,
+    ) {
+        let key = (
+            PathIdentifier::new(
+                chain_id.to_string(),
+                channel_id.to_string(),
+                port_id.to_string(),
+            ),
+            seq_nr,
+        );
+
+        if let Some(send_time) = self.packets_sent.get(&key) {
+            self.packets_sent.invalidate(&key);
+
+            if let Some(latency) = recv_time.duration_since(&send_time) {
+                let labels = &[KeyValue::new("path", key.0.to_string())];
+
+                let cx = Context::current();
+
+                self.packet_recv_latency
+                    .record(&cx, latency.as_millis() as u64, labels);
+            }
+        }
+
+        self.packets_received.insert(key, recv_time);
+    }
+
+    /// Record that the acknowledgement of the packet with the given sequence number, sent over
+    /// the given channel and port of the given chain, was relayed to the given chain in a block
+    /// with the given timestamp, along with its latency since the packet was received.
+    pub fn packet_acknowledged(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        ack_time: Timestamp,
+    ) {
+        let key = (
+            PathIdentifier::new(
+                chain_id.to_string(),
+                channel_id.to_string(),
+                port_id.to_string(),
+            ),
+            seq_nr,
+        );
+
+        if let Some(recv_time) = self.packets_received.get(&key) {
+            self.packets_received.invalidate(&key);
+
+            if let Some(latency) = ack_time.duration_since(&recv_time) {
+                let labels = &[KeyValue::new("path", key.0.to_string())];
+
+                let cx = Context::current();
+
+                self.packet_ack_latency
+                    .record(&cx, latency.as_millis() as u64, labels);
+            }
+        }
+    }
+
     pub fn send_packet_events(
         &self,
         _seq_nr: u64,
@@ -708,6 +898,57 @@ impl TelemetryState {
         self.backlog_size.observe(&cx, total, labels);
     }
 
+    /// Records the sequence number of the packet at the head of the backlog of an ordered
+    /// channel, i.e. the next packet the destination chain expects to receive.
+    /// Every packet with a lower sequence number has been received, and is evicted from the backlog,
+    /// so that `backlog_oldest_sequence` reports the packet blocking the channel.
+    pub fn backlog_head_of_line(
+        &self,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+        counterparty_chain_id: &ChainId,
+    ) {
+        let cx = Context::current();
+
+        // Unique identifier for a chain/channel/port path.
+        let path_uid: PathIdentifier = PathIdentifier::new(
+            chain_id.to_string(),
+            channel_id.to_string(),
+            port_id.to_string(),
+        );
+
+        let labels = &[
+            KeyValue::new("chain", chain_id.to_string()),
+            KeyValue::new("counterparty", counterparty_chain_id.to_string()),
+            KeyValue::new("channel", channel_id.to_string()),
+            KeyValue::new("port", port_id.to_string()),
+        ];
+
+        // Retrieve local timestamp for when the head of the backlog was first observed.
+        let now = Time::now();
+        let timestamp = match now.duration_since(Time::unix_epoch()) {
+            Ok(ts) => ts.as_secs(),
+            Err(_) => 0,
+        };
+
+        let path_backlog = self
+            .backlogs
+            .entry(path_uid)
+            .or_insert_with(|| DashMap::with_capacity(BACKLOG_CAPACITY));
+
+        path_backlog.retain(|sn, _| *sn >= seq_nr);
+
+        let oldest_ts = *path_backlog.entry(seq_nr).or_insert(timestamp);
+
+        self.backlog_oldest_sequence.observe(&cx, seq_nr, labels);
+        self.backlog_oldest_timestamp
+            .observe(&cx, oldest_ts, labels);
+        self.backlog_size
+            .observe(&cx, path_backlog.len() as u64, labels);
+    }
+
     /// Evicts from the backlog the event for the given sequence number.
     /// Removing events happens when the relayer observed either an acknowledgment
     /// or a timeout for a packet sequence number, which means that the corresponding
@@ -760,6 +1001,34 @@ impl TelemetryState {
             }
         }
     }
+
+    pub fn init_packet_tracer(&self, tracer: PacketTracer) {
+        let _ = self.packet_tracer.set(tracer);
+    }
+
+    /// Record that the packet with the given sequence number, sent over the given
+    /// channel and port of the given chain, reached the given stage of its lifecycle.
+    /// This is a no-op unless the export of packet traces is enabled.
+    pub fn packet_stage(
+        &self,
+        stage: PacketStage,
+        tracking_id: impl ToString,
+        seq_nr: u64,
+        chain_id: &ChainId,
+        channel_id: &ChannelId,
+        port_id: &PortId,
+    ) {
+        if let Some(tracer) = self.packet_tracer.get() {
+            tracer.record(
+                stage,
+                tracking_id.to_string(),
+                seq_nr,
+                chain_id,
+                channel_id,
+                port_id,
+            );
+        }
+    }
 }
 
 use std::sync::Arc;
@@ -771,12 +1040,19 @@ use opentelemetry::sdk::metrics::aggregators::{histogram, last_value, sum};
 use opentelemetry::sdk::metrics::sdk_api::Descriptor;
 
 #[derive(Debug)]
-struct CustomAggregatorSelector;
+struct CustomAggregatorSelector {
+    latency_buckets: Arc<RwLock<LatencyBuckets>>,
+}
+
+fn histogram_boundaries(buckets: &[u64]) -> Vec<f64> {
+    buckets.iter().map(|bucket| *bucket as f64).collect()
+}
 
 impl AggregatorSelector for CustomAggregatorSelector {
     fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
         match descriptor.name() {
             "wallet_balance" => Some(Arc::new(last_value())),
+            "dynamic_gas_price" => Some(Arc::new(last_value())),
             "backlog_oldest_sequence" => Some(Arc::new(last_value())),
             "backlog_oldest_timestamp" => Some(Arc::new(last_value())),
             "backlog_size" => Some(Arc::new(last_value())),
@@ -789,6 +1065,14 @@ impl AggregatorSelector for CustomAggregatorSelector {
             "tx_latency_confirmed" => Some(Arc::new(histogram(&[
                 1000.0, 5000.0, 9000.0, 13000.0, 17000.0, 20000.0,
             ]))),
+            "packet_recv_latency" => {
+                let buckets = self.latency_buckets.read().unwrap();
+                Some(Arc::new(histogram(&histogram_boundaries(&buckets.recv))))
+            }
+            "packet_ack_latency" => {
+                let buckets = self.latency_buckets.read().unwrap();
+                Some(Arc::new(histogram(&histogram_boundaries(&buckets.ack))))
+            }
             _ => Some(Arc::new(sum())),
         }
     }
@@ -799,9 +1083,13 @@ impl Default for TelemetryState {
         use opentelemetry::sdk::export::metrics::aggregation;
         use opentelemetry::sdk::metrics::{controllers, processors};
 
+        let latency_buckets = Arc::new(RwLock::new(LatencyBuckets::default()));
+
         let controller = controllers::basic(
             processors::factory(
-                CustomAggregatorSelector,
+                CustomAggregatorSelector {
+                    latency_buckets: latency_buckets.clone(),
+                },
                 aggregation::cumulative_temporality_selector(),
             )
             .with_memory(true),
@@ -872,11 +1160,21 @@ impl Default for TelemetryState {
                 .with_description("Number of messages submitted to a specific chain")
                 .init(),
 
+            fees_paid: meter
+                .u64_counter("fees_paid")
+                .with_description("Total fees paid for the transactions submitted to a specific chain, per denomination")
+                .init(),
+
             wallet_balance: meter
                 .f64_observable_gauge("wallet_balance")
                 .with_description("The balance of each wallet Hermes uses per chain. Please note that when converting the balance to f64 a loss in precision might be introduced in the displayed value")
                 .init(),
 
+            dynamic_gas_price: meter
+                .f64_observable_gauge("dynamic_gas_price")
+                .with_description("The gas price used for the transactions submitted to chains with a dynamic gas price")
+                .init(),
+
             send_packet_events: meter
                 .u64_counter("send_packet_events")
                 .with_description("Number of SendPacket events received")
@@ -918,6 +1216,39 @@ impl Default for TelemetryState {
                     until the corresponding transaction(s) were confirmed. Milliseconds.")
                 .init(),
 
+            packet_recv_latency: meter
+                .u64_histogram("packet_recv_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The latency of the packets sent over a path, \
+                    i.e. the difference between the timestamps of the block in which a packet was sent \
+                    and of the block in which it was received on the counterparty chain. Milliseconds.")
+                .init(),
+
+            packet_ack_latency: meter
+                .u64_histogram("packet_ack_latency")
+                .with_unit(Unit::new("milliseconds"))
+                .with_description("The latency of the acknowledgements of the packets sent over a path, \
+                    i.e. the difference between the timestamps of the block in which a packet was received \
+                    and of the block in which its acknowledgement was relayed. Milliseconds.")
+                .init(),
+
+            latency_buckets,
+
+            block_timestamps: moka::sync::Cache::builder()
+                .max_capacity(10_000)
+                .time_to_live(Duration::from_secs(60 * 60)) // Remove entries after 1 hour
+                .build(),
+
+            packets_sent: moka::sync::Cache::builder()
+                .max_capacity(100_000)
+                .time_to_idle(Duration::from_secs(60 * 60)) // Forget about packets which have not been received for 1 hour
+                .build(),
+
+            packets_received: moka::sync::Cache::builder()
+                .max_capacity(100_000)
+                .time_to_idle(Duration::from_secs(60 * 60)) // Forget about packets which have not been acknowledged for 1 hour
+                .build(),
+
             in_flight_events: moka::sync::Cache::builder()
                 .time_to_live(Duration::from_secs(60 * 60)) // Remove entries after 1 hour
                 .time_to_idle(Duration::from_secs(30 * 60)) // Remove entries if they have been idle for 30 minutes
@@ -940,6 +1271,70 @@ impl Default for TelemetryState {
                 .u64_observable_gauge("backlog_size")
                 .with_description("Total number of SendPacket events in the backlog")
                 .init(),
+
+            packet_tracer: OnceCell::new(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::cell::Cell;
+
+    fn histogram_count(state: &TelemetryState, name: &str, path: &str) -> u64 {
+        state
+            .gather()
+            .iter()
+            .filter(|family| family.get_name() == name)
+            .flat_map(|family| family.get_metric())
+            .filter(|metric| {
+                metric
+                    .get_label()
+                    .iter()
+                    .any(|label| label.get_name() == "path" && label.get_value() == path)
+            })
+            .map(|metric| metric.get_histogram().get_sample_count())
+            .sum()
+    }
+
+    #[test]
+    fn packet_latencies() {
+        let state = TelemetryState::default();
+
+        state.set_latency_buckets(LatencyBuckets {
+            recv: vec![1000, 5000],
+            ack: vec![1000, 5000],
+        });
+
+        let chain_id = ChainId::from_string("ibc-0");
+        let channel_id = ChannelId::new(0);
+        let port_id = PortId::transfer();
+
+        let time = |secs: u64| Timestamp::from_nanoseconds(secs * 1_000_000_000).unwrap();
+
+        // The timestamp of a block is only queried once
+        let queries = Cell::new(0);
+        let query = || {
+            queries.set(queries.get() + 1);
+            Some(time(10))
+        };
+
+        assert_eq!(state.block_timestamp(&chain_id, 5, query), Some(time(10)));
+        assert_eq!(state.block_timestamp(&chain_id, 5, query), Some(time(10)));
+        assert_eq!(queries.get(), 1);
+
+        // The latencies are only recorded for the packets seen at the previous stage
+        state.packet_received(1, &chain_id, &channel_id, &port_id, time(12));
+        state.packet_acknowledged(2, &chain_id, &channel_id, &port_id, time(14));
+
+        state.packet_sent(3, &chain_id, &channel_id, &port_id, time(10));
+        state.packet_received(3, &chain_id, &channel_id, &port_id, time(13));
+        state.packet_acknowledged(3, &chain_id, &channel_id, &port_id, time(15));
+
+        let path = "ibc-0/transfer/channel-0";
+        assert_eq!(histogram_count(&state, "packet_recv_latency", path), 1);
+        assert_eq!(histogram_count(&state, "packet_ack_latency", path), 1);
+    }
+}
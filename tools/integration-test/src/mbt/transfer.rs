@@ -145,6 +145,7 @@ impl TestOverrides for IbcTransferMBT {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                misbehaviour_scan_interval: None,
             },
             connections: ConfigConnections { enabled: true },
             channels: ConfigChannels { enabled: true },
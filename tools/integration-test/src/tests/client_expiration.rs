@@ -103,6 +103,7 @@ impl TestOverrides for ExpirationTestOverrides {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                misbehaviour_scan_interval: None,
             },
             connections: config::Connections { enabled: true },
             channels: config::Channels { enabled: true },
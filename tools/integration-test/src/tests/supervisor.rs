@@ -21,6 +21,7 @@ impl TestOverrides for SupervisorTest {
                 enabled: true,
                 refresh: true,
                 misbehaviour: true,
+                misbehaviour_scan_interval: None,
             },
             connections: config::Connections { enabled: true },
             channels: config::Channels { enabled: true },
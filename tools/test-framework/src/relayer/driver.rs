@@ -62,6 +62,8 @@ impl RelayerDriver {
             SupervisorOptions {
                 health_check: false,
                 force_full_scan: false,
+                config_path: Some(self.config_path.clone()),
+                validate_config: |_| Ok(()),
             },
         )
         .map_err(Error::supervisor)
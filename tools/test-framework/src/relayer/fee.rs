@@ -6,6 +6,7 @@ use ibc_relayer::chain::cosmos::query::fee::{
 };
 use ibc_relayer::chain::cosmos::tx::simple_send_tx;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
+use ibc_relayer::chain::requests::QueryIncentivizedPacketsRequest;
 use ibc_relayer::event::IbcEventWithHeight;
 use ibc_relayer_types::applications::ics29_fee::msgs::pay_packet::build_pay_packet_message;
 use ibc_relayer_types::applications::ics29_fee::msgs::pay_packet_async::build_pay_packet_fee_async_message;
@@ -171,7 +172,14 @@ pub async fn query_incentivized_packets<Chain, Counterparty>(
     channel_id: &TaggedChannelIdRef<'_, Chain, Counterparty>,
     port_id: &TaggedPortIdRef<'_, Chain, Counterparty>,
 ) -> Result<Vec<IdentifiedPacketFees>, Error> {
-    raw_query_incentivized_packets(grpc_address, channel_id.value(), port_id.value())
-        .await
-        .map_err(handle_generic_error)
+    raw_query_incentivized_packets(
+        grpc_address,
+        QueryIncentivizedPacketsRequest {
+            port_id: port_id.cloned_value(),
+            channel_id: channel_id.cloned_value(),
+            pagination: None,
+        },
+    )
+    .await
+    .map_err(handle_generic_error)
 }
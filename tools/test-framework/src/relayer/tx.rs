@@ -4,6 +4,7 @@ use core::time::Duration;
 use http::uri::Uri;
 
 use ibc_proto::cosmos::tx::v1beta1::Fee;
+use ibc_relayer::chain::cosmos::budget::FeeBudgetTracker;
 use ibc_relayer::chain::cosmos::gas::calculate_fee;
 use ibc_relayer::chain::cosmos::types::config::TxConfig;
 use ibc_relayer::chain::cosmos::types::gas::GasConfig;
@@ -53,17 +54,21 @@ pub fn new_tx_config_for_test(
     let max_msg_num = Default::default();
     let max_tx_size = Default::default();
     let extension_options = Default::default();
+    let attribute_encoding = Default::default();
+    let fee_budget = FeeBudgetTracker::new(Default::default(), gas_config.gas_price.denom.clone());
 
     Ok(TxConfig {
         chain_id,
         gas_config,
         rpc_client,
         rpc_address,
+        attribute_encoding,
         grpc_address,
         rpc_timeout,
         address_type,
         max_msg_num,
         max_tx_size,
         extension_options,
+        fee_budget,
     })
 }
@@ -129,9 +129,11 @@ impl FullNode {
             rpc_addr: Url::from_str(&self.chain_driver.rpc_address())?,
             websocket_addr: Url::from_str(&self.chain_driver.websocket_address())?,
             grpc_addr: Url::from_str(&self.chain_driver.grpc_address())?,
+            witness_rpc_addrs: Vec::new(),
             rpc_timeout: Duration::from_secs(10),
             account_prefix: self.chain_driver.account_prefix.clone(),
             key_name: self.wallets.relayer.id.0.clone(),
+            key_names: Vec::new(),
 
             // By default we use in-memory key store to avoid polluting
             // ~/.hermes/keys. See
@@ -149,9 +151,16 @@ impl FullNode {
             max_block_time: Duration::from_secs(30),
             clock_drift: Duration::from_secs(5),
             trusting_period: Some(Duration::from_secs(14 * 24 * 3600)),
+            light_block_store_dir: None,
             trust_threshold: Default::default(),
             gas_price: config::GasPrice::new(0.001, "stake".to_string()),
+            dynamic_gas_price: Default::default(),
+            event_source: Default::default(),
             packet_filter: Default::default(),
+            fee_filter: Default::default(),
+            fee_budget: Default::default(),
+            remote_signer: None,
+            wasm_checksum: None,
             address_type: chain_type.address_type(),
             memo_prefix: Default::default(),
             proof_specs: Default::default(),